@@ -0,0 +1,6 @@
+-- Unique keys used by the bulk importer's ON CONFLICT upserts
+ALTER TABLE colleges
+    ADD CONSTRAINT colleges_name_district_city_key UNIQUE (name, district, city);
+
+ALTER TABLE contact_info
+    ADD CONSTRAINT contact_info_college_id_key UNIQUE (college_id);
@@ -0,0 +1,24 @@
+-- Fee structure per college, course and academic year (amounts in rupees)
+CREATE TABLE fee_structures (
+    id SERIAL PRIMARY KEY,
+    college_id INTEGER NOT NULL REFERENCES colleges(id) ON DELETE CASCADE,
+    course VARCHAR(100) NOT NULL,
+    year INTEGER NOT NULL,
+    tuition_fee INTEGER NOT NULL DEFAULT 0,
+    development_fee INTEGER NOT NULL DEFAULT 0,
+    exam_fee INTEGER NOT NULL DEFAULT 0,
+    hostel_fee INTEGER,
+    total_fee INTEGER GENERATED ALWAYS AS (tuition_fee + development_fee + exam_fee) STORED,
+    -- Annual fee payable by each admission category, when it differs from total_fee
+    open_fee INTEGER,
+    obc_fee INTEGER,
+    sc_fee INTEGER,
+    st_fee INTEGER,
+    ews_fee INTEGER,
+    tfws_fee INTEGER,
+    created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE (college_id, course, year)
+);
+
+CREATE INDEX idx_fee_structures_college_year ON fee_structures(college_id, year);
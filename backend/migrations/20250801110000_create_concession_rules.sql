@@ -0,0 +1,34 @@
+-- Data-driven fee concession rules used by the cost-of-attendance estimate.
+-- A rule reduces one fee component by a percentage (optionally capped) for students
+-- matching its categories, income cap and hostel requirement.
+CREATE TABLE concession_rules (
+    id SERIAL PRIMARY KEY,
+    name VARCHAR(100) NOT NULL,
+    description TEXT,
+    academic_year INTEGER, -- NULL applies to every year
+    categories TEXT[] NOT NULL DEFAULT '{}', -- empty applies to every category
+    max_family_income INTEGER, -- NULL means no income cap
+    requires_hostel BOOLEAN NOT NULL DEFAULT FALSE,
+    component VARCHAR(20) NOT NULL CHECK (component IN ('tuition', 'development', 'exam', 'hostel')),
+    percentage INTEGER NOT NULL CHECK (percentage BETWEEN 0 AND 100),
+    max_amount INTEGER, -- yearly cap on the concession, NULL means uncapped
+    active BOOLEAN NOT NULL DEFAULT TRUE,
+    created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP
+);
+
+-- Indicative Maharashtra schemes; admins should review these every admission year
+INSERT INTO concession_rules (name, description, categories, max_family_income, component, percentage) VALUES
+    ('EBC (Rajarshri Chhatrapati Shahu Maharaj Shikshan Shulkh Shishyavrutti)',
+     '50% tuition fee reimbursement for economically backward OPEN/EWS students',
+     '{OPEN,EWS}', 800000, 'tuition', 50),
+    ('TFWS seat', 'Tuition fee waiver for students admitted on TFWS seats',
+     '{TFWS}', 800000, 'tuition', 100),
+    ('OBC tuition fee scholarship', '50% tuition fee reimbursement for OBC/SEBC/VJNT/SBC students',
+     '{OBC,SEBC,VJNT,SBC}', 800000, 'tuition', 50),
+    ('Post-matric scholarship (tuition)', 'Full tuition fee reimbursement for SC/ST students',
+     '{SC,ST}', 250000, 'tuition', 100),
+    ('Post-matric scholarship (development)', 'Full development fee reimbursement for SC/ST students',
+     '{SC,ST}', 250000, 'development', 100),
+    ('Post-matric scholarship (exam)', 'Full exam fee reimbursement for SC/ST students',
+     '{SC,ST}', 250000, 'exam', 100);
@@ -0,0 +1,45 @@
+-- Scholarship schemes with structured eligibility rules.
+-- Empty arrays and NULL caps mean the scheme does not restrict on that attribute.
+CREATE TABLE scholarships (
+    id SERIAL PRIMARY KEY,
+    name VARCHAR(255) NOT NULL,
+    provider VARCHAR(100) NOT NULL, -- MahaDBT, Central (NSP), AICTE, etc.
+    description TEXT,
+    url VARCHAR(500),
+    max_amount INTEGER, -- maximum yearly benefit in rupees
+    categories TEXT[] NOT NULL DEFAULT '{}',
+    max_family_income INTEGER,
+    gender VARCHAR(10) CHECK (gender IN ('female', 'male')),
+    courses TEXT[] NOT NULL DEFAULT '{}', -- college categories: Engineering, Medical, etc.
+    minority_only BOOLEAN NOT NULL DEFAULT FALSE,
+    requires_domicile BOOLEAN NOT NULL DEFAULT FALSE, -- Maharashtra domicile
+    active BOOLEAN NOT NULL DEFAULT TRUE,
+    created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP
+);
+
+INSERT INTO scholarships (name, provider, description, url, categories, max_family_income, gender, courses, minority_only, requires_domicile) VALUES
+    ('Government of India Post-Matric Scholarship (SC)', 'MahaDBT',
+     'Tuition, exam fee and maintenance allowance for SC students', 'https://mahadbt.maharashtra.gov.in',
+     '{SC}', 250000, NULL, '{}', FALSE, TRUE),
+    ('Post-Matric Scholarship for ST Students', 'MahaDBT',
+     'Tuition, exam fee and maintenance allowance for ST students', 'https://mahadbt.maharashtra.gov.in',
+     '{ST}', 250000, NULL, '{}', FALSE, TRUE),
+    ('Post-Matric Scholarship for OBC/VJNT/SBC Students', 'MahaDBT',
+     'Fee reimbursement and maintenance allowance', 'https://mahadbt.maharashtra.gov.in',
+     '{OBC,VJNT,SBC}', 150000, NULL, '{}', FALSE, TRUE),
+    ('Rajarshi Chhatrapati Shahu Maharaj Shikshan Shulkh Shishyavrutti Yojana', 'MahaDBT',
+     'Tuition fee reimbursement for economically backward students', 'https://mahadbt.maharashtra.gov.in',
+     '{OPEN,EWS}', 800000, NULL, '{}', FALSE, TRUE),
+    ('Dr. Panjabrao Deshmukh Vastigruh Nirvah Bhatta Yojana', 'MahaDBT',
+     'Hostel maintenance allowance for students staying away from home', 'https://mahadbt.maharashtra.gov.in',
+     '{OPEN,EWS,OBC}', 800000, NULL, '{}', FALSE, TRUE),
+    ('Central Sector Scheme of Scholarships for College and University Students', 'Central (NSP)',
+     'Merit-based scholarship for students above the 80th percentile in Class XII', 'https://scholarships.gov.in',
+     '{}', 450000, NULL, '{}', FALSE, FALSE),
+    ('AICTE Pragati Scholarship for Girls', 'AICTE',
+     'Scholarship for girl students in AICTE-approved technical institutions', 'https://scholarships.gov.in',
+     '{}', 800000, 'female', '{Engineering,Pharmacy}', FALSE, FALSE),
+    ('Post-Matric Scholarship for Minorities', 'Central (NSP)',
+     'Scholarship for students from notified minority communities', 'https://scholarships.gov.in',
+     '{}', 200000, NULL, '{}', TRUE, FALSE);
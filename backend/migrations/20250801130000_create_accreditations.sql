@@ -0,0 +1,56 @@
+-- NAAC institutional accreditation cycles
+CREATE TABLE naac_accreditations (
+    id SERIAL PRIMARY KEY,
+    college_id INTEGER NOT NULL REFERENCES colleges(id) ON DELETE CASCADE,
+    grade VARCHAR(5) NOT NULL CHECK (grade IN ('A++', 'A+', 'A', 'B++', 'B+', 'B', 'C', 'D')),
+    cgpa DOUBLE PRECISION,
+    cycle INTEGER,
+    valid_from DATE NOT NULL,
+    valid_until DATE NOT NULL,
+    created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE (college_id, valid_from)
+);
+
+-- NBA accreditation of individual programmes
+CREATE TABLE nba_accreditations (
+    id SERIAL PRIMARY KEY,
+    college_id INTEGER NOT NULL REFERENCES colleges(id) ON DELETE CASCADE,
+    programme VARCHAR(255) NOT NULL,
+    valid_from DATE NOT NULL,
+    valid_until DATE NOT NULL,
+    created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE (college_id, programme, valid_from)
+);
+
+-- NIRF rankings per year and ranking category (Overall, Engineering, College, ...)
+CREATE TABLE nirf_rankings (
+    id SERIAL PRIMARY KEY,
+    college_id INTEGER NOT NULL REFERENCES colleges(id) ON DELETE CASCADE,
+    year INTEGER NOT NULL,
+    category VARCHAR(50) NOT NULL,
+    rank INTEGER, -- exact rank, published for the top 100/200
+    rank_band VARCHAR(20), -- e.g. 101-150 when only a band is published
+    score DOUBLE PRECISION,
+    created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE (college_id, year, category),
+    CHECK (rank IS NOT NULL OR rank_band IS NOT NULL)
+);
+
+CREATE INDEX idx_naac_college ON naac_accreditations(college_id);
+CREATE INDEX idx_nba_college ON nba_accreditations(college_id);
+CREATE INDEX idx_nirf_college_year ON nirf_rankings(college_id, year);
+
+-- Orders NAAC grades so filters can compare them (higher is better)
+CREATE FUNCTION naac_grade_rank(grade TEXT) RETURNS INTEGER AS $$
+    SELECT CASE grade
+        WHEN 'A++' THEN 8
+        WHEN 'A+' THEN 7
+        WHEN 'A' THEN 6
+        WHEN 'B++' THEN 5
+        WHEN 'B+' THEN 4
+        WHEN 'B' THEN 3
+        WHEN 'C' THEN 2
+        WHEN 'D' THEN 1
+        ELSE 0
+    END
+$$ LANGUAGE SQL IMMUTABLE;
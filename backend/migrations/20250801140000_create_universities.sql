@@ -0,0 +1,30 @@
+-- Universities that colleges are affiliated to
+CREATE TABLE universities (
+    id SERIAL PRIMARY KEY,
+    name VARCHAR(255) NOT NULL UNIQUE,
+    short_name VARCHAR(50) NOT NULL UNIQUE, -- SPPU, MU, RTMNU, etc.
+    city VARCHAR(100),
+    university_type VARCHAR(50), -- State, Deemed, Private, Central
+    website VARCHAR(255),
+    created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP
+);
+
+ALTER TABLE colleges
+    ADD COLUMN university_id INTEGER REFERENCES universities(id) ON DELETE SET NULL;
+
+CREATE INDEX idx_colleges_university ON colleges(university_id);
+
+INSERT INTO universities (name, short_name, city, university_type, website) VALUES
+    ('Savitribai Phule Pune University', 'SPPU', 'Pune', 'State', 'https://www.unipune.ac.in'),
+    ('University of Mumbai', 'MU', 'Mumbai', 'State', 'https://mu.ac.in'),
+    ('Rashtrasant Tukadoji Maharaj Nagpur University', 'RTMNU', 'Nagpur', 'State', 'https://www.nagpuruniversity.ac.in'),
+    ('Dr. Babasaheb Ambedkar Technological University', 'DBATU', 'Lonere', 'State', 'https://dbatu.ac.in'),
+    ('Dr. Babasaheb Ambedkar Marathwada University', 'BAMU', 'Chhatrapati Sambhajinagar', 'State', 'https://www.bamu.ac.in'),
+    ('Shivaji University', 'SUK', 'Kolhapur', 'State', 'https://www.unishivaji.ac.in'),
+    ('Sant Gadge Baba Amravati University', 'SGBAU', 'Amravati', 'State', 'https://www.sgbau.ac.in'),
+    ('Swami Ramanand Teerth Marathwada University', 'SRTMU', 'Nanded', 'State', 'https://srtmun.ac.in'),
+    ('Kavayitri Bahinabai Chaudhari North Maharashtra University', 'KBCNMU', 'Jalgaon', 'State', 'https://nmu.ac.in'),
+    ('Punyashlok Ahilyadevi Holkar Solapur University', 'PAHSU', 'Solapur', 'State', 'https://su.digitaluniversity.ac'),
+    ('Gondwana University', 'GUG', 'Gadchiroli', 'State', 'https://unigug.ac.in'),
+    ('SNDT Women''s University', 'SNDT', 'Mumbai', 'State', 'https://sndt.ac.in'),
+    ('Maharashtra University of Health Sciences', 'MUHS', 'Nashik', 'State', 'https://www.muhs.ac.in');
@@ -0,0 +1,23 @@
+-- Placement outcomes per college, branch and year (packages in lakhs per annum)
+CREATE TABLE placement_stats (
+    id SERIAL PRIMARY KEY,
+    college_id INTEGER NOT NULL REFERENCES colleges(id) ON DELETE CASCADE,
+    year INTEGER NOT NULL,
+    branch VARCHAR(100) NOT NULL,
+    students_eligible INTEGER NOT NULL CHECK (students_eligible >= 0),
+    students_placed INTEGER NOT NULL CHECK (students_placed >= 0),
+    placement_rate DOUBLE PRECISION GENERATED ALWAYS AS (
+        CASE WHEN students_eligible > 0
+            THEN students_placed * 100.0 / students_eligible
+        END
+    ) STORED,
+    median_package DOUBLE PRECISION,
+    highest_package DOUBLE PRECISION,
+    top_recruiters TEXT[] NOT NULL DEFAULT '{}',
+    created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE (college_id, branch, year),
+    CHECK (students_placed <= students_eligible)
+);
+
+CREATE INDEX idx_placement_stats_college_year ON placement_stats(college_id, year);
@@ -0,0 +1,27 @@
+-- Managed facility/amenity taxonomy
+CREATE TABLE facilities (
+    id SERIAL PRIMARY KEY,
+    slug VARCHAR(50) NOT NULL UNIQUE, -- used by the facilities= filter
+    name VARCHAR(100) NOT NULL,
+    description TEXT,
+    created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP
+);
+
+-- Facilities offered by each college
+CREATE TABLE college_facilities (
+    college_id INTEGER NOT NULL REFERENCES colleges(id) ON DELETE CASCADE,
+    facility_id INTEGER NOT NULL REFERENCES facilities(id) ON DELETE CASCADE,
+    PRIMARY KEY (college_id, facility_id)
+);
+
+CREATE INDEX idx_college_facilities_facility ON college_facilities(facility_id);
+
+INSERT INTO facilities (slug, name) VALUES
+    ('library', 'Library'),
+    ('lab', 'Laboratories'),
+    ('sports_complex', 'Sports complex'),
+    ('wheelchair_access', 'Wheelchair access'),
+    ('wifi', 'Wi-Fi campus'),
+    ('canteen', 'Canteen'),
+    ('transport', 'College transport'),
+    ('girls_hostel', 'Girls'' hostel');
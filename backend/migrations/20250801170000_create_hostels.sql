@@ -0,0 +1,14 @@
+-- Hostel details per college, one row per hostel type
+CREATE TABLE hostels (
+    id SERIAL PRIMARY KEY,
+    college_id INTEGER NOT NULL REFERENCES colleges(id) ON DELETE CASCADE,
+    hostel_type VARCHAR(10) NOT NULL CHECK (hostel_type IN ('boys', 'girls')),
+    capacity INTEGER CHECK (capacity >= 0),
+    annual_fee INTEGER CHECK (annual_fee >= 0), -- rupees per year
+    mess_available BOOLEAN,
+    distance_km DOUBLE PRECISION CHECK (distance_km >= 0), -- from the main campus
+    first_year_guaranteed BOOLEAN,
+    created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE (college_id, hostel_type)
+);
@@ -0,0 +1,8 @@
+-- Gender admission policy and languages of instruction
+ALTER TABLE colleges
+    ADD COLUMN gender_policy VARCHAR(20) NOT NULL DEFAULT 'co-ed'
+        CHECK (gender_policy IN ('co-ed', 'women-only', 'men-only')),
+    ADD COLUMN instruction_languages TEXT[] NOT NULL DEFAULT '{}'; -- Marathi, English, Hindi, ...
+
+CREATE INDEX idx_colleges_gender_policy ON colleges(gender_policy);
+CREATE INDEX idx_colleges_instruction_languages ON colleges USING GIN (instruction_languages);
@@ -0,0 +1,17 @@
+-- Images belong to exactly one college, with at most one primary image each
+DELETE FROM college_images WHERE college_id IS NULL;
+
+UPDATE college_images SET is_primary = FALSE
+WHERE is_primary IS NULL
+   OR (is_primary AND id <> (
+        SELECT MIN(i.id) FROM college_images i
+        WHERE i.college_id = college_images.college_id AND i.is_primary
+   ));
+
+ALTER TABLE college_images
+    ALTER COLUMN college_id SET NOT NULL,
+    ALTER COLUMN is_primary SET NOT NULL,
+    ALTER COLUMN uploaded_at TYPE TIMESTAMPTZ;
+
+CREATE INDEX idx_college_images_college ON college_images(college_id);
+CREATE UNIQUE INDEX idx_college_images_one_primary ON college_images(college_id) WHERE is_primary;
@@ -0,0 +1,21 @@
+-- Uploaded images are identified by the SHA-256 of their bytes so identical
+-- uploads for a college are stored once
+ALTER TABLE college_images
+    ADD COLUMN content_hash VARCHAR(64),
+    ADD COLUMN width INTEGER CHECK (width > 0),
+    ADD COLUMN height INTEGER CHECK (height > 0);
+
+CREATE UNIQUE INDEX idx_college_images_content_hash ON college_images(college_id, content_hash);
+
+-- Metadata-free WebP renditions generated from each uploaded image
+CREATE TABLE image_renditions (
+    id SERIAL PRIMARY KEY,
+    image_id INTEGER NOT NULL REFERENCES college_images(id) ON DELETE CASCADE,
+    variant VARCHAR(20) NOT NULL CHECK (variant IN ('thumbnail', 'card', 'hero')),
+    width INTEGER NOT NULL CHECK (width > 0),
+    height INTEGER NOT NULL CHECK (height > 0),
+    byte_size INTEGER NOT NULL CHECK (byte_size >= 0),
+    file_path VARCHAR(500) NOT NULL, -- relative to UPLOAD_DIR
+    created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE (image_id, variant)
+);
@@ -0,0 +1,18 @@
+-- Documents published by colleges (prospectuses, fee notices, cutoff lists)
+CREATE TABLE college_attachments (
+    id SERIAL PRIMARY KEY,
+    college_id INTEGER NOT NULL REFERENCES colleges(id) ON DELETE CASCADE,
+    document_type VARCHAR(30) NOT NULL
+        CHECK (document_type IN ('prospectus', 'fee_notice', 'cutoff', 'brochure', 'other')),
+    academic_year VARCHAR(7) CHECK (academic_year ~ '^[0-9]{4}-[0-9]{2}$'), -- e.g. 2024-25
+    title VARCHAR(255) NOT NULL,
+    file_name VARCHAR(255) NOT NULL, -- original upload name
+    content_type VARCHAR(100) NOT NULL,
+    byte_size BIGINT NOT NULL CHECK (byte_size > 0),
+    checksum VARCHAR(64) NOT NULL, -- SHA-256 of the file
+    file_path VARCHAR(500) NOT NULL, -- relative to UPLOAD_DIR
+    uploaded_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE (college_id, checksum)
+);
+
+CREATE INDEX idx_college_attachments_college ON college_attachments(college_id);
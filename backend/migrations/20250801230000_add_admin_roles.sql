@@ -0,0 +1,7 @@
+-- Privilege level of each admin account
+ALTER TABLE admin_users
+    ADD COLUMN role VARCHAR(20) NOT NULL DEFAULT 'volunteer'
+        CHECK (role IN ('volunteer', 'reviewer', 'superadmin'));
+
+-- Accounts created before roles existed had full access
+UPDATE admin_users SET role = 'superadmin';
@@ -0,0 +1,54 @@
+-- College representatives: admin accounts limited to proposing changes for one college
+ALTER TABLE admin_users DROP CONSTRAINT admin_users_role_check;
+ALTER TABLE admin_users
+    ADD CONSTRAINT admin_users_role_check
+        CHECK (role IN ('representative', 'volunteer', 'reviewer', 'superadmin')),
+    ADD COLUMN college_id INTEGER REFERENCES colleges(id) ON DELETE CASCADE,
+    ADD CONSTRAINT admin_users_college_scope_check
+        CHECK ((role = 'representative') = (college_id IS NOT NULL));
+
+-- Requests from institutions to manage a college profile; approving one creates
+-- the representative account
+CREATE TABLE representative_claims (
+    id SERIAL PRIMARY KEY,
+    college_id INTEGER NOT NULL REFERENCES colleges(id) ON DELETE CASCADE,
+    username VARCHAR(100) NOT NULL, -- stored lowercase
+    password_hash TEXT NOT NULL,
+    full_name VARCHAR(255) NOT NULL,
+    designation VARCHAR(255),
+    email VARCHAR(255) NOT NULL,
+    phone VARCHAR(20),
+    message TEXT, -- how the claimant can be verified
+    status VARCHAR(20) NOT NULL DEFAULT 'pending'
+        CHECK (status IN ('pending', 'approved', 'rejected')),
+    review_note TEXT,
+    reviewed_by INTEGER REFERENCES admin_users(id) ON DELETE SET NULL,
+    reviewed_at TIMESTAMPTZ,
+    user_id INTEGER REFERENCES admin_users(id) ON DELETE SET NULL, -- account created on approval
+    created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE UNIQUE INDEX idx_representative_claims_pending_username
+    ON representative_claims(username) WHERE status = 'pending';
+CREATE INDEX idx_representative_claims_status ON representative_claims(status, created_at);
+
+-- Changes proposed by representatives, applied only once a reviewer approves them
+CREATE TABLE college_submissions (
+    id SERIAL PRIMARY KEY,
+    college_id INTEGER NOT NULL REFERENCES colleges(id) ON DELETE CASCADE,
+    submitted_by INTEGER REFERENCES admin_users(id) ON DELETE SET NULL,
+    kind VARCHAR(20) NOT NULL CHECK (kind IN ('contact_info', 'courses', 'cutoffs', 'image')),
+    changes JSONB NOT NULL,
+    status VARCHAR(20) NOT NULL DEFAULT 'pending'
+        CHECK (status IN ('pending', 'approved', 'rejected')),
+    review_note TEXT,
+    reviewed_by INTEGER REFERENCES admin_users(id) ON DELETE SET NULL,
+    reviewed_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX idx_college_submissions_status ON college_submissions(status, created_at);
+CREATE INDEX idx_college_submissions_college ON college_submissions(college_id);
+
+-- Images uploaded by representatives stay hidden until their submission is approved
+ALTER TABLE college_images ADD COLUMN published BOOLEAN NOT NULL DEFAULT TRUE;
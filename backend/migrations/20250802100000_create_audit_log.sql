@@ -0,0 +1,66 @@
+-- One row per created, updated or deleted record in the audited tables, written by
+-- triggers. The application identifies the actor and source of a change with the
+-- transaction-local settings audit.actor_id, audit.actor and audit.source; changes
+-- made without them (e.g. from psql) are recorded with source 'database'.
+CREATE TABLE audit_log (
+    id BIGSERIAL PRIMARY KEY,
+    occurred_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    actor_id INTEGER REFERENCES admin_users(id) ON DELETE SET NULL,
+    actor VARCHAR(100), -- username at the time of the change
+    source VARCHAR(30) NOT NULL, -- csv_upload, json_upload, manual_edit, image_upload, submission, database
+    table_name VARCHAR(50) NOT NULL,
+    record_id INTEGER NOT NULL,
+    college_id INTEGER, -- not a foreign key, so the history outlives the college
+    action VARCHAR(10) NOT NULL CHECK (action IN ('create', 'update', 'delete')),
+    changes JSONB NOT NULL -- {"column": {"before": ..., "after": ...}} for changed columns only
+);
+
+CREATE INDEX idx_audit_log_college ON audit_log(college_id, occurred_at);
+CREATE INDEX idx_audit_log_actor ON audit_log(actor, occurred_at);
+CREATE INDEX idx_audit_log_occurred_at ON audit_log(occurred_at);
+
+CREATE FUNCTION record_audit() RETURNS trigger AS $$
+DECLARE
+    old_row JSONB := CASE WHEN TG_OP <> 'INSERT' THEN to_jsonb(OLD) END;
+    new_row JSONB := CASE WHEN TG_OP <> 'DELETE' THEN to_jsonb(NEW) END;
+    current_row JSONB := COALESCE(new_row, old_row);
+    diff JSONB;
+BEGIN
+    SELECT jsonb_object_agg(
+               key, jsonb_build_object('before', old_row -> key, 'after', new_row -> key))
+    INTO diff
+    FROM jsonb_object_keys(current_row) AS key
+    WHERE key <> 'updated_at'
+      AND (old_row -> key) IS DISTINCT FROM (new_row -> key);
+
+    -- Upserts that rewrite identical values are not changes
+    IF diff IS NULL THEN
+        RETURN NULL;
+    END IF;
+
+    INSERT INTO audit_log (actor_id, actor, source, table_name, record_id, college_id, action, changes)
+    VALUES (
+        NULLIF(current_setting('audit.actor_id', TRUE), '')::INTEGER,
+        NULLIF(current_setting('audit.actor', TRUE), ''),
+        COALESCE(NULLIF(current_setting('audit.source', TRUE), ''), 'database'),
+        TG_TABLE_NAME,
+        (current_row ->> 'id')::INTEGER,
+        CASE WHEN TG_TABLE_NAME = 'colleges'
+            THEN (current_row ->> 'id')::INTEGER
+            ELSE (current_row ->> 'college_id')::INTEGER
+        END,
+        CASE TG_OP WHEN 'INSERT' THEN 'create' WHEN 'UPDATE' THEN 'update' ELSE 'delete' END,
+        diff
+    );
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER colleges_audit AFTER INSERT OR UPDATE OR DELETE ON colleges
+    FOR EACH ROW EXECUTE FUNCTION record_audit();
+CREATE TRIGGER contact_info_audit AFTER INSERT OR UPDATE OR DELETE ON contact_info
+    FOR EACH ROW EXECUTE FUNCTION record_audit();
+CREATE TRIGGER cutoffs_audit AFTER INSERT OR UPDATE OR DELETE ON cutoffs
+    FOR EACH ROW EXECUTE FUNCTION record_audit();
+CREATE TRIGGER college_images_audit AFTER INSERT OR UPDATE OR DELETE ON college_images
+    FOR EACH ROW EXECUTE FUNCTION record_audit();
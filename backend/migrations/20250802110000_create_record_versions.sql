@@ -0,0 +1,90 @@
+-- Row versions of colleges and contact_info, maintained by triggers. Each version
+-- holds the full row as JSONB and is valid from valid_from until valid_to (NULL
+-- for the current version; closed without a successor when the row is deleted).
+CREATE TABLE record_versions (
+    id BIGSERIAL PRIMARY KEY,
+    table_name VARCHAR(50) NOT NULL,
+    record_id INTEGER NOT NULL,
+    college_id INTEGER NOT NULL, -- not a foreign key, so versions outlive the college
+    valid_from TIMESTAMPTZ NOT NULL,
+    valid_to TIMESTAMPTZ,
+    data JSONB NOT NULL,
+    CHECK (valid_to IS NULL OR valid_to > valid_from)
+);
+
+CREATE UNIQUE INDEX idx_record_versions_current
+    ON record_versions(table_name, record_id) WHERE valid_to IS NULL;
+CREATE INDEX idx_record_versions_college ON record_versions(college_id, valid_from);
+CREATE INDEX idx_record_versions_period ON record_versions(table_name, valid_from, valid_to);
+
+CREATE FUNCTION record_version() RETURNS trigger AS $$
+DECLARE
+    changed_at TIMESTAMPTZ := NOW();
+BEGIN
+    -- Rewriting identical values (e.g. re-importing the same CSV) is not a new version
+    IF TG_OP = 'UPDATE' AND to_jsonb(OLD) - 'updated_at' = to_jsonb(NEW) - 'updated_at' THEN
+        RETURN NULL;
+    END IF;
+
+    IF TG_OP <> 'INSERT' THEN
+        -- A version opened earlier in this transaction was never visible; replace it
+        DELETE FROM record_versions
+        WHERE table_name = TG_TABLE_NAME AND record_id = OLD.id
+          AND valid_to IS NULL AND xmin = pg_current_xact_id()::xid;
+        -- A transaction that started later may have committed a newer version first
+        changed_at := GREATEST(changed_at, (
+            SELECT MAX(valid_from) + INTERVAL '1 microsecond' FROM record_versions
+            WHERE table_name = TG_TABLE_NAME AND record_id = OLD.id
+        ));
+        UPDATE record_versions SET valid_to = changed_at
+        WHERE table_name = TG_TABLE_NAME AND record_id = OLD.id AND valid_to IS NULL;
+    END IF;
+
+    IF TG_OP <> 'DELETE' THEN
+        INSERT INTO record_versions (table_name, record_id, college_id, valid_from, data)
+        VALUES (
+            TG_TABLE_NAME,
+            NEW.id,
+            CASE WHEN TG_TABLE_NAME = 'colleges'
+                THEN NEW.id
+                ELSE (to_jsonb(NEW) ->> 'college_id')::INTEGER
+            END,
+            changed_at,
+            to_jsonb(NEW)
+        );
+    END IF;
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER colleges_version AFTER INSERT OR UPDATE OR DELETE ON colleges
+    FOR EACH ROW EXECUTE FUNCTION record_version();
+CREATE TRIGGER contact_info_version AFTER INSERT OR UPDATE OR DELETE ON contact_info
+    FOR EACH ROW EXECUTE FUNCTION record_version();
+
+-- Earlier values were not kept, so existing rows get a single version starting
+-- when the college was created
+INSERT INTO record_versions (table_name, record_id, college_id, valid_from, data)
+SELECT 'colleges', c.id, c.id, COALESCE(c.created_at AT TIME ZONE 'UTC', NOW()), to_jsonb(c)
+FROM colleges c;
+
+INSERT INTO record_versions (table_name, record_id, college_id, valid_from, data)
+SELECT 'contact_info', ci.id, ci.college_id,
+       COALESCE(c.created_at AT TIME ZONE 'UTC', NOW()), to_jsonb(ci)
+FROM contact_info ci
+JOIN colleges c ON c.id = ci.college_id;
+
+-- The colleges and contact_info rows as they were at a point in time
+CREATE FUNCTION colleges_as_of(at TIMESTAMPTZ) RETURNS SETOF colleges AS $$
+    SELECT r.* FROM record_versions v,
+        jsonb_populate_record(NULL::colleges, v.data) AS r
+    WHERE v.table_name = 'colleges'
+      AND v.valid_from <= at AND (v.valid_to IS NULL OR v.valid_to > at)
+$$ LANGUAGE sql STABLE;
+
+CREATE FUNCTION contact_info_as_of(at TIMESTAMPTZ) RETURNS SETOF contact_info AS $$
+    SELECT r.* FROM record_versions v,
+        jsonb_populate_record(NULL::contact_info, v.data) AS r
+    WHERE v.table_name = 'contact_info'
+      AND v.valid_from <= at AND (v.valid_to IS NULL OR v.valid_to > at)
+$$ LANGUAGE sql STABLE;
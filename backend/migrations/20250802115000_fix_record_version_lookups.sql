@@ -0,0 +1,47 @@
+-- TG_TABLE_NAME is of type name, with the "C" collation, so comparing table_name
+-- with it could not use an index and the version trigger scanned all of
+-- record_versions for every change. Compare against a text copy instead, and index
+-- each record's versions by date for the lookup of its latest one.
+CREATE INDEX idx_record_versions_record ON record_versions(table_name, record_id, valid_from);
+
+CREATE OR REPLACE FUNCTION record_version() RETURNS trigger AS $$
+DECLARE
+    changed_at TIMESTAMPTZ := NOW();
+    -- In the column's collation, so the indexes on table_name apply
+    versioned_table TEXT := TG_TABLE_NAME;
+BEGIN
+    -- Rewriting identical values (e.g. re-importing the same CSV) is not a new version
+    IF TG_OP = 'UPDATE' AND to_jsonb(OLD) - 'updated_at' = to_jsonb(NEW) - 'updated_at' THEN
+        RETURN NULL;
+    END IF;
+
+    IF TG_OP <> 'INSERT' THEN
+        -- A version opened earlier in this transaction was never visible; replace it
+        DELETE FROM record_versions
+        WHERE table_name = versioned_table AND record_id = OLD.id
+          AND valid_to IS NULL AND xmin = pg_current_xact_id()::xid;
+        -- A transaction that started later may have committed a newer version first
+        changed_at := GREATEST(changed_at, (
+            SELECT MAX(valid_from) + INTERVAL '1 microsecond' FROM record_versions
+            WHERE table_name = versioned_table AND record_id = OLD.id
+        ));
+        UPDATE record_versions SET valid_to = changed_at
+        WHERE table_name = versioned_table AND record_id = OLD.id AND valid_to IS NULL;
+    END IF;
+
+    IF TG_OP <> 'DELETE' THEN
+        INSERT INTO record_versions (table_name, record_id, college_id, valid_from, data)
+        VALUES (
+            TG_TABLE_NAME,
+            NEW.id,
+            CASE WHEN TG_TABLE_NAME = 'colleges'
+                THEN NEW.id
+                ELSE (to_jsonb(NEW) ->> 'college_id')::INTEGER
+            END,
+            changed_at,
+            to_jsonb(NEW)
+        );
+    END IF;
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
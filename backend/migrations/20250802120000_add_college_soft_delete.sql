@@ -0,0 +1,7 @@
+-- Deleting a college only hides it; the row and everything that references it are
+-- kept until a superadmin purges it after the retention period
+ALTER TABLE colleges
+    ADD COLUMN deleted_at TIMESTAMPTZ,
+    ADD COLUMN deleted_by INTEGER REFERENCES admin_users(id) ON DELETE SET NULL;
+
+CREATE INDEX idx_colleges_deleted_at ON colleges(deleted_at) WHERE deleted_at IS NOT NULL;
@@ -0,0 +1,33 @@
+-- College imports run in the background. The uploaded file is kept under IMPORT_DIR
+-- until the job ends, so a job interrupted by a restart is resumed from its last
+-- checkpoint (or, for atomic imports, from the start).
+CREATE TABLE import_jobs (
+    id SERIAL PRIMARY KEY,
+    kind VARCHAR(20) NOT NULL CHECK (kind IN ('colleges_csv', 'colleges_json')),
+    status VARCHAR(20) NOT NULL DEFAULT 'queued'
+        CHECK (status IN ('queued', 'running', 'completed', 'failed', 'cancelled')),
+    atomic BOOLEAN NOT NULL DEFAULT FALSE,
+    total_rows INTEGER, -- known once the file has been counted
+    processed_rows INTEGER NOT NULL DEFAULT 0, -- rows handled as of the last checkpoint
+    inserted INTEGER NOT NULL DEFAULT 0,
+    updated INTEGER NOT NULL DEFAULT 0,
+    error_count INTEGER NOT NULL DEFAULT 0,
+    message TEXT,
+    cancel_requested BOOLEAN NOT NULL DEFAULT FALSE,
+    created_by INTEGER REFERENCES admin_users(id) ON DELETE SET NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    started_at TIMESTAMPTZ,
+    finished_at TIMESTAMPTZ
+);
+
+CREATE INDEX idx_import_jobs_created_at ON import_jobs(created_at);
+CREATE INDEX idx_import_jobs_unfinished ON import_jobs(id) WHERE status IN ('queued', 'running');
+
+-- Rows an import job rejected, as reported in upload responses ("Line 5: ...")
+CREATE TABLE import_job_errors (
+    job_id INTEGER NOT NULL REFERENCES import_jobs(id) ON DELETE CASCADE,
+    row_number INTEGER NOT NULL,
+    message TEXT NOT NULL
+);
+
+CREATE INDEX idx_import_job_errors_job ON import_job_errors(job_id, row_number);
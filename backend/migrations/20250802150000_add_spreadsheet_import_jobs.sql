@@ -0,0 +1,9 @@
+-- XLSX/ODS college imports run as jobs too, reading the sheet chosen at upload with
+-- its column names taken from row header_row
+ALTER TABLE import_jobs DROP CONSTRAINT import_jobs_kind_check;
+ALTER TABLE import_jobs ADD CONSTRAINT import_jobs_kind_check
+    CHECK (kind IN ('colleges_csv', 'colleges_json', 'colleges_spreadsheet'));
+
+ALTER TABLE import_jobs
+    ADD COLUMN sheet TEXT,
+    ADD COLUMN header_row INTEGER;
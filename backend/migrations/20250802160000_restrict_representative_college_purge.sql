@@ -0,0 +1,6 @@
+-- Purging a college no longer deletes its representatives' accounts along with it;
+-- the purge is refused while any account is still scoped to the college
+ALTER TABLE admin_users
+    DROP CONSTRAINT admin_users_college_id_fkey,
+    ADD CONSTRAINT admin_users_college_id_fkey
+        FOREIGN KEY (college_id) REFERENCES colleges(id) ON DELETE RESTRICT;
@@ -0,0 +1,30 @@
+-- One cutoff per college, year, branch and category, so concurrent uploads cannot add
+-- duplicates. A missing branch or category is the same key as an empty one. Existing
+-- duplicates are not resolved here: the migration stops and lists them so an admin
+-- can decide which rows to keep.
+DO $$
+DECLARE
+    duplicates TEXT;
+BEGIN
+    SELECT string_agg(duplicate, '; ') INTO duplicates
+    FROM (
+        SELECT format('college %s, year %s, branch %L, category %L: ids %s',
+                      college_id, year, COALESCE(branch, ''), COALESCE(category, ''),
+                      string_agg(id::TEXT, ', ' ORDER BY id)) AS duplicate
+        FROM cutoffs
+        GROUP BY college_id, year, COALESCE(branch, ''), COALESCE(category, '')
+        HAVING COUNT(*) > 1
+    ) groups;
+
+    IF duplicates IS NOT NULL THEN
+        RAISE EXCEPTION 'cutoffs has duplicate rows; remove all but one of each before migrating: %',
+            duplicates;
+    END IF;
+END
+$$;
+
+CREATE UNIQUE INDEX cutoffs_college_year_branch_category_key
+    ON cutoffs (college_id, year, COALESCE(branch, ''), COALESCE(category, ''));
+
+-- Covered by the leading columns of the unique index
+DROP INDEX idx_cutoffs_college_year;
@@ -0,0 +1,7 @@
+-- Cutoff CSV and spreadsheet uploads run as import jobs like the college imports
+ALTER TABLE import_jobs DROP CONSTRAINT import_jobs_kind_check;
+ALTER TABLE import_jobs ADD CONSTRAINT import_jobs_kind_check
+    CHECK (kind IN (
+        'colleges_csv', 'colleges_json', 'colleges_spreadsheet',
+        'cutoffs_csv', 'cutoffs_spreadsheet'
+    ));
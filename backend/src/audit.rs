@@ -0,0 +1,54 @@
+//! Audit trail: database triggers record every change to colleges, contact_info,
+//! cutoffs and college_images in `audit_log`. Writers attribute their changes by
+//! calling [`set_context`] at the start of the transaction making them.
+
+use crate::auth::CurrentAdmin;
+use sqlx::{Postgres, Transaction};
+
+/// How a change reached the database.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AuditSource {
+    CsvUpload,
+    JsonUpload,
+    /// An XLSX or ODS workbook
+    SpreadsheetUpload,
+    ManualEdit,
+    ImageUpload,
+    /// A representative's submission, applied when a reviewer approved it
+    Submission,
+}
+
+impl AuditSource {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            AuditSource::CsvUpload => "csv_upload",
+            AuditSource::JsonUpload => "json_upload",
+            AuditSource::SpreadsheetUpload => "spreadsheet_upload",
+            AuditSource::ManualEdit => "manual_edit",
+            AuditSource::ImageUpload => "image_upload",
+            AuditSource::Submission => "submission",
+        }
+    }
+}
+
+/// Attribute the audited changes made in `tx` to `actor`. The settings are local
+/// to the transaction, so pooled connections never carry them over.
+pub async fn set_context(
+    tx: &mut Transaction<'_, Postgres>,
+    actor: &CurrentAdmin,
+    source: AuditSource,
+) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        r#"
+        SELECT set_config('audit.actor_id', $1, TRUE),
+               set_config('audit.actor', $2, TRUE),
+               set_config('audit.source', $3, TRUE)
+        "#,
+    )
+    .bind(actor.id.to_string())
+    .bind(&actor.username)
+    .bind(source.as_str())
+    .execute(&mut **tx)
+    .await?;
+    Ok(())
+}
@@ -0,0 +1,368 @@
+//! Admin authentication: Argon2 password hashing, signed session tokens and the
+//! middleware guarding `/api/admin/*`.
+
+use crate::errors::ApiError;
+use argon2::password_hash::{PasswordHash, PasswordHasher, PasswordVerifier, SaltString};
+use argon2::Argon2;
+use axum::{
+    async_trait,
+    extract::{FromRequestParts, Request},
+    http::{header, request::Parts},
+    middleware::Next,
+    response::Response,
+    Extension,
+};
+use chrono::{DateTime, Duration, Utc};
+use jsonwebtoken::{Algorithm, DecodingKey, EncodingKey, Header, Validation};
+use rand_core::{OsRng, RngCore};
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use std::sync::{Arc, OnceLock};
+
+/// Token signing settings, read from `ADMIN_TOKEN_SECRET` and
+/// `ADMIN_TOKEN_TTL_MINUTES` (default 720).
+#[derive(Clone)]
+pub struct AuthConfig {
+    secret: Arc<Vec<u8>>,
+    pub token_ttl: Duration,
+}
+
+impl AuthConfig {
+    pub fn new(secret: impl Into<Vec<u8>>, token_ttl: Duration) -> Self {
+        AuthConfig {
+            secret: Arc::new(secret.into()),
+            token_ttl,
+        }
+    }
+
+    pub fn from_env() -> Self {
+        let secret = match std::env::var("ADMIN_TOKEN_SECRET") {
+            Ok(secret) if !secret.is_empty() => secret.into_bytes(),
+            _ => {
+                tracing::warn!(
+                    "ADMIN_TOKEN_SECRET is not set; using a random secret, so admin tokens \
+                     will not survive a restart"
+                );
+                let mut secret = vec![0u8; 32];
+                OsRng.fill_bytes(&mut secret);
+                secret
+            }
+        };
+        let ttl_minutes = std::env::var("ADMIN_TOKEN_TTL_MINUTES")
+            .ok()
+            .and_then(|minutes| minutes.parse().ok())
+            .unwrap_or(720);
+
+        AuthConfig::new(secret, Duration::minutes(ttl_minutes))
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct Claims {
+    /// Admin user id
+    sub: i32,
+    /// `admin_sessions.id`, checked on every request so tokens can be revoked
+    sid: i64,
+    iat: i64,
+    exp: i64,
+}
+
+/// Actions guarded individually in the admin handlers.
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Permission {
+    /// Bulk imports and adding new colleges, images and documents
+    Upload,
+    /// Changing existing records
+    Edit,
+    /// Approving or rejecting submitted changes
+    Review,
+    /// Deleting records; deleted colleges stay restorable
+    Delete,
+    /// Permanently removing deleted colleges once their retention period has passed
+    Purge,
+    /// Viewing admin statistics
+    Stats,
+    /// Creating admin accounts and assigning roles
+    ManageUsers,
+    /// Proposing changes to one's own college, applied after review
+    Submit,
+}
+
+/// Admin account roles, from least to most privileged.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Role {
+    /// Staff of one college: proposes changes to that college's profile only
+    Representative,
+    /// Data-entry volunteer: uploads data but cannot change or delete existing records
+    Volunteer,
+    /// Reviews and edits submitted data
+    Reviewer,
+    /// Full access, including account management
+    Superadmin,
+}
+
+impl Permission {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Permission::Upload => "upload",
+            Permission::Edit => "edit",
+            Permission::Review => "review",
+            Permission::Delete => "delete",
+            Permission::Purge => "purge",
+            Permission::Stats => "stats",
+            Permission::ManageUsers => "manage_users",
+            Permission::Submit => "submit",
+        }
+    }
+}
+
+impl Role {
+    pub const ALL: [Role; 4] = [
+        Role::Representative,
+        Role::Volunteer,
+        Role::Reviewer,
+        Role::Superadmin,
+    ];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Role::Representative => "representative",
+            Role::Volunteer => "volunteer",
+            Role::Reviewer => "reviewer",
+            Role::Superadmin => "superadmin",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Role> {
+        Role::ALL.into_iter().find(|role| role.as_str() == value)
+    }
+
+    pub fn permissions(self) -> &'static [Permission] {
+        use Permission::*;
+        match self {
+            Role::Representative => &[Submit],
+            Role::Volunteer => &[Upload, Stats],
+            Role::Reviewer => &[Upload, Edit, Review, Stats],
+            Role::Superadmin => &[Upload, Edit, Review, Delete, Purge, Stats, ManageUsers],
+        }
+    }
+
+    pub fn allows(self, permission: Permission) -> bool {
+        self.permissions().contains(&permission)
+    }
+}
+
+/// The authenticated admin, added to request extensions by [`require_admin`]
+/// and available to handlers as an extractor.
+#[derive(Clone)]
+pub struct CurrentAdmin {
+    pub id: i32,
+    pub username: String,
+    pub role: Role,
+    /// The college a representative account is limited to
+    pub college_id: Option<i32>,
+    pub session_id: i64,
+}
+
+impl CurrentAdmin {
+    /// Load an active admin outside of a request, e.g. to resume their background
+    /// import after a restart. There is no session, so `session_id` is 0.
+    pub async fn load(pool: &PgPool, id: i32) -> Result<Option<CurrentAdmin>, ApiError> {
+        let Some((username, role, college_id)) =
+            sqlx::query_as::<_, (String, String, Option<i32>)>(
+                "SELECT username, role, college_id FROM admin_users WHERE id = $1 AND active",
+            )
+            .bind(id)
+            .fetch_optional(pool)
+            .await?
+        else {
+            return Ok(None);
+        };
+        let role = Role::parse(&role).ok_or_else(|| {
+            ApiError::InternalServerError(format!("Unknown admin role '{}'", role))
+        })?;
+        Ok(Some(CurrentAdmin {
+            id,
+            username,
+            role,
+            college_id,
+            session_id: 0,
+        }))
+    }
+
+    /// Fail with 403 unless the admin's role grants `permission`.
+    pub fn require(&self, permission: Permission) -> Result<(), ApiError> {
+        if self.role.allows(permission) {
+            Ok(())
+        } else {
+            Err(ApiError::Forbidden(format!(
+                "The {} role does not have the {} permission",
+                self.role.as_str(),
+                permission.as_str()
+            )))
+        }
+    }
+
+    /// Fail with 403 unless the admin is a representative of `college_id`.
+    pub fn require_college(&self, college_id: i32) -> Result<(), ApiError> {
+        self.require(Permission::Submit)?;
+        if self.college_id == Some(college_id) {
+            Ok(())
+        } else {
+            Err(ApiError::Forbidden(
+                "Representatives can only submit changes for their own college".to_string(),
+            ))
+        }
+    }
+}
+
+#[async_trait]
+impl<S: Send + Sync> FromRequestParts<S> for CurrentAdmin {
+    type Rejection = ApiError;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        parts
+            .extensions
+            .get::<CurrentAdmin>()
+            .cloned()
+            .ok_or_else(|| ApiError::Unauthorized("Missing bearer token".to_string()))
+    }
+}
+
+/// Hash a password as an Argon2id PHC string.
+pub fn hash_password(password: &str) -> Result<String, String> {
+    let salt = SaltString::generate(&mut OsRng);
+    Argon2::default()
+        .hash_password(password.as_bytes(), &salt)
+        .map(|hash| hash.to_string())
+        .map_err(|e| format!("Failed to hash password: {}", e))
+}
+
+/// Check a password against a stored PHC string.
+pub fn verify_password(password: &str, password_hash: &str) -> bool {
+    PasswordHash::new(password_hash)
+        .map(|hash| {
+            Argon2::default()
+                .verify_password(password.as_bytes(), &hash)
+                .is_ok()
+        })
+        .unwrap_or(false)
+}
+
+// Hash checked for unknown usernames so failed logins take the same time either way
+pub(crate) fn dummy_password_hash() -> &'static str {
+    static HASH: OnceLock<String> = OnceLock::new();
+    HASH.get_or_init(|| hash_password("not-a-real-password").unwrap_or_default())
+}
+
+/// Sign a token for an admin session.
+pub fn issue_token(
+    config: &AuthConfig,
+    user_id: i32,
+    session_id: i64,
+    issued_at: DateTime<Utc>,
+    expires_at: DateTime<Utc>,
+) -> Result<String, ApiError> {
+    let claims = Claims {
+        sub: user_id,
+        sid: session_id,
+        iat: issued_at.timestamp(),
+        exp: expires_at.timestamp(),
+    };
+    jsonwebtoken::encode(
+        &Header::new(Algorithm::HS256),
+        &claims,
+        &EncodingKey::from_secret(&config.secret),
+    )
+    .map_err(|e| ApiError::InternalServerError(format!("Failed to issue token: {}", e)))
+}
+
+fn decode_token(config: &AuthConfig, token: &str) -> Option<Claims> {
+    let mut validation = Validation::new(Algorithm::HS256);
+    validation.leeway = 0;
+    jsonwebtoken::decode::<Claims>(
+        token,
+        &DecodingKey::from_secret(&config.secret),
+        &validation,
+    )
+    .ok()
+    .map(|data| data.claims)
+}
+
+/// Middleware for `/api/admin/*`: requires a valid, unexpired and unrevoked
+/// `Authorization: Bearer <token>` and exposes the caller as [`CurrentAdmin`].
+pub async fn require_admin(
+    Extension(pool): Extension<PgPool>,
+    Extension(config): Extension<AuthConfig>,
+    mut request: Request,
+    next: Next,
+) -> Result<Response, ApiError> {
+    let unauthorized = || ApiError::Unauthorized("Invalid or expired token".to_string());
+
+    let token = request
+        .headers()
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .ok_or_else(|| ApiError::Unauthorized("Missing bearer token".to_string()))?;
+    let claims = decode_token(&config, token.trim()).ok_or_else(unauthorized)?;
+
+    let (username, role, college_id): (String, String, Option<i32>) = sqlx::query_as(
+        r#"
+        SELECT u.username, u.role, u.college_id FROM admin_sessions s
+        JOIN admin_users u ON u.id = s.user_id
+        WHERE s.id = $1 AND s.user_id = $2
+          AND s.revoked_at IS NULL AND s.expires_at > NOW() AND u.active
+        "#,
+    )
+    .bind(claims.sid)
+    .bind(claims.sub)
+    .fetch_optional(&pool)
+    .await?
+    .ok_or_else(unauthorized)?;
+
+    let role = Role::parse(&role)
+        .ok_or_else(|| ApiError::InternalServerError(format!("Unknown admin role '{}'", role)))?;
+
+    request.extensions_mut().insert(CurrentAdmin {
+        id: claims.sub,
+        username,
+        role,
+        college_id,
+        session_id: claims.sid,
+    });
+
+    Ok(next.run(request).await)
+}
+
+/// Create the first (superadmin) account from `ADMIN_USERNAME` and `ADMIN_PASSWORD`
+/// when no admin users exist yet.
+pub async fn bootstrap_admin(pool: &PgPool) -> anyhow::Result<()> {
+    let (Ok(username), Ok(password)) = (
+        std::env::var("ADMIN_USERNAME"),
+        std::env::var("ADMIN_PASSWORD"),
+    ) else {
+        return Ok(());
+    };
+
+    let has_admins: bool = sqlx::query_scalar("SELECT EXISTS (SELECT 1 FROM admin_users)")
+        .fetch_one(pool)
+        .await?;
+    if has_admins {
+        return Ok(());
+    }
+
+    let password_hash = hash_password(&password).map_err(anyhow::Error::msg)?;
+    sqlx::query(
+        "INSERT INTO admin_users (username, password_hash, role) VALUES ($1, $2, 'superadmin')",
+    )
+    .bind(username.trim().to_lowercase())
+    .bind(password_hash)
+    .execute(pool)
+    .await?;
+    tracing::info!("Created initial admin user '{}'", username.trim());
+
+    Ok(())
+}
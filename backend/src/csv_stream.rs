@@ -0,0 +1,168 @@
+//! Incremental CSV parsing for uploads that arrive in chunks, so a file is read
+//! record by record as it streams in instead of being buffered whole.
+
+use csv::{ByteRecord, Position, StringRecord};
+use csv_core::{ReadRecordResult, Reader};
+use serde::de::DeserializeOwned;
+
+const UTF8_BOM: &[u8] = b"\xEF\xBB\xBF";
+
+/// A row read from the stream: its line number as reported in import errors
+/// (the header is line 1) and the deserialized record or why it was rejected.
+pub type CsvRow<T> = (usize, Result<T, String>);
+
+/// Parses CSV with a header row from successive chunks. A record split across
+/// chunks is completed by a later chunk, so only one record is held at a time.
+pub struct CsvStream {
+    reader: Reader,
+    headers: Option<StringRecord>,
+    // Fields of the record being read and the end offset of each, kept across chunks
+    fields: Vec<u8>,
+    fields_len: usize,
+    ends: Vec<usize>,
+    ends_len: usize,
+    // Where the record being read started, once it has
+    start: Option<Position>,
+    bytes_read: u64,
+    records: u64,
+    finished: bool,
+}
+
+impl Default for CsvStream {
+    fn default() -> Self {
+        CsvStream {
+            reader: Reader::new(),
+            headers: None,
+            fields: vec![0; 1024],
+            fields_len: 0,
+            ends: vec![0; 32],
+            ends_len: 0,
+            start: None,
+            bytes_read: 0,
+            records: 0,
+            finished: false,
+        }
+    }
+}
+
+impl CsvStream {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parse the next chunk, returning the rows it completes.
+    pub fn feed<T: DeserializeOwned>(&mut self, chunk: &[u8]) -> Vec<CsvRow<T>> {
+        let mut rows = Vec::new();
+        if !chunk.is_empty() {
+            self.read(chunk, &mut rows);
+        }
+        rows
+    }
+
+    /// Mark the end of the input, returning the last row if the file does not end
+    /// with a line break.
+    pub fn finish<T: DeserializeOwned>(&mut self) -> Vec<CsvRow<T>> {
+        let mut rows = Vec::new();
+        if !self.finished {
+            self.finished = true;
+            // Empty input tells the parser the data has ended
+            self.read(&[], &mut rows);
+        }
+        rows
+    }
+
+    fn read<T: DeserializeOwned>(&mut self, mut input: &[u8], rows: &mut Vec<CsvRow<T>>) {
+        loop {
+            if self.start.is_none() {
+                let mut position = Position::new();
+                position
+                    .set_byte(self.bytes_read)
+                    .set_line(self.reader.line())
+                    .set_record(self.records);
+                self.start = Some(position);
+            }
+
+            let (result, nin, nout, nend) = self.reader.read_record(
+                input,
+                &mut self.fields[self.fields_len..],
+                &mut self.ends[self.ends_len..],
+            );
+            input = &input[nin..];
+            self.bytes_read += nin as u64;
+            self.fields_len += nout;
+            self.ends_len += nend;
+
+            match result {
+                ReadRecordResult::InputEmpty | ReadRecordResult::End => return,
+                ReadRecordResult::OutputFull => {
+                    let len = self.fields.len() * 2;
+                    self.fields.resize(len, 0);
+                }
+                ReadRecordResult::OutputEndsFull => {
+                    let len = self.ends.len() * 2;
+                    self.ends.resize(len, 0);
+                }
+                ReadRecordResult::Record => {
+                    let record = self.take_record();
+                    if let Some(row) = self.complete(record) {
+                        rows.push(row);
+                    }
+                }
+            }
+        }
+    }
+
+    // Move the fields read so far into a record, ready for the next one
+    fn take_record(&mut self) -> ByteRecord {
+        let mut record = ByteRecord::new();
+        let mut start = 0;
+        for &end in &self.ends[..self.ends_len] {
+            record.push_field(&self.fields[start..end]);
+            start = end;
+        }
+        record.set_position(self.start.take());
+        self.fields_len = 0;
+        self.ends_len = 0;
+        record
+    }
+
+    // Keep the first record as the header; deserialize every later one against it
+    fn complete<T: DeserializeOwned>(&mut self, record: ByteRecord) -> Option<CsvRow<T>> {
+        self.records += 1;
+        let Some(headers) = &self.headers else {
+            let mut headers = ByteRecord::new();
+            for (i, field) in record.iter().enumerate() {
+                let field = match i {
+                    0 => field.strip_prefix(UTF8_BOM).unwrap_or(field),
+                    _ => field,
+                };
+                headers.push_field(field);
+            }
+            self.headers = Some(StringRecord::from_byte_record_lossy(headers));
+            return None;
+        };
+
+        // Line numbers count the header as line 1, as the importers always have
+        let row = self.records as usize;
+        Some((row, deserialize(record, headers)))
+    }
+}
+
+fn deserialize<T: DeserializeOwned>(
+    record: ByteRecord,
+    headers: &StringRecord,
+) -> Result<T, String> {
+    if record.len() != headers.len() {
+        let position = record.position().cloned().unwrap_or_else(Position::new);
+        return Err(format!(
+            "CSV error: record {} (line: {}, byte: {}): found record with {} fields, but the header has {} fields",
+            position.record(),
+            position.line(),
+            position.byte(),
+            record.len(),
+            headers.len()
+        ));
+    }
+    let record = StringRecord::from_byte_record(record).map_err(|e| e.to_string())?;
+    record.deserialize(Some(headers)).map_err(|e| e.to_string())
+}
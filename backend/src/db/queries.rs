@@ -1,6 +1,42 @@
-use crate::models::college::{College, CollegeWithContact};
+use crate::models::accreditation::{NaacAccreditation, NbaAccreditation, NirfRanking};
+use crate::models::attachment::Attachment;
+use crate::models::college::{College, CollegeDependents, CollegeVersion, CollegeWithContact};
+use crate::models::college_image::{CollegeImage, ImageRendition};
+use crate::models::facet::Facets;
+use crate::models::{
+    AsOf, CollegeFilters, ConcessionRule, FacetCount, Facility, FacilityWithCount, FeeStructure,
+    Hostel, PlacementStat, Scholarship, ScholarshipLink, SortBy, UniversityWithCount,
+};
+use chrono::{DateTime, Utc};
 use sqlx::PgPool;
 
+// Lowest annual fee a college charges in its most recent fee year
+const LATEST_MIN_FEE: &str = "(SELECT MIN(f.total_fee) FROM fee_structures f \
+     WHERE f.college_id = colleges.id \
+     AND f.year = (SELECT MAX(year) FROM fee_structures WHERE college_id = colleges.id))";
+
+// Best NIRF rank a college holds in its most recent ranking year
+const LATEST_BEST_NIRF: &str = "(SELECT MIN(r.rank) FROM nirf_rankings r \
+     WHERE r.college_id = colleges.id \
+     AND r.year = (SELECT MAX(year) FROM nirf_rankings WHERE college_id = colleges.id))";
+
+// Overall placement percentage across all branches in a college's most recent placement year
+const LATEST_PLACEMENT_RATE: &str =
+    "(SELECT SUM(p.students_placed) * 100.0 / NULLIF(SUM(p.students_eligible), 0) \
+     FROM placement_stats p \
+     WHERE p.college_id = colleges.id \
+     AND p.year = (SELECT MAX(year) FROM placement_stats WHERE college_id = colleges.id))";
+
+// Restricts a per-college table to rows of colleges that have not been deleted
+const LIVE_COLLEGE_ID: &str = "college_id IN (SELECT id FROM colleges WHERE deleted_at IS NULL)";
+
+// College columns plus the URL of its primary gallery image; the query continues
+// with the source of the `colleges` rows
+const COLLEGE_COLUMNS: &str = "SELECT colleges.*, \
+     (SELECT i.image_url FROM college_images i \
+      WHERE i.college_id = colleges.id AND i.is_primary) AS primary_image_url \
+     FROM";
+
 // Fetch a single college with joined contact info by ID
 pub async fn fetch_college_with_contact_by_id(
     pool: &PgPool,
@@ -11,30 +47,111 @@ pub async fn fetch_college_with_contact_by_id(
         SELECT
             c.id, c.name, c.category, c.district, c.city, c.type,
             c.autonomous, c.minority, c.hostel_available, c.established_year,
+            c.university_id, u.short_name AS university,
+            c.gender_policy, c.instruction_languages,
             ci.phone, ci.email, ci.website, ci.address, ci.pincode
         FROM colleges c
+        LEFT JOIN universities u ON c.university_id = u.id
         LEFT JOIN contact_info ci ON c.id = ci.college_id
-        WHERE c.id = $1
+        WHERE c.id = $1 AND c.deleted_at IS NULL
+        "#,
+    )
+    .bind(id)
+    .fetch_one(pool)
+    .await
+}
+
+// Fetch a single college with contact info as it was at `at`; a college that did not
+// exist then, or has been deleted since, is not found
+pub async fn fetch_college_with_contact_as_of(
+    pool: &PgPool,
+    id: i32,
+    at: DateTime<Utc>,
+) -> Result<CollegeWithContact, sqlx::Error> {
+    sqlx::query_as::<_, CollegeWithContact>(
+        r#"
+        SELECT
+            c.id, c.name, c.category, c.district, c.city, c.type,
+            c.autonomous, c.minority, c.hostel_available, c.established_year,
+            c.university_id, u.short_name AS university,
+            c.gender_policy, c.instruction_languages,
+            ci.phone, ci.email, ci.website, ci.address, ci.pincode
+        FROM colleges_as_of($2) c
+        LEFT JOIN universities u ON c.university_id = u.id
+        LEFT JOIN contact_info_as_of($2) ci ON c.id = ci.college_id
+        WHERE c.id = $1 AND c.deleted_at IS NULL
+          AND EXISTS (SELECT 1 FROM colleges WHERE id = $1 AND deleted_at IS NULL)
         "#,
     )
     .bind(id)
+    .bind(at)
     .fetch_one(pool)
     .await
 }
 
+// Fetch every version of a college with its contact info, oldest first. A new period
+// starts whenever either the college or its contact info changed; periods in which
+// the college was deleted are left out.
+pub async fn fetch_college_history(
+    pool: &PgPool,
+    id: i32,
+) -> Result<Vec<CollegeVersion>, sqlx::Error> {
+    sqlx::query_as::<_, CollegeVersion>(
+        r#"
+        WITH changes AS (
+            SELECT valid_from AS at FROM record_versions WHERE college_id = $1
+            UNION
+            SELECT valid_to FROM record_versions
+            WHERE college_id = $1 AND valid_to IS NOT NULL
+        ),
+        periods AS (
+            SELECT at AS valid_from, LEAD(at) OVER (ORDER BY at) AS valid_to FROM changes
+        )
+        SELECT
+            p.valid_from, p.valid_to,
+            c.id, c.name, c.category, c.district, c.city, c.type,
+            c.autonomous, c.minority, c.hostel_available, c.established_year,
+            c.university_id, u.short_name AS university,
+            c.gender_policy, c.instruction_languages,
+            ci.phone, ci.email, ci.website, ci.address, ci.pincode
+        FROM periods p
+        JOIN record_versions cv ON cv.table_name = 'colleges' AND cv.record_id = $1
+            AND cv.valid_from <= p.valid_from
+            AND (cv.valid_to IS NULL OR cv.valid_to > p.valid_from)
+        CROSS JOIN LATERAL jsonb_populate_record(NULL::colleges, cv.data) c
+        LEFT JOIN universities u ON c.university_id = u.id
+        LEFT JOIN record_versions iv ON iv.table_name = 'contact_info' AND iv.college_id = $1
+            AND iv.valid_from <= p.valid_from
+            AND (iv.valid_to IS NULL OR iv.valid_to > p.valid_from)
+        LEFT JOIN LATERAL jsonb_populate_record(NULL::contact_info, iv.data) ci ON TRUE
+        WHERE c.deleted_at IS NULL
+        ORDER BY p.valid_from
+        "#,
+    )
+    .bind(id)
+    .fetch_all(pool)
+    .await
+}
+
 // Fetch all colleges ordered by name
 pub async fn fetch_all_colleges(pool: &PgPool) -> Result<Vec<College>, sqlx::Error> {
-    sqlx::query_as::<_, College>("SELECT * FROM colleges ORDER BY name")
-        .fetch_all(pool)
-        .await
+    sqlx::query_as::<_, College>(&format!(
+        "{} colleges WHERE deleted_at IS NULL ORDER BY name",
+        COLLEGE_COLUMNS
+    ))
+    .fetch_all(pool)
+    .await
 }
 
 // Fetch college by id without contact info (optional/legacy use)
 pub async fn fetch_college_by_id(pool: &PgPool, id: i32) -> Result<College, sqlx::Error> {
-    sqlx::query_as::<_, College>("SELECT * FROM colleges WHERE id = $1")
-        .bind(id)
-        .fetch_one(pool)
-        .await
+    sqlx::query_as::<_, College>(&format!(
+        "{} colleges WHERE id = $1 AND deleted_at IS NULL",
+        COLLEGE_COLUMNS
+    ))
+    .bind(id)
+    .fetch_one(pool)
+    .await
 }
 
 // Fetch colleges filtered by district
@@ -42,10 +159,13 @@ pub async fn fetch_colleges_by_district(
     pool: &PgPool,
     district: &str,
 ) -> Result<Vec<College>, sqlx::Error> {
-    sqlx::query_as::<_, College>("SELECT * FROM colleges WHERE district = $1 ORDER BY name")
-        .bind(district)
-        .fetch_all(pool)
-        .await
+    sqlx::query_as::<_, College>(&format!(
+        "{} colleges WHERE district = $1 AND deleted_at IS NULL ORDER BY name",
+        COLLEGE_COLUMNS
+    ))
+    .bind(district)
+    .fetch_all(pool)
+    .await
 }
 
 // Fetch colleges filtered by category
@@ -53,10 +173,13 @@ pub async fn fetch_colleges_by_category(
     pool: &PgPool,
     category: &str,
 ) -> Result<Vec<College>, sqlx::Error> {
-    sqlx::query_as::<_, College>("SELECT * FROM colleges WHERE category = $1 ORDER BY name")
-        .bind(category)
-        .fetch_all(pool)
-        .await
+    sqlx::query_as::<_, College>(&format!(
+        "{} colleges WHERE category = $1 AND deleted_at IS NULL ORDER BY name",
+        COLLEGE_COLUMNS
+    ))
+    .bind(category)
+    .fetch_all(pool)
+    .await
 }
 
 // Search colleges by name (case-insensitive, partial match)
@@ -65,57 +188,176 @@ pub async fn search_colleges_by_name(
     name: &str,
 ) -> Result<Vec<College>, sqlx::Error> {
     let search_pattern = format!("%{}%", name);
-    sqlx::query_as::<_, College>("SELECT * FROM colleges WHERE name ILIKE $1 ORDER BY name")
-        .bind(&search_pattern)
-        .fetch_all(pool)
-        .await
+    sqlx::query_as::<_, College>(&format!(
+        "{} colleges WHERE name ILIKE $1 AND deleted_at IS NULL ORDER BY name",
+        COLLEGE_COLUMNS
+    ))
+    .bind(&search_pattern)
+    .fetch_all(pool)
+    .await
 }
 
 // Fetch colleges with dynamic filters using QueryBuilder
 pub async fn fetch_colleges_with_filters(
     pool: &PgPool,
-    district: Option<&str>,
-    category: Option<&str>,
-    college_type: Option<&str>,
-    autonomous: Option<bool>,
-    hostel_available: Option<bool>,
+    filters: &CollegeFilters,
 ) -> Result<Vec<College>, sqlx::Error> {
     use sqlx::QueryBuilder;
 
-    let mut query_builder = QueryBuilder::new("SELECT * FROM colleges WHERE 1=1");
+    let mut query_builder = QueryBuilder::new(COLLEGE_COLUMNS);
+    match filters.as_of {
+        Some(AsOf(at)) => {
+            query_builder.push(" colleges_as_of(");
+            query_builder.push_bind(at);
+            query_builder.push(") AS colleges");
+        }
+        None => {
+            query_builder.push(" colleges");
+        }
+    }
+    query_builder.push(" WHERE deleted_at IS NULL");
+    if filters.as_of.is_some() {
+        // Colleges deleted since are hidden from past listings as well
+        query_builder.push(" AND id IN (SELECT id FROM colleges WHERE deleted_at IS NULL)");
+    }
+
+    if let Some(name) = filters.search.as_deref() {
+        if !name.trim().is_empty() {
+            query_builder.push(" AND name ILIKE ");
+            query_builder.push_bind(format!("%{}%", name));
+        }
+    }
 
-    if let Some(d) = district {
+    if let Some(d) = filters.district.as_deref() {
         if !d.trim().is_empty() {
             query_builder.push(" AND district = ");
             query_builder.push_bind(d);
         }
     }
 
-    if let Some(c) = category {
+    if let Some(c) = filters.category.as_deref() {
         if !c.trim().is_empty() {
             query_builder.push(" AND category = ");
             query_builder.push_bind(c);
         }
     }
 
-    if let Some(t) = college_type {
+    if let Some(t) = filters.college_type.as_deref() {
         if !t.trim().is_empty() {
             query_builder.push(" AND type = ");
             query_builder.push_bind(t);
         }
     }
 
-    if autonomous == Some(true) {
+    if let Some(g) = &filters.gender_policy {
+        query_builder.push(" AND gender_policy = ");
+        query_builder.push_bind(g);
+    }
+
+    if let Some(l) = filters.language.as_deref() {
+        if !l.trim().is_empty() {
+            query_builder.push(
+                " AND EXISTS (SELECT 1 FROM unnest(instruction_languages) lang \
+                 WHERE lower(lang) = lower(",
+            );
+            query_builder.push_bind(l.trim());
+            query_builder.push("))");
+        }
+    }
+
+    if filters.autonomous == Some(true) {
         query_builder.push(" AND autonomous = ");
         query_builder.push_bind(true);
     }
 
-    if hostel_available == Some(true) {
+    if filters.hostel_available == Some(true) {
         query_builder.push(" AND hostel_available = ");
         query_builder.push_bind(true);
     }
 
-    query_builder.push(" ORDER BY name");
+    if filters.girls_hostel == Some(true) || filters.max_hostel_fee.is_some() {
+        query_builder.push(" AND EXISTS (SELECT 1 FROM hostels h WHERE h.college_id = colleges.id");
+        if filters.girls_hostel == Some(true) {
+            query_builder.push(" AND h.hostel_type = 'girls'");
+        }
+        if let Some(max_hostel_fee) = filters.max_hostel_fee {
+            query_builder.push(" AND h.annual_fee <= ");
+            query_builder.push_bind(max_hostel_fee);
+        }
+        query_builder.push(")");
+    }
+
+    if let Some(u) = filters.university.as_deref() {
+        if !u.trim().is_empty() {
+            query_builder.push(
+                " AND university_id IN (SELECT id FROM universities \
+                 WHERE lower(short_name) = lower(",
+            );
+            query_builder.push_bind(u.trim());
+            query_builder.push(") OR lower(name) = lower(");
+            query_builder.push_bind(u.trim());
+            query_builder.push("))");
+        }
+    }
+
+    let facility_slugs = filters.facility_slugs();
+    if !facility_slugs.is_empty() {
+        let required = facility_slugs.len() as i64;
+        query_builder.push(
+            " AND (SELECT COUNT(DISTINCT f.slug) FROM college_facilities cf \
+             JOIN facilities f ON f.id = cf.facility_id \
+             WHERE cf.college_id = colleges.id AND f.slug = ANY(",
+        );
+        query_builder.push_bind(facility_slugs);
+        query_builder.push(")) = ");
+        query_builder.push_bind(required);
+    }
+
+    if let Some(max_fee) = filters.max_fee {
+        query_builder.push(format!(" AND {} <= ", LATEST_MIN_FEE));
+        query_builder.push_bind(max_fee);
+    }
+
+    if let Some(grade) = filters.naac_min {
+        query_builder.push(
+            " AND EXISTS (SELECT 1 FROM naac_accreditations n \
+             WHERE n.college_id = colleges.id AND n.valid_until >= CURRENT_DATE \
+             AND naac_grade_rank(n.grade) >= naac_grade_rank(",
+        );
+        query_builder.push_bind(grade.as_str());
+        query_builder.push("))");
+    }
+
+    if let Some(nirf_max) = filters.nirf_max {
+        query_builder.push(format!(" AND {} <= ", LATEST_BEST_NIRF));
+        query_builder.push_bind(nirf_max);
+    }
+
+    if let Some(min_rate) = filters.min_placement_rate {
+        query_builder.push(format!(" AND {}::float8 >= ", LATEST_PLACEMENT_RATE));
+        query_builder.push_bind(min_rate);
+    }
+
+    match filters.sort {
+        Some(SortBy::Fee) => {
+            query_builder.push(format!(" ORDER BY {} ASC NULLS LAST, name", LATEST_MIN_FEE));
+        }
+        Some(SortBy::Nirf) => {
+            query_builder.push(format!(
+                " ORDER BY {} ASC NULLS LAST, name",
+                LATEST_BEST_NIRF
+            ));
+        }
+        Some(SortBy::Placement) => {
+            query_builder.push(format!(
+                " ORDER BY {} DESC NULLS LAST, name",
+                LATEST_PLACEMENT_RATE
+            ));
+        }
+        Some(SortBy::Name) | None => {
+            query_builder.push(" ORDER BY name");
+        }
+    }
 
     let query = query_builder.build_query_as::<College>();
     query.fetch_all(pool).await
@@ -123,21 +365,384 @@ pub async fn fetch_colleges_with_filters(
 
 // Fetch distinct districts for filter dropdowns
 pub async fn fetch_all_districts(pool: &PgPool) -> Result<Vec<String>, sqlx::Error> {
-    sqlx::query_scalar::<_, String>("SELECT DISTINCT district FROM colleges ORDER BY district")
-        .fetch_all(pool)
-        .await
+    sqlx::query_scalar::<_, String>(
+        "SELECT DISTINCT district FROM colleges WHERE deleted_at IS NULL ORDER BY district",
+    )
+    .fetch_all(pool)
+    .await
 }
 
 // Fetch distinct categories for filter dropdowns
 pub async fn fetch_all_categories(pool: &PgPool) -> Result<Vec<String>, sqlx::Error> {
-    sqlx::query_scalar::<_, String>("SELECT DISTINCT category FROM colleges ORDER BY category")
-        .fetch_all(pool)
-        .await
+    sqlx::query_scalar::<_, String>(
+        "SELECT DISTINCT category FROM colleges WHERE deleted_at IS NULL ORDER BY category",
+    )
+    .fetch_all(pool)
+    .await
 }
 
 // Fetch distinct college types for filter dropdowns
 pub async fn fetch_all_college_types(pool: &PgPool) -> Result<Vec<String>, sqlx::Error> {
-    sqlx::query_scalar::<_, String>("SELECT DISTINCT type FROM colleges ORDER BY type")
-        .fetch_all(pool)
-        .await
+    sqlx::query_scalar::<_, String>(
+        "SELECT DISTINCT type FROM colleges WHERE deleted_at IS NULL ORDER BY type",
+    )
+    .fetch_all(pool)
+    .await
+}
+
+// Fetch distinct gender admission policies for filter dropdowns
+pub async fn fetch_all_gender_policies(pool: &PgPool) -> Result<Vec<String>, sqlx::Error> {
+    sqlx::query_scalar::<_, String>(
+        "SELECT DISTINCT gender_policy FROM colleges WHERE deleted_at IS NULL \
+         ORDER BY gender_policy",
+    )
+    .fetch_all(pool)
+    .await
+}
+
+// Fetch distinct instruction languages for filter dropdowns
+pub async fn fetch_all_instruction_languages(pool: &PgPool) -> Result<Vec<String>, sqlx::Error> {
+    sqlx::query_scalar::<_, String>(
+        "SELECT DISTINCT unnest(instruction_languages) AS language FROM colleges \
+         WHERE deleted_at IS NULL ORDER BY language",
+    )
+    .fetch_all(pool)
+    .await
+}
+
+// Count colleges per value of a filterable column, most common first
+async fn fetch_facet_counts(pool: &PgPool, sql: &str) -> Result<Vec<FacetCount>, sqlx::Error> {
+    sqlx::query_as::<_, FacetCount>(sql).fetch_all(pool).await
+}
+
+// Fetch value counts for every college facet
+pub async fn fetch_facets(pool: &PgPool) -> Result<Facets, sqlx::Error> {
+    Ok(Facets {
+        districts: fetch_facet_counts(
+            pool,
+            "SELECT district AS value, COUNT(*) AS count FROM colleges \
+             WHERE deleted_at IS NULL GROUP BY district ORDER BY count DESC, value",
+        )
+        .await?,
+        categories: fetch_facet_counts(
+            pool,
+            "SELECT category AS value, COUNT(*) AS count FROM colleges \
+             WHERE deleted_at IS NULL GROUP BY category ORDER BY count DESC, value",
+        )
+        .await?,
+        college_types: fetch_facet_counts(
+            pool,
+            "SELECT type AS value, COUNT(*) AS count FROM colleges \
+             WHERE deleted_at IS NULL GROUP BY type ORDER BY count DESC, value",
+        )
+        .await?,
+        gender_policies: fetch_facet_counts(
+            pool,
+            "SELECT gender_policy AS value, COUNT(*) AS count FROM colleges \
+             WHERE deleted_at IS NULL GROUP BY gender_policy ORDER BY count DESC, value",
+        )
+        .await?,
+        instruction_languages: fetch_facet_counts(
+            pool,
+            "SELECT lang AS value, COUNT(*) AS count \
+             FROM colleges, unnest(instruction_languages) AS lang \
+             WHERE deleted_at IS NULL GROUP BY lang ORDER BY count DESC, value",
+        )
+        .await?,
+    })
+}
+
+// Fetch fee structures for a college, most recent year first
+pub async fn fetch_fees_by_college(
+    pool: &PgPool,
+    college_id: i32,
+) -> Result<Vec<FeeStructure>, sqlx::Error> {
+    sqlx::query_as::<_, FeeStructure>(&format!(
+        "SELECT * FROM fee_structures WHERE college_id = $1 AND {} ORDER BY year DESC, course",
+        LIVE_COLLEGE_ID
+    ))
+    .bind(college_id)
+    .fetch_all(pool)
+    .await
+}
+
+// Fetch active concession rules that apply to the given fee year
+pub async fn fetch_active_concession_rules(
+    pool: &PgPool,
+    year: i32,
+) -> Result<Vec<ConcessionRule>, sqlx::Error> {
+    sqlx::query_as::<_, ConcessionRule>(
+        r#"
+        SELECT * FROM concession_rules
+        WHERE active AND (academic_year IS NULL OR academic_year = $1)
+        ORDER BY id
+        "#,
+    )
+    .bind(year)
+    .fetch_all(pool)
+    .await
+}
+
+// Fetch active scholarship schemes ordered by provider and name
+pub async fn fetch_active_scholarships(pool: &PgPool) -> Result<Vec<Scholarship>, sqlx::Error> {
+    sqlx::query_as::<_, Scholarship>(
+        "SELECT * FROM scholarships WHERE active ORDER BY provider, name",
+    )
+    .fetch_all(pool)
+    .await
+}
+
+// Fetch active schemes open to students of a college category (Engineering, Medical, ...)
+pub async fn fetch_scholarships_for_course(
+    pool: &PgPool,
+    course: &str,
+) -> Result<Vec<ScholarshipLink>, sqlx::Error> {
+    sqlx::query_as::<_, ScholarshipLink>(
+        r#"
+        SELECT id, name, provider, url FROM scholarships
+        WHERE active
+          AND (cardinality(courses) = 0
+               OR EXISTS (SELECT 1 FROM unnest(courses) c WHERE lower(c) = lower($1)))
+        ORDER BY provider, name
+        "#,
+    )
+    .bind(course)
+    .fetch_all(pool)
+    .await
+}
+
+// Fetch NAAC accreditation cycles for a college, most recent first
+pub async fn fetch_naac_by_college(
+    pool: &PgPool,
+    college_id: i32,
+) -> Result<Vec<NaacAccreditation>, sqlx::Error> {
+    sqlx::query_as::<_, NaacAccreditation>(&format!(
+        "SELECT * FROM naac_accreditations WHERE college_id = $1 AND {} \
+         ORDER BY valid_from DESC",
+        LIVE_COLLEGE_ID
+    ))
+    .bind(college_id)
+    .fetch_all(pool)
+    .await
+}
+
+// Fetch NBA-accredited programmes for a college
+pub async fn fetch_nba_by_college(
+    pool: &PgPool,
+    college_id: i32,
+) -> Result<Vec<NbaAccreditation>, sqlx::Error> {
+    sqlx::query_as::<_, NbaAccreditation>(&format!(
+        "SELECT * FROM nba_accreditations WHERE college_id = $1 AND {} \
+         ORDER BY programme, valid_from DESC",
+        LIVE_COLLEGE_ID
+    ))
+    .bind(college_id)
+    .fetch_all(pool)
+    .await
+}
+
+// Fetch NIRF rankings for a college, most recent year first
+pub async fn fetch_nirf_by_college(
+    pool: &PgPool,
+    college_id: i32,
+) -> Result<Vec<NirfRanking>, sqlx::Error> {
+    sqlx::query_as::<_, NirfRanking>(&format!(
+        "SELECT * FROM nirf_rankings WHERE college_id = $1 AND {} ORDER BY year DESC, category",
+        LIVE_COLLEGE_ID
+    ))
+    .bind(college_id)
+    .fetch_all(pool)
+    .await
+}
+
+// Fetch universities with the number of affiliated colleges
+pub async fn fetch_universities_with_counts(
+    pool: &PgPool,
+) -> Result<Vec<UniversityWithCount>, sqlx::Error> {
+    sqlx::query_as::<_, UniversityWithCount>(
+        r#"
+        SELECT u.id, u.name, u.short_name, u.city, u.university_type, u.website,
+               COUNT(c.id) AS college_count
+        FROM universities u
+        LEFT JOIN colleges c ON c.university_id = u.id AND c.deleted_at IS NULL
+        GROUP BY u.id
+        ORDER BY u.name
+        "#,
+    )
+    .fetch_all(pool)
+    .await
+}
+
+// Resolve a university short name or full name to its id
+pub async fn find_university_id<'e, E>(executor: E, name: &str) -> Result<Option<i32>, sqlx::Error>
+where
+    E: sqlx::PgExecutor<'e>,
+{
+    sqlx::query_scalar::<_, i32>(
+        "SELECT id FROM universities WHERE lower(short_name) = lower($1) OR lower(name) = lower($1)",
+    )
+    .bind(name.trim())
+    .fetch_optional(executor)
+    .await
+}
+
+// Fetch placement statistics for a college, most recent year first
+pub async fn fetch_placements_by_college(
+    pool: &PgPool,
+    college_id: i32,
+) -> Result<Vec<PlacementStat>, sqlx::Error> {
+    sqlx::query_as::<_, PlacementStat>(&format!(
+        "SELECT * FROM placement_stats WHERE college_id = $1 AND {} ORDER BY year DESC, branch",
+        LIVE_COLLEGE_ID
+    ))
+    .bind(college_id)
+    .fetch_all(pool)
+    .await
+}
+
+// Fetch facilities with the number of colleges offering each
+pub async fn fetch_facilities_with_counts(
+    pool: &PgPool,
+) -> Result<Vec<FacilityWithCount>, sqlx::Error> {
+    sqlx::query_as::<_, FacilityWithCount>(
+        r#"
+        SELECT f.id, f.slug, f.name, f.description, COUNT(cf.college_id) AS college_count
+        FROM facilities f
+        LEFT JOIN college_facilities cf ON cf.facility_id = f.id
+            AND cf.college_id IN (SELECT id FROM colleges WHERE deleted_at IS NULL)
+        GROUP BY f.id
+        ORDER BY f.name
+        "#,
+    )
+    .fetch_all(pool)
+    .await
+}
+
+// Fetch the facilities a college offers
+pub async fn fetch_facilities_by_college(
+    pool: &PgPool,
+    college_id: i32,
+) -> Result<Vec<Facility>, sqlx::Error> {
+    sqlx::query_as::<_, Facility>(&format!(
+        r#"
+        SELECT f.* FROM facilities f
+        JOIN college_facilities cf ON cf.facility_id = f.id
+        WHERE cf.college_id = $1 AND cf.{}
+        ORDER BY f.name
+        "#,
+        LIVE_COLLEGE_ID
+    ))
+    .bind(college_id)
+    .fetch_all(pool)
+    .await
+}
+
+// Fetch hostel details for a college
+pub async fn fetch_hostels_by_college(
+    pool: &PgPool,
+    college_id: i32,
+) -> Result<Vec<Hostel>, sqlx::Error> {
+    sqlx::query_as::<_, Hostel>(&format!(
+        "SELECT * FROM hostels WHERE college_id = $1 AND {} ORDER BY hostel_type",
+        LIVE_COLLEGE_ID
+    ))
+    .bind(college_id)
+    .fetch_all(pool)
+    .await
+}
+
+// Fetch published gallery images for a college, primary image first
+pub async fn fetch_images_by_college(
+    pool: &PgPool,
+    college_id: i32,
+) -> Result<Vec<CollegeImage>, sqlx::Error> {
+    sqlx::query_as::<_, CollegeImage>(&format!(
+        r#"
+        SELECT * FROM college_images
+        WHERE college_id = $1 AND published AND {}
+        ORDER BY is_primary DESC, id
+        "#,
+        LIVE_COLLEGE_ID
+    ))
+    .bind(college_id)
+    .fetch_all(pool)
+    .await
+}
+
+// Fetch the renditions generated for a set of published images, smallest first
+pub async fn fetch_renditions_by_images(
+    pool: &PgPool,
+    image_ids: &[i32],
+) -> Result<Vec<ImageRendition>, sqlx::Error> {
+    sqlx::query_as::<_, ImageRendition>(&format!(
+        r#"
+        SELECT * FROM image_renditions
+        WHERE image_id = ANY($1)
+          AND image_id IN (SELECT id FROM college_images WHERE published AND {})
+        ORDER BY image_id, width
+        "#,
+        LIVE_COLLEGE_ID
+    ))
+    .bind(image_ids)
+    .fetch_all(pool)
+    .await
+}
+
+// Attachment columns plus the download URL
+pub(crate) const ATTACHMENT_SELECT: &str =
+    "SELECT *, '/attachments/' || id AS url FROM college_attachments";
+
+// Fetch documents attached to a college, newest academic year first
+pub async fn fetch_attachments_by_college(
+    pool: &PgPool,
+    college_id: i32,
+) -> Result<Vec<Attachment>, sqlx::Error> {
+    sqlx::query_as::<_, Attachment>(&format!(
+        "{} WHERE college_id = $1 AND {} \
+         ORDER BY academic_year DESC NULLS LAST, document_type, title",
+        ATTACHMENT_SELECT, LIVE_COLLEGE_ID
+    ))
+    .bind(college_id)
+    .fetch_all(pool)
+    .await
+}
+
+// Fetch a single attachment by ID
+pub async fn fetch_attachment_by_id(pool: &PgPool, id: i32) -> Result<Attachment, sqlx::Error> {
+    sqlx::query_as::<_, Attachment>(&format!(
+        "{} WHERE id = $1 AND {}",
+        ATTACHMENT_SELECT, LIVE_COLLEGE_ID
+    ))
+    .bind(id)
+    .fetch_one(pool)
+    .await
+}
+
+// Count the records that reference a college and are removed when it is purged
+pub async fn fetch_college_dependents<'e, E>(
+    executor: E,
+    college_id: i32,
+) -> Result<CollegeDependents, sqlx::Error>
+where
+    E: sqlx::PgExecutor<'e>,
+{
+    sqlx::query_as::<_, CollegeDependents>(
+        r#"
+        SELECT
+            (SELECT COUNT(*) FROM contact_info WHERE college_id = $1) AS contact_info,
+            (SELECT COUNT(*) FROM cutoffs WHERE college_id = $1) AS cutoffs,
+            (SELECT COUNT(*) FROM fee_structures WHERE college_id = $1) AS fee_structures,
+            (SELECT COUNT(*) FROM placement_stats WHERE college_id = $1) AS placement_stats,
+            (SELECT COUNT(*) FROM naac_accreditations WHERE college_id = $1) AS naac_accreditations,
+            (SELECT COUNT(*) FROM nba_accreditations WHERE college_id = $1) AS nba_accreditations,
+            (SELECT COUNT(*) FROM nirf_rankings WHERE college_id = $1) AS nirf_rankings,
+            (SELECT COUNT(*) FROM college_facilities WHERE college_id = $1) AS facilities,
+            (SELECT COUNT(*) FROM hostels WHERE college_id = $1) AS hostels,
+            (SELECT COUNT(*) FROM college_images WHERE college_id = $1) AS images,
+            (SELECT COUNT(*) FROM college_attachments WHERE college_id = $1) AS attachments
+        "#,
+    )
+    .bind(college_id)
+    .fetch_one(executor)
+    .await
 }
@@ -0,0 +1,182 @@
+use crate::auth::{CurrentAdmin, Permission};
+use crate::errors::ApiError;
+use crate::handlers::admin::{import_csv, read_csv_file, UploadResponse};
+use crate::models::NaacGrade;
+use axum::{extract::Multipart, Extension, Json};
+use chrono::NaiveDate;
+use serde::Deserialize;
+use sqlx::PgPool;
+
+#[derive(Deserialize)]
+pub struct NaacImport {
+    college_id: i32,
+    grade: NaacGrade,
+    cgpa: Option<f64>,
+    cycle: Option<i32>,
+    valid_from: NaiveDate,
+    valid_until: NaiveDate,
+}
+
+#[derive(Deserialize)]
+pub struct NbaImport {
+    college_id: i32,
+    programme: String,
+    valid_from: NaiveDate,
+    valid_until: NaiveDate,
+}
+
+#[derive(Deserialize)]
+pub struct NirfImport {
+    college_id: i32,
+    year: i32,
+    category: String,
+    rank: Option<i32>,
+    rank_band: Option<String>,
+    score: Option<f64>,
+}
+
+fn validate_validity(valid_from: NaiveDate, valid_until: NaiveDate) -> Result<(), String> {
+    if valid_until < valid_from {
+        return Err("valid_until must not be before valid_from".to_string());
+    }
+    Ok(())
+}
+
+// CSV Upload Handler for NAAC accreditation cycles
+pub async fn upload_naac_csv(
+    Extension(pool): Extension<PgPool>,
+    admin: CurrentAdmin,
+    mut multipart: Multipart,
+) -> Result<Json<UploadResponse>, ApiError> {
+    admin.require(Permission::Upload)?;
+
+    let content = read_csv_file(&mut multipart).await?;
+
+    let response = import_csv("NAAC CSV upload completed", &content, |row: NaacImport| {
+        let pool = pool.clone();
+        async move {
+            validate_validity(row.valid_from, row.valid_until)?;
+            if row.cgpa.is_some_and(|cgpa| !(0.0..=4.0).contains(&cgpa)) {
+                return Err("cgpa must be between 0 and 4".to_string());
+            }
+
+            sqlx::query_scalar::<_, bool>(
+                r#"
+                INSERT INTO naac_accreditations (college_id, grade, cgpa, cycle, valid_from, valid_until)
+                VALUES ($1, $2, $3, $4, $5, $6)
+                ON CONFLICT (college_id, valid_from)
+                DO UPDATE SET
+                    grade = EXCLUDED.grade,
+                    cgpa = EXCLUDED.cgpa,
+                    cycle = EXCLUDED.cycle,
+                    valid_until = EXCLUDED.valid_until
+                RETURNING (xmax <> 0) AS was_update
+                "#,
+            )
+            .bind(row.college_id)
+            .bind(row.grade.as_str())
+            .bind(row.cgpa)
+            .bind(row.cycle)
+            .bind(row.valid_from)
+            .bind(row.valid_until)
+            .fetch_one(&pool)
+            .await
+            .map_err(|e| e.to_string())
+        }
+    })
+    .await;
+
+    Ok(Json(response))
+}
+
+// CSV Upload Handler for NBA-accredited programmes
+pub async fn upload_nba_csv(
+    Extension(pool): Extension<PgPool>,
+    admin: CurrentAdmin,
+    mut multipart: Multipart,
+) -> Result<Json<UploadResponse>, ApiError> {
+    admin.require(Permission::Upload)?;
+
+    let content = read_csv_file(&mut multipart).await?;
+
+    let response = import_csv("NBA CSV upload completed", &content, |row: NbaImport| {
+        let pool = pool.clone();
+        async move {
+            if row.programme.trim().is_empty() {
+                return Err("programme is required".to_string());
+            }
+            validate_validity(row.valid_from, row.valid_until)?;
+
+            sqlx::query_scalar::<_, bool>(
+                r#"
+                INSERT INTO nba_accreditations (college_id, programme, valid_from, valid_until)
+                VALUES ($1, $2, $3, $4)
+                ON CONFLICT (college_id, programme, valid_from)
+                DO UPDATE SET valid_until = EXCLUDED.valid_until
+                RETURNING (xmax <> 0) AS was_update
+                "#,
+            )
+            .bind(row.college_id)
+            .bind(row.programme.trim())
+            .bind(row.valid_from)
+            .bind(row.valid_until)
+            .fetch_one(&pool)
+            .await
+            .map_err(|e| e.to_string())
+        }
+    })
+    .await;
+
+    Ok(Json(response))
+}
+
+// CSV Upload Handler for NIRF rankings
+pub async fn upload_nirf_csv(
+    Extension(pool): Extension<PgPool>,
+    admin: CurrentAdmin,
+    mut multipart: Multipart,
+) -> Result<Json<UploadResponse>, ApiError> {
+    admin.require(Permission::Upload)?;
+
+    let content = read_csv_file(&mut multipart).await?;
+
+    let response = import_csv("NIRF CSV upload completed", &content, |row: NirfImport| {
+        let pool = pool.clone();
+        async move {
+            if row.category.trim().is_empty() {
+                return Err("category is required".to_string());
+            }
+            if row.rank.is_none() && row.rank_band.is_none() {
+                return Err("either rank or rank_band is required".to_string());
+            }
+            if row.rank.is_some_and(|rank| rank < 1) {
+                return Err("rank must be positive".to_string());
+            }
+
+            sqlx::query_scalar::<_, bool>(
+                r#"
+                INSERT INTO nirf_rankings (college_id, year, category, rank, rank_band, score)
+                VALUES ($1, $2, $3, $4, $5, $6)
+                ON CONFLICT (college_id, year, category)
+                DO UPDATE SET
+                    rank = EXCLUDED.rank,
+                    rank_band = EXCLUDED.rank_band,
+                    score = EXCLUDED.score
+                RETURNING (xmax <> 0) AS was_update
+                "#,
+            )
+            .bind(row.college_id)
+            .bind(row.year)
+            .bind(row.category.trim())
+            .bind(row.rank)
+            .bind(&row.rank_band)
+            .bind(row.score)
+            .fetch_one(&pool)
+            .await
+            .map_err(|e| e.to_string())
+        }
+    })
+    .await;
+
+    Ok(Json(response))
+}
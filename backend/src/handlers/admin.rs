@@ -1,28 +1,117 @@
+use crate::audit::{self, AuditSource};
+use crate::auth::require_admin;
+use crate::auth::{CurrentAdmin, Permission};
+use crate::csv_stream::CsvStream;
+use crate::db::queries;
 use crate::errors::ApiError;
+use crate::handlers::{
+    accreditation, attachment, audit_log, auth, college, concession, cutoff, facility, fee, image,
+    import_job, placement, representative, scholarship, university,
+};
+use crate::models::college::{parse_gender_policy, parse_languages};
+use crate::spreadsheet::Sheet;
 use axum::{
-    extract::{Extension, Multipart},
-    response::Json,
-    routing::{get, post},
+    body::Bytes,
+    extract::{multipart::Field, DefaultBodyLimit, Extension, Multipart, Query},
+    http::{header, HeaderMap, StatusCode},
+    middleware,
+    response::{IntoResponse, Json, Response},
+    routing::{delete, get, post, put},
     Router,
 };
-use serde::{Deserialize, Serialize};
-use sqlx::PgPool;
-use std::collections::HashMap;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+use sqlx::{Acquire, PgPool, Postgres, Transaction};
+use std::collections::{HashMap, HashSet};
+use std::future::Future;
+use std::hash::Hash;
+use std::time::Instant;
 
 #[derive(Serialize)]
 pub struct UploadResponse {
-    message: String,
-    inserted: usize,
-    updated: usize,
-    errors: Vec<String>,
+    pub message: String,
+    pub inserted: usize,
+    pub updated: usize,
+    pub errors: Vec<String>,
 }
 
-#[derive(Deserialize)]
+/// Query parameters for the college imports.
+#[derive(Deserialize, Default)]
+pub struct ImportOptions {
+    /// Validate every row and report what it would change, without writing anything
+    #[serde(default)]
+    pub dry_run: bool,
+    /// Write every row in one transaction, rolled back if any row fails
+    #[serde(default)]
+    pub atomic: bool,
+}
+
+/// Largest accepted spreadsheet upload, in bytes. Workbooks are read whole, unlike
+/// the streamed CSV uploads.
+pub const MAX_SPREADSHEET_BYTES: usize = 50 * 1024 * 1024;
+
+/// Query parameters choosing what to import from an uploaded workbook.
+#[derive(Deserialize, Default)]
+pub struct SheetOptions {
+    /// Sheet name; the first sheet by default
+    pub sheet: Option<String>,
+    /// Row holding the column names, counted from 1 as in the spreadsheet; rows
+    /// above it are skipped (default 1)
+    pub header_row: Option<usize>,
+}
+
+impl SheetOptions {
+    // Read the chosen sheet of a workbook off the async runtime
+    pub(crate) async fn read(&self, data: Bytes) -> Result<Sheet, ApiError> {
+        if self.header_row == Some(0) {
+            return Err(ApiError::BadRequest(
+                "header_row must be at least 1".to_string(),
+            ));
+        }
+        let name = self.sheet.clone();
+        let header_row = self.header_row.unwrap_or(1);
+        tokio::task::spawn_blocking(move || Sheet::read(&data, name.as_deref(), header_row))
+            .await
+            .map_err(|e| {
+                ApiError::InternalServerError(format!("Spreadsheet processing failed: {}", e))
+            })?
+            .map_err(ApiError::BadRequest)
+    }
+}
+
+/// What importing one row would do, as reported by a dry run.
+#[derive(Serialize)]
+pub struct RowPreview {
+    /// CSV line number, or position in the JSON `colleges` array (from 1)
+    pub row: usize,
+    pub name: String,
+    pub district: String,
+    pub city: String,
+    /// insert, update or unchanged
+    pub action: &'static str,
+    /// Fields that would change, keyed by import column, as `{"before": .., "after": ..}`
+    pub changes: Map<String, Value>,
+}
+
+/// Response for a dry-run import. Rows that would rewrite identical values are
+/// counted as unchanged rather than updated.
+#[derive(Serialize)]
+pub struct ImportPreview {
+    pub message: String,
+    pub dry_run: bool,
+    pub inserted: usize,
+    pub updated: usize,
+    pub unchanged: usize,
+    pub errors: Vec<String>,
+    pub rows: Vec<RowPreview>,
+}
+
+#[derive(Serialize, Deserialize)]
 pub struct BulkCollegeData {
-    colleges: Vec<CollegeImport>,
+    pub(crate) colleges: Vec<CollegeImport>,
 }
 
-#[derive(Deserialize)]
+#[derive(Serialize, Deserialize)]
 pub struct CollegeImport {
     name: String,
     category: String,
@@ -38,113 +127,1183 @@ pub struct CollegeImport {
     website: Option<String>,
     address: Option<String>,
     pincode: Option<String>,
+    /// Affiliating university, by short name (SPPU) or full name
+    university: Option<String>,
+    /// co-ed, women-only or men-only
+    gender_policy: Option<String>,
+    /// Comma- or semicolon-separated, e.g. "Marathi;English"
+    instruction_languages: Option<String>,
+    // Hostel details; mess, distance and first-year guarantee apply to both hostels
+    boys_hostel_capacity: Option<i32>,
+    boys_hostel_fee: Option<i32>,
+    girls_hostel_capacity: Option<i32>,
+    girls_hostel_fee: Option<i32>,
+    hostel_mess_available: Option<bool>,
+    hostel_distance_km: Option<f64>,
+    hostel_first_year_guaranteed: Option<bool>,
 }
 
-// JSON Upload Handler
+impl CollegeImport {
+    // Reject values the database would refuse, so a dry run catches them too
+    fn validate(&self) -> Result<(), String> {
+        let lengths = [
+            ("name", Some(&self.name), 255),
+            ("category", Some(&self.category), 50),
+            ("district", Some(&self.district), 100),
+            ("city", Some(&self.city), 100),
+            ("type", Some(&self.r#type), 50),
+            ("phone", self.phone.as_ref(), 20),
+            ("email", self.email.as_ref(), 255),
+            ("website", self.website.as_ref(), 255),
+            ("pincode", self.pincode.as_ref(), 10),
+        ];
+        for (column, value, max) in lengths {
+            if value.is_some_and(|value| value.chars().count() > max) {
+                return Err(format!("{} must be at most {} characters", column, max));
+            }
+        }
+        if self.name.trim().is_empty() {
+            return Err("name is required".to_string());
+        }
+
+        let hostel_numbers = [
+            self.boys_hostel_capacity,
+            self.boys_hostel_fee,
+            self.girls_hostel_capacity,
+            self.girls_hostel_fee,
+        ];
+        if hostel_numbers.iter().flatten().any(|number| *number < 0)
+            || self.hostel_distance_km.is_some_and(|km| km < 0.0)
+        {
+            return Err("hostel capacities, fees and distance cannot be negative".to_string());
+        }
+        Ok(())
+    }
+}
+
+// Values derived from an import row before it is written
+struct ResolvedImport {
+    university_id: Option<i32>,
+    gender_policy: Option<String>,
+    instruction_languages: Option<Vec<String>>,
+}
+
+// Validate a row and resolve its university, gender policy and languages; each
+// university is looked up once per import
+async fn resolve_import<'e, E>(
+    executor: E,
+    universities: &mut UniversityCache,
+    college: &CollegeImport,
+) -> Result<ResolvedImport, String>
+where
+    E: sqlx::PgExecutor<'e>,
+{
+    college.validate()?;
+
+    // Resolve the affiliating university, if one was given
+    let university_id = match college.university.as_deref() {
+        Some(name) if !name.trim().is_empty() => {
+            let id = match universities.get(name) {
+                Some(id) => *id,
+                None => {
+                    let id = queries::find_university_id(executor, name)
+                        .await
+                        .map_err(|e| e.to_string())?;
+                    universities.insert(name.to_string(), id);
+                    id
+                }
+            };
+            Some(id.ok_or_else(|| format!("Unknown university '{}'", name.trim()))?)
+        }
+        _ => None,
+    };
+
+    let gender_policy = college
+        .gender_policy
+        .as_deref()
+        .filter(|policy| !policy.trim().is_empty())
+        .map(parse_gender_policy)
+        .transpose()?;
+    let instruction_languages = college
+        .instruction_languages
+        .as_deref()
+        .map(parse_languages)
+        .filter(|languages| !languages.is_empty());
+
+    Ok(ResolvedImport {
+        university_id,
+        gender_policy,
+        instruction_languages,
+    })
+}
+
+// Plans an import without writing anything. Colleges are keyed by (name, district,
+// city) like the upsert, and a college listed twice is diffed against its earlier row.
+struct ImportPlanner {
+    planned: HashMap<(String, String, String), Map<String, Value>>,
+    universities: UniversityCache,
+    inserted: usize,
+    updated: usize,
+    unchanged: usize,
+    rows: Vec<RowPreview>,
+}
+
+impl ImportPlanner {
+    fn new() -> Self {
+        ImportPlanner {
+            planned: HashMap::new(),
+            universities: HashMap::new(),
+            inserted: 0,
+            updated: 0,
+            unchanged: 0,
+            rows: Vec::new(),
+        }
+    }
+
+    async fn plan(
+        &mut self,
+        pool: &PgPool,
+        row: usize,
+        college: CollegeImport,
+    ) -> Result<(), String> {
+        let resolved = resolve_import(pool, &mut self.universities, &college).await?;
+        let key = (
+            college.name.clone(),
+            college.district.clone(),
+            college.city.clone(),
+        );
+
+        let current = match self.planned.get(&key) {
+            Some(fields) => Some(fields.clone()),
+            None => match fetch_import_fields(pool, &college)
+                .await
+                .map_err(|e| e.to_string())?
+            {
+                Some((true, _)) => {
+                    return Err(format!(
+                        "College '{}' was deleted; restore it before importing it again",
+                        college.name
+                    ))
+                }
+                Some((false, fields)) => Some(fields),
+                None => None,
+            },
+        };
+
+        let after = planned_import_fields(current.as_ref(), &college, &resolved);
+        let mut changes = Map::new();
+        for (column, value) in &after {
+            let before = current
+                .as_ref()
+                .and_then(|fields| fields.get(column))
+                .unwrap_or(&Value::Null);
+            if !same_value(before, value) {
+                changes.insert(column.clone(), json!({ "before": before, "after": value }));
+            }
+        }
+
+        let action = if current.is_none() {
+            self.inserted += 1;
+            "insert"
+        } else if changes.is_empty() {
+            self.unchanged += 1;
+            "unchanged"
+        } else {
+            self.updated += 1;
+            "update"
+        };
+        self.rows.push(RowPreview {
+            row,
+            name: college.name,
+            district: college.district,
+            city: college.city,
+            action,
+            changes,
+        });
+        self.planned.insert(key, after);
+        Ok(())
+    }
+
+    fn into_preview(self, message: &str, errors: Vec<String>) -> ImportPreview {
+        ImportPreview {
+            message: message.to_string(),
+            dry_run: true,
+            inserted: self.inserted,
+            updated: self.updated,
+            unchanged: self.unchanged,
+            errors,
+            rows: self.rows,
+        }
+    }
+}
+
+// Fetch whether a college is deleted and the current values an import row can
+// change, keyed by import column
+async fn fetch_import_fields(
+    pool: &PgPool,
+    college: &CollegeImport,
+) -> Result<Option<(bool, Map<String, Value>)>, sqlx::Error> {
+    let row: Option<(bool, sqlx::types::Json<Map<String, Value>>)> = sqlx::query_as(
+        r#"
+        SELECT c.deleted_at IS NOT NULL, jsonb_build_object(
+            'category', c.category, 'type', c.type, 'autonomous', c.autonomous,
+            'minority', c.minority, 'hostel_available', c.hostel_available,
+            'established_year', c.established_year, 'university_id', c.university_id,
+            'gender_policy', c.gender_policy, 'instruction_languages', c.instruction_languages,
+            'phone', ci.phone, 'email', ci.email, 'website', ci.website,
+            'address', ci.address, 'pincode', ci.pincode,
+            'boys_hostel_capacity', b.capacity, 'boys_hostel_fee', b.annual_fee,
+            'boys_hostel_mess_available', b.mess_available,
+            'boys_hostel_distance_km', b.distance_km,
+            'boys_hostel_first_year_guaranteed', b.first_year_guaranteed,
+            'girls_hostel_capacity', g.capacity, 'girls_hostel_fee', g.annual_fee,
+            'girls_hostel_mess_available', g.mess_available,
+            'girls_hostel_distance_km', g.distance_km,
+            'girls_hostel_first_year_guaranteed', g.first_year_guaranteed
+        )
+        FROM colleges c
+        LEFT JOIN contact_info ci ON ci.college_id = c.id
+        LEFT JOIN hostels b ON b.college_id = c.id AND b.hostel_type = 'boys'
+        LEFT JOIN hostels g ON g.college_id = c.id AND g.hostel_type = 'girls'
+        WHERE c.name = $1 AND c.district = $2 AND c.city = $3
+        "#,
+    )
+    .bind(&college.name)
+    .bind(&college.district)
+    .bind(&college.city)
+    .fetch_optional(pool)
+    .await?;
+    Ok(row.map(|(deleted, fields)| (deleted, fields.0)))
+}
+
+// The values a college would have after importing `college`, following the same
+// rules as write_college: college columns are overwritten, while the
+// university, gender policy, languages, contact info and hostel details keep their
+// current values when the row leaves them out
+fn planned_import_fields(
+    current: Option<&Map<String, Value>>,
+    college: &CollegeImport,
+    resolved: &ResolvedImport,
+) -> Map<String, Value> {
+    let keep = |column: &str, new: Value, default: Value| {
+        if !new.is_null() {
+            return new;
+        }
+        current
+            .and_then(|fields| fields.get(column))
+            .filter(|value| !value.is_null())
+            .cloned()
+            .unwrap_or(default)
+    };
+
+    let mut fields = Map::new();
+    fields.insert("category".into(), json!(college.category));
+    fields.insert("type".into(), json!(college.r#type));
+    fields.insert(
+        "autonomous".into(),
+        json!(college.autonomous.unwrap_or(false)),
+    );
+    fields.insert("minority".into(), json!(college.minority.unwrap_or(false)));
+    fields.insert(
+        "hostel_available".into(),
+        json!(college.hostel_available.unwrap_or(false)),
+    );
+    fields.insert("established_year".into(), json!(college.established_year));
+    fields.insert(
+        "university_id".into(),
+        keep("university_id", json!(resolved.university_id), Value::Null),
+    );
+    fields.insert(
+        "gender_policy".into(),
+        keep(
+            "gender_policy",
+            json!(resolved.gender_policy),
+            json!("co-ed"),
+        ),
+    );
+    fields.insert(
+        "instruction_languages".into(),
+        keep(
+            "instruction_languages",
+            json!(resolved.instruction_languages),
+            json!([]),
+        ),
+    );
+
+    let contact = [
+        ("phone", &college.phone),
+        ("email", &college.email),
+        ("website", &college.website),
+        ("address", &college.address),
+        ("pincode", &college.pincode),
+    ];
+    for (column, value) in contact {
+        fields.insert(column.into(), keep(column, json!(value), Value::Null));
+    }
+
+    let hostels = [
+        (
+            "boys",
+            college.boys_hostel_capacity,
+            college.boys_hostel_fee,
+        ),
+        (
+            "girls",
+            college.girls_hostel_capacity,
+            college.girls_hostel_fee,
+        ),
+    ];
+    for (hostel_type, capacity, annual_fee) in hostels {
+        // Hostel details are only written for hostels the row gives a capacity or fee for
+        let touched = capacity.is_some() || annual_fee.is_some();
+        let values = [
+            ("capacity", json!(capacity)),
+            ("fee", json!(annual_fee)),
+            ("mess_available", json!(college.hostel_mess_available)),
+            ("distance_km", json!(college.hostel_distance_km)),
+            (
+                "first_year_guaranteed",
+                json!(college.hostel_first_year_guaranteed),
+            ),
+        ];
+        for (field, value) in values {
+            let column = format!("{}_hostel_{}", hostel_type, field);
+            let value = if touched { value } else { Value::Null };
+            let planned = keep(&column, value, Value::Null);
+            fields.insert(column, planned);
+        }
+    }
+
+    fields
+}
+
+// Compare JSON values, treating 2 and 2.0 as equal since the database returns whole
+// numbers without a fraction
+fn same_value(a: &Value, b: &Value) -> bool {
+    match (a, b) {
+        (Value::Number(a), Value::Number(b)) => a.as_f64() == b.as_f64(),
+        _ => a == b,
+    }
+}
+
+// JSON Upload Handler; with `dry_run=true` it only reports what the upload would change
 pub async fn upload_colleges_json(
+    Query(options): Query<ImportOptions>,
     Extension(pool): Extension<PgPool>,
+    admin: CurrentAdmin,
     Json(data): Json<BulkCollegeData>,
-) -> Result<Json<UploadResponse>, ApiError> {
-    println!("📊 Processing {} colleges", data.colleges.len());
+) -> Result<Response, ApiError> {
+    admin.require(Permission::Upload)?;
+
+    if options.dry_run {
+        let mut planner = ImportPlanner::new();
+        let mut errors = Vec::new();
+        for (index, college) in data.colleges.into_iter().enumerate() {
+            if let Err(e) = planner.plan(&pool, index + 1, college).await {
+                errors.push(format!("College #{}: {}", index + 1, e));
+            }
+        }
+        return Ok(Json(planner.into_preview("Dry run completed", errors)).into_response());
+    }
+
+    // Imported in the background; the response is the job to follow
+    let job = import_job::start_json_job(&pool, &admin, options.atomic, &data).await?;
+    Ok((StatusCode::ACCEPTED, Json(job)).into_response())
+}
+
+// Largest accepted college or cutoff CSV upload, from `MAX_CSV_UPLOAD_BYTES` (default
+// 100 MiB)
+pub(crate) fn max_csv_upload_bytes() -> u64 {
+    std::env::var("MAX_CSV_UPLOAD_BYTES")
+        .ok()
+        .and_then(|bytes| bytes.parse().ok())
+        .filter(|bytes| *bytes > 0)
+        .unwrap_or(100 * 1024 * 1024)
+}
+
+// Refuse a CSV upload whose declared size is over `max_bytes` before reading any of it
+pub(crate) fn check_declared_size(headers: &HeaderMap, max_bytes: u64) -> Result<(), ApiError> {
+    let declared = headers
+        .get(header::CONTENT_LENGTH)
+        .and_then(|length| length.to_str().ok())
+        .and_then(|length| length.parse::<u64>().ok());
+    if declared.is_some_and(|length| length > max_bytes) {
+        return Err(ApiError::PayloadTooLarge(format!(
+            "CSV upload exceeds the limit of {} bytes",
+            max_bytes
+        )));
+    }
+    Ok(())
+}
+
+// Read the next chunk of an uploaded file, failing once `received` passes `max_bytes`
+pub(crate) async fn next_upload_chunk(
+    field: &mut Field<'_>,
+    received: &mut u64,
+    max_bytes: u64,
+) -> Result<Option<Bytes>, ApiError> {
+    let chunk = field
+        .chunk()
+        .await
+        .map_err(|e| ApiError::BadRequest(format!("Failed to read file: {}", e)))?;
+    if let Some(chunk) = &chunk {
+        *received += chunk.len() as u64;
+        if *received > max_bytes {
+            return Err(ApiError::PayloadTooLarge(format!(
+                "CSV upload exceeds the limit of {} bytes",
+                max_bytes
+            )));
+        }
+    }
+    Ok(chunk)
+}
+
+// CSV Upload Handler; the file is stored and imported by a background job whose id is
+// returned straight away, or with `dry_run=true` it only reports what the upload would
+// change. The file is read as it arrives, so its size is bounded only by
+// `MAX_CSV_UPLOAD_BYTES`.
+pub async fn upload_colleges_csv(
+    Query(options): Query<ImportOptions>,
+    Extension(pool): Extension<PgPool>,
+    admin: CurrentAdmin,
+    headers: HeaderMap,
+    mut multipart: Multipart,
+) -> Result<Response, ApiError> {
+    admin.require(Permission::Upload)?;
+
+    let max_bytes = max_csv_upload_bytes();
+    check_declared_size(&headers, max_bytes)?;
+
+    if !options.dry_run {
+        let job = import_job::start_csv_job(
+            &pool,
+            &admin,
+            "colleges_csv",
+            options.atomic,
+            &mut multipart,
+            max_bytes,
+        )
+        .await?;
+        return Ok((StatusCode::ACCEPTED, Json(job)).into_response());
+    }
 
-    let mut inserted = 0;
-    let mut updated = 0;
     let mut errors = Vec::new();
+    let mut planner = ImportPlanner::new();
+    let mut received: u64 = 0;
 
-    for (index, college) in data.colleges.into_iter().enumerate() {
-        match insert_or_update_college(&pool, college).await {
-            Ok(was_update) => {
-                if was_update {
-                    updated += 1;
-                } else {
-                    inserted += 1;
+    while let Some(mut field) = multipart
+        .next_field()
+        .await
+        .map_err(|e| ApiError::BadRequest(format!("Invalid file upload: {}", e)))?
+    {
+        if field.name() != Some("file") {
+            continue;
+        }
+
+        let mut stream = CsvStream::new();
+        loop {
+            let chunk = next_upload_chunk(&mut field, &mut received, max_bytes).await?;
+            let rows = match &chunk {
+                Some(chunk) => stream.feed::<CollegeImport>(chunk),
+                None => stream.finish(),
+            };
+
+            for (line, row) in rows {
+                let result = match row {
+                    Ok(college) => planner.plan(&pool, line, college).await,
+                    Err(e) => Err(format!("CSV parsing error: {}", e)),
+                };
+                if let Err(e) = result {
+                    errors.push(format!("Line {}: {}", line, e));
                 }
-                println!("✅ Processed college #{}", index + 1);
             }
-            Err(e) => {
-                let error_msg = format!("College #{}: {}", index + 1, e);
-                errors.push(error_msg);
-                println!("❌ Error processing college #{}: {}", index + 1, e);
+
+            if chunk.is_none() {
+                break;
             }
         }
     }
 
-    Ok(Json(UploadResponse {
-        message: "Upload completed".to_string(),
-        inserted,
-        updated,
-        errors,
-    }))
+    Ok(Json(planner.into_preview("CSV dry run completed", errors)).into_response())
 }
 
-// CSV Upload Handler
-pub async fn upload_colleges_csv(
-    mut multipart: Multipart,
+// Spreadsheet Upload Handler; an XLSX or ODS workbook with the columns of the CSV
+// upload, imported by a background job, or with `dry_run=true` only previewed.
+// `sheet` and `header_row` choose the table to read.
+pub async fn upload_colleges_spreadsheet(
+    Query(options): Query<ImportOptions>,
+    Query(sheet_options): Query<SheetOptions>,
     Extension(pool): Extension<PgPool>,
-) -> Result<Json<UploadResponse>, ApiError> {
-    let mut inserted = 0;
-    let mut updated = 0;
+    admin: CurrentAdmin,
+    mut multipart: Multipart,
+) -> Result<Response, ApiError> {
+    admin.require(Permission::Upload)?;
+
+    let data = read_upload(&mut multipart).await?;
+    let sheet = sheet_options.read(data.clone()).await?;
+
+    if !options.dry_run {
+        let job = import_job::start_spreadsheet_job(
+            &pool,
+            &admin,
+            "colleges_spreadsheet",
+            options.atomic,
+            &data,
+            &sheet,
+        )
+        .await?;
+        return Ok((StatusCode::ACCEPTED, Json(job)).into_response());
+    }
+
     let mut errors = Vec::new();
+    let mut planner = ImportPlanner::new();
+    for (row, result) in sheet.rows::<CollegeImport>() {
+        let result = match result {
+            Ok(college) => planner.plan(&pool, row, college).await,
+            Err(e) => Err(e),
+        };
+        if let Err(e) = result {
+            errors.push(format!("{}{}: {}", sheet.label(), row, e));
+        }
+    }
 
+    Ok(Json(planner.into_preview("Spreadsheet dry run completed", errors)).into_response())
+}
+
+// Read the multipart field named "file" as UTF-8 text
+pub(crate) async fn read_csv_file(multipart: &mut Multipart) -> Result<String, ApiError> {
+    let data = read_upload(multipart).await?;
+    String::from_utf8(data.to_vec())
+        .map_err(|e| ApiError::BadRequest(format!("Invalid UTF-8 content: {}", e)))
+}
+
+// Read the multipart field named "file" whole
+pub(crate) async fn read_upload(multipart: &mut Multipart) -> Result<Bytes, ApiError> {
     while let Some(field) = multipart
         .next_field()
         .await
         .map_err(|e| ApiError::BadRequest(format!("Invalid file upload: {}", e)))?
     {
         if field.name() == Some("file") {
-            let data = field
+            return field
                 .bytes()
                 .await
-                .map_err(|e| ApiError::BadRequest(format!("Failed to read file: {}", e)))?;
-
-            let content = String::from_utf8(data.to_vec())
-                .map_err(|e| ApiError::BadRequest(format!("Invalid UTF-8 content: {}", e)))?;
-
-            let mut reader = csv::Reader::from_reader(content.as_bytes());
-
-            for (line_num, result) in reader.deserialize().enumerate() {
-                match result {
-                    Ok(record) => {
-                        let college: CollegeImport = record;
-                        match insert_or_update_college(&pool, college).await {
-                            Ok(was_update) => {
-                                if was_update {
-                                    updated += 1;
-                                } else {
-                                    inserted += 1;
-                                }
-                            }
-                            Err(e) => {
-                                errors.push(format!("Line {}: {}", line_num + 2, e));
-                            }
-                        }
-                    }
-                    Err(e) => {
-                        errors.push(format!("Line {}: CSV parsing error: {}", line_num + 2, e));
-                    }
-                }
+                .map_err(|e| ApiError::BadRequest(format!("Failed to read file: {}", e)));
+        }
+    }
+
+    Err(ApiError::BadRequest(
+        "Missing multipart field 'file'".to_string(),
+    ))
+}
+
+// Run each CSV record through `import_row`, which returns Ok(true) for an update and
+// Ok(false) for an insert; parse and row errors are collected with their line numbers
+pub(crate) async fn import_csv<T, F, Fut>(
+    message: &str,
+    content: &str,
+    mut import_row: F,
+) -> UploadResponse
+where
+    T: DeserializeOwned,
+    F: FnMut(T) -> Fut,
+    Fut: Future<Output = Result<bool, String>>,
+{
+    let mut inserted = 0;
+    let mut updated = 0;
+    let mut errors = Vec::new();
+
+    let mut reader = csv::Reader::from_reader(content.as_bytes());
+
+    for (line_num, result) in reader.deserialize().enumerate() {
+        match result {
+            Ok(record) => match import_row(record).await {
+                Ok(true) => updated += 1,
+                Ok(false) => inserted += 1,
+                Err(e) => errors.push(format!("Line {}: {}", line_num + 2, e)),
+            },
+            Err(e) => {
+                errors.push(format!("Line {}: CSV parsing error: {}", line_num + 2, e));
             }
         }
     }
 
-    Ok(Json(UploadResponse {
-        message: "CSV upload completed".to_string(),
+    UploadResponse {
+        message: message.to_string(),
         inserted,
         updated,
         errors,
-    }))
+    }
 }
 
-// Database Insert/Update Function
-async fn insert_or_update_college(
-    pool: &PgPool,
+// Rows written per batch of set-based upserts
+pub(crate) const IMPORT_BATCH_SIZE: usize = 500;
+
+// Universities resolved during an import, by the name given in the rows
+type UniversityCache = HashMap<String, Option<i32>>;
+
+// Rows an import writes in batches of set-based upserts
+pub(crate) trait BatchImport: DeserializeOwned + Sized {
+    // The record a row writes; a batch holds at most one row per record
+    type Key: Eq + Hash;
+    // Lookups kept from one batch to the next
+    type Cache: Default;
+    // What a row holds, for the log, e.g. "college"
+    const NOUN: &'static str;
+
+    fn key(&self) -> Self::Key;
+
+    // Write a batch in `tx`, returning each row's outcome (true for an update). A
+    // database error means the batch as a whole was refused.
+    async fn write_batch(
+        tx: &mut Transaction<'_, Postgres>,
+        cache: &mut Self::Cache,
+        rows: &[(usize, Self)],
+    ) -> Result<Vec<Result<bool, String>>, sqlx::Error>;
+
+    // Write a single row in `tx`; returns true if an existing record was updated
+    async fn write_one(
+        tx: &mut Transaction<'_, Postgres>,
+        cache: &mut Self::Cache,
+        row: Self,
+    ) -> Result<bool, String>;
+}
+
+impl BatchImport for CollegeImport {
+    type Key = (String, String, String);
+    type Cache = UniversityCache;
+    const NOUN: &'static str = "college";
+
+    fn key(&self) -> Self::Key {
+        (self.name.clone(), self.district.clone(), self.city.clone())
+    }
+
+    async fn write_batch(
+        tx: &mut Transaction<'_, Postgres>,
+        universities: &mut UniversityCache,
+        rows: &[(usize, CollegeImport)],
+    ) -> Result<Vec<Result<bool, String>>, sqlx::Error> {
+        write_college_batch(tx, universities, rows).await
+    }
+
+    async fn write_one(
+        tx: &mut Transaction<'_, Postgres>,
+        universities: &mut UniversityCache,
+        college: CollegeImport,
+    ) -> Result<bool, String> {
+        write_college(tx, universities, college).await
+    }
+}
+
+// Writes imported rows in batches of set-based upserts, collecting the outcome of
+// every row. Each batch is written in its own transaction, attributed to the
+// uploading admin in the audit log, or with `atomic=true` every batch goes into one
+// transaction that is rolled back if any row fails.
+pub(crate) struct ImportWriter<'a, T: BatchImport> {
+    pool: &'a PgPool,
+    admin: &'a CurrentAdmin,
+    source: AuditSource,
+    // How rows are referred to in errors, e.g. "Line " or "College #"
+    label: String,
+    atomic: Option<Transaction<'static, Postgres>>,
+    cache: T::Cache,
+    // Rows waiting for the next batch, with their row numbers
+    pending: Vec<(usize, T)>,
+    pending_keys: HashSet<T::Key>,
+    inserted: usize,
+    updated: usize,
+    errors: Vec<(usize, String)>,
+    rows: usize,
+    started: Instant,
+}
+
+impl<'a, T: BatchImport> ImportWriter<'a, T> {
+    pub(crate) async fn begin(
+        pool: &'a PgPool,
+        admin: &'a CurrentAdmin,
+        source: AuditSource,
+        label: String,
+        atomic: bool,
+    ) -> Result<ImportWriter<'a, T>, ApiError> {
+        let atomic = if atomic {
+            let mut tx = pool.begin().await?;
+            audit::set_context(&mut tx, admin, source).await?;
+            Some(tx)
+        } else {
+            None
+        };
+        Ok(ImportWriter {
+            pool,
+            admin,
+            source,
+            label,
+            atomic,
+            cache: T::Cache::default(),
+            pending: Vec::with_capacity(IMPORT_BATCH_SIZE),
+            pending_keys: HashSet::new(),
+            inserted: 0,
+            updated: 0,
+            errors: Vec::new(),
+            rows: 0,
+            started: Instant::now(),
+        })
+    }
+
+    // Queue one row, writing the batch once it is full
+    pub(crate) async fn write(&mut self, row: usize, record: T) -> Result<(), ApiError> {
+        self.rows += 1;
+        let key = record.key();
+        // A record listed twice is written in a later batch, so its rows still
+        // apply in order
+        if self.pending_keys.contains(&key) {
+            self.flush().await?;
+        }
+        self.pending_keys.insert(key);
+        self.pending.push((row, record));
+        if self.pending.len() >= IMPORT_BATCH_SIZE {
+            self.flush().await?;
+        }
+        Ok(())
+    }
+
+    // Record a row that could not be read
+    pub(crate) fn reject(&mut self, row: usize, error: String) {
+        self.rows += 1;
+        self.errors.push((row, error));
+    }
+
+    // Write the queued rows. If the database refuses the batch, it is undone and its
+    // rows are written one at a time to find the ones at fault.
+    pub(crate) async fn flush(&mut self) -> Result<(), ApiError> {
+        if self.pending.is_empty() {
+            return Ok(());
+        }
+        let rows = std::mem::take(&mut self.pending);
+        self.pending_keys.clear();
+
+        let mut tx = match self.atomic.as_mut() {
+            Some(tx) => tx.begin().await?,
+            None => {
+                let mut tx = self.pool.begin().await?;
+                audit::set_context(&mut tx, self.admin, self.source).await?;
+                tx
+            }
+        };
+        let row_numbers: Vec<usize> = rows.iter().map(|(row, _)| *row).collect();
+        let results = match T::write_batch(&mut tx, &mut self.cache, &rows).await {
+            Ok(results) => {
+                tx.commit().await?;
+                results
+            }
+            Err(_) => {
+                tx.rollback().await?;
+                let mut results = Vec::with_capacity(rows.len());
+                for (_, record) in rows {
+                    results.push(self.write_one(record).await);
+                }
+                results
+            }
+        };
+
+        for (row, result) in row_numbers.into_iter().zip(results) {
+            match result {
+                Ok(true) => self.updated += 1,
+                Ok(false) => self.inserted += 1,
+                Err(e) => self.errors.push((row, e)),
+            }
+        }
+        Ok(())
+    }
+
+    // Whether every row so far has been written or rejected, none waiting in a batch
+    pub(crate) fn is_idle(&self) -> bool {
+        self.pending.is_empty()
+    }
+
+    // Rows passed in so far, and how many were inserted, updated and rejected
+    pub(crate) fn progress(&self) -> (usize, usize, usize, usize) {
+        (self.rows, self.inserted, self.updated, self.errors.len())
+    }
+
+    // Errors recorded after the first `from`, as (row, message)
+    pub(crate) fn errors_since(&self, from: usize) -> Vec<(usize, String)> {
+        self.errors[from.min(self.errors.len())..]
+            .iter()
+            .map(|(row, e)| (*row, format!("{}{}: {}", self.label, row, e)))
+            .collect()
+    }
+
+    // Write a single row, in its own transaction or, for an atomic import, under a
+    // savepoint; returns true if an existing record was updated
+    async fn write_one(&mut self, record: T) -> Result<bool, String> {
+        let mut tx = match self.atomic.as_mut() {
+            // A savepoint per row undoes just the failed row and keeps the
+            // transaction usable, so every remaining row is still checked
+            Some(tx) => tx.begin().await.map_err(|e| e.to_string())?,
+            None => {
+                let mut tx = self.pool.begin().await.map_err(|e| e.to_string())?;
+                audit::set_context(&mut tx, self.admin, self.source)
+                    .await
+                    .map_err(|e| e.to_string())?;
+                tx
+            }
+        };
+        match T::write_one(&mut tx, &mut self.cache, record).await {
+            Ok(was_update) => {
+                tx.commit().await.map_err(|e| e.to_string())?;
+                Ok(was_update)
+            }
+            Err(e) => {
+                tx.rollback().await.map_err(|e| e.to_string())?;
+                Err(e)
+            }
+        }
+    }
+
+    // Write the remaining rows and commit an atomic import, or roll all of it back
+    // if any row failed
+    pub(crate) async fn finish(mut self, message: &str) -> Result<UploadResponse, ApiError> {
+        self.flush().await?;
+
+        self.errors.sort_by_key(|(row, _)| *row);
+        let mut response = UploadResponse {
+            message: message.to_string(),
+            inserted: self.inserted,
+            updated: self.updated,
+            errors: self
+                .errors
+                .into_iter()
+                .map(|(row, e)| format!("{}{}: {}", self.label, row, e))
+                .collect(),
+        };
+
+        if let Some(tx) = self.atomic {
+            if response.errors.is_empty() {
+                tx.commit().await?;
+            } else {
+                tx.rollback().await?;
+                response.message = format!(
+                    "Import rolled back: {} row(s) failed, nothing was written",
+                    response.errors.len()
+                );
+                response.inserted = 0;
+                response.updated = 0;
+            }
+        }
+
+        let elapsed = self.started.elapsed().as_secs_f64();
+        tracing::info!(
+            "Imported {} {} row(s) in {:.2}s ({:.0} rows/s): {} inserted, {} updated, {} failed",
+            self.rows,
+            T::NOUN,
+            elapsed,
+            self.rows as f64 / elapsed.max(f64::EPSILON),
+            response.inserted,
+            response.updated,
+            response.errors.len()
+        );
+        Ok(response)
+    }
+}
+
+// Upsert a batch of colleges with their contact info and hostels in `tx`, three
+// statements in all. Returns each row's outcome (true for an update); rows that fail
+// validation are left out. A database error means the batch as a whole was refused.
+async fn write_college_batch(
+    tx: &mut Transaction<'_, Postgres>,
+    universities: &mut UniversityCache,
+    rows: &[(usize, CollegeImport)],
+) -> Result<Vec<Result<bool, String>>, sqlx::Error> {
+    let mut results: Vec<Result<bool, String>> = Vec::with_capacity(rows.len());
+    let mut batch = CollegeBatch::default();
+    for (_, college) in rows {
+        match resolve_import(&mut **tx, universities, college).await {
+            Ok(resolved) => {
+                batch.push(college, resolved);
+                results.push(Ok(false));
+            }
+            Err(e) => results.push(Err(e)),
+        }
+    }
+    if batch.name.is_empty() {
+        return Ok(results);
+    }
+
+    // Update the live colleges in the batch and insert the missing ones; deleted
+    // colleges match neither and are reported below
+    let written = sqlx::query!(
+        r#"
+        WITH input AS (
+            SELECT * FROM UNNEST(
+                $1::text[], $2::text[], $3::text[], $4::text[], $5::text[], $6::bool[],
+                $7::bool[], $8::bool[], $9::int4[], $10::int4[], $11::text[], $12::text[]
+            ) AS t(name, category, district, city, type, autonomous, minority, hostel_available,
+                   established_year, university_id, gender_policy, instruction_languages)
+        ),
+        updated AS (
+            UPDATE colleges SET
+                category = input.category,
+                type = input.type,
+                autonomous = input.autonomous,
+                minority = input.minority,
+                hostel_available = input.hostel_available,
+                established_year = input.established_year,
+                university_id = COALESCE(input.university_id, colleges.university_id),
+                gender_policy = COALESCE(input.gender_policy, colleges.gender_policy),
+                instruction_languages = COALESCE(string_to_array(input.instruction_languages, ','), colleges.instruction_languages),
+                updated_at = CURRENT_TIMESTAMP
+            FROM input
+            WHERE colleges.name = input.name
+              AND colleges.district = input.district
+              AND colleges.city = input.city
+              AND colleges.deleted_at IS NULL
+            RETURNING colleges.id, colleges.name, colleges.district, colleges.city, FALSE AS was_insert
+        ),
+        inserted AS (
+            INSERT INTO colleges (name, category, district, city, type, autonomous, minority, hostel_available, established_year, university_id, gender_policy, instruction_languages)
+            SELECT name, category, district, city, type, autonomous, minority, hostel_available, established_year, university_id,
+                   COALESCE(gender_policy, 'co-ed'), COALESCE(string_to_array(instruction_languages, ','), '{}')
+            FROM input
+            WHERE NOT EXISTS (
+                SELECT 1 FROM colleges
+                WHERE colleges.name = input.name AND colleges.district = input.district AND colleges.city = input.city
+            )
+            RETURNING id, name, district, city, TRUE AS was_insert
+        )
+        SELECT id AS "id!", name AS "name!", district AS "district!", city AS "city!", was_insert AS "was_insert!" FROM updated
+        UNION ALL
+        SELECT id, name, district, city, was_insert FROM inserted
+        "#,
+        &batch.name,
+        &batch.category,
+        &batch.district,
+        &batch.city,
+        &batch.r#type,
+        &batch.autonomous,
+        &batch.minority,
+        &batch.hostel_available,
+        &batch.established_year as &[Option<i32>],
+        &batch.university_id as &[Option<i32>],
+        &batch.gender_policy as &[Option<String>],
+        &batch.instruction_languages as &[Option<String>]
+    )
+    .fetch_all(&mut **tx)
+    .await?;
+
+    let written: HashMap<(String, String, String), (i32, bool)> = written
+        .into_iter()
+        .map(|row| ((row.name, row.district, row.city), (row.id, row.was_insert)))
+        .collect();
+
+    let mut contacts = ContactBatch::default();
+    let mut hostels = HostelBatch::default();
+    for ((_, college), result) in rows.iter().zip(results.iter_mut()) {
+        if result.is_err() {
+            continue;
+        }
+        let key = (
+            college.name.clone(),
+            college.district.clone(),
+            college.city.clone(),
+        );
+        let Some(&(college_id, was_insert)) = written.get(&key) else {
+            // The college was deleted and is awaiting purge
+            *result = Err(format!(
+                "College '{}' was deleted; restore it before importing it again",
+                college.name
+            ));
+            continue;
+        };
+        *result = Ok(!was_insert);
+        contacts.push(college_id, college);
+        hostels.push(college_id, college);
+    }
+
+    if !contacts.college_id.is_empty() {
+        sqlx::query!(
+            r#"
+            INSERT INTO contact_info (college_id, phone, email, website, address, pincode)
+            SELECT * FROM UNNEST($1::int4[], $2::text[], $3::text[], $4::text[], $5::text[], $6::text[])
+            ON CONFLICT (college_id)
+            DO UPDATE SET
+                phone = COALESCE(EXCLUDED.phone, contact_info.phone),
+                email = COALESCE(EXCLUDED.email, contact_info.email),
+                website = COALESCE(EXCLUDED.website, contact_info.website),
+                address = COALESCE(EXCLUDED.address, contact_info.address),
+                pincode = COALESCE(EXCLUDED.pincode, contact_info.pincode)
+            "#,
+            &contacts.college_id,
+            &contacts.phone as &[Option<String>],
+            &contacts.email as &[Option<String>],
+            &contacts.website as &[Option<String>],
+            &contacts.address as &[Option<String>],
+            &contacts.pincode as &[Option<String>]
+        )
+        .execute(&mut **tx)
+        .await?;
+    }
+
+    if !hostels.college_id.is_empty() {
+        sqlx::query!(
+            r#"
+            INSERT INTO hostels (college_id, hostel_type, capacity, annual_fee, mess_available, distance_km, first_year_guaranteed)
+            SELECT * FROM UNNEST($1::int4[], $2::text[], $3::int4[], $4::int4[], $5::bool[], $6::float8[], $7::bool[])
+            ON CONFLICT (college_id, hostel_type)
+            DO UPDATE SET
+                capacity = COALESCE(EXCLUDED.capacity, hostels.capacity),
+                annual_fee = COALESCE(EXCLUDED.annual_fee, hostels.annual_fee),
+                mess_available = COALESCE(EXCLUDED.mess_available, hostels.mess_available),
+                distance_km = COALESCE(EXCLUDED.distance_km, hostels.distance_km),
+                first_year_guaranteed = COALESCE(EXCLUDED.first_year_guaranteed, hostels.first_year_guaranteed),
+                updated_at = CURRENT_TIMESTAMP
+            "#,
+            &hostels.college_id,
+            &hostels.hostel_type,
+            &hostels.capacity as &[Option<i32>],
+            &hostels.annual_fee as &[Option<i32>],
+            &hostels.mess_available as &[Option<bool>],
+            &hostels.distance_km as &[Option<f64>],
+            &hostels.first_year_guaranteed as &[Option<bool>]
+        )
+        .execute(&mut **tx)
+        .await?;
+    }
+
+    Ok(results)
+}
+
+// Column arrays for the batched college upsert
+#[derive(Default)]
+struct CollegeBatch {
+    name: Vec<String>,
+    category: Vec<String>,
+    district: Vec<String>,
+    city: Vec<String>,
+    r#type: Vec<String>,
+    autonomous: Vec<bool>,
+    minority: Vec<bool>,
+    hostel_available: Vec<bool>,
+    established_year: Vec<Option<i32>>,
+    university_id: Vec<Option<i32>>,
+    gender_policy: Vec<Option<String>>,
+    // Comma-joined; parsed languages never contain a comma
+    instruction_languages: Vec<Option<String>>,
+}
+
+impl CollegeBatch {
+    fn push(&mut self, college: &CollegeImport, resolved: ResolvedImport) {
+        self.name.push(college.name.clone());
+        self.category.push(college.category.clone());
+        self.district.push(college.district.clone());
+        self.city.push(college.city.clone());
+        self.r#type.push(college.r#type.clone());
+        self.autonomous.push(college.autonomous.unwrap_or(false));
+        self.minority.push(college.minority.unwrap_or(false));
+        self.hostel_available
+            .push(college.hostel_available.unwrap_or(false));
+        self.established_year.push(college.established_year);
+        self.university_id.push(resolved.university_id);
+        self.gender_policy.push(resolved.gender_policy);
+        self.instruction_languages.push(
+            resolved
+                .instruction_languages
+                .map(|languages| languages.join(",")),
+        );
+    }
+}
+
+// Column arrays for the batched contact info upsert
+#[derive(Default)]
+struct ContactBatch {
+    college_id: Vec<i32>,
+    phone: Vec<Option<String>>,
+    email: Vec<Option<String>>,
+    website: Vec<Option<String>>,
+    address: Vec<Option<String>>,
+    pincode: Vec<Option<String>>,
+}
+
+impl ContactBatch {
+    // Queue the row's contact info, if it has any
+    fn push(&mut self, college_id: i32, college: &CollegeImport) {
+        if college.phone.is_none()
+            && college.email.is_none()
+            && college.website.is_none()
+            && college.address.is_none()
+            && college.pincode.is_none()
+        {
+            return;
+        }
+        self.college_id.push(college_id);
+        self.phone.push(college.phone.clone());
+        self.email.push(college.email.clone());
+        self.website.push(college.website.clone());
+        self.address.push(college.address.clone());
+        self.pincode.push(college.pincode.clone());
+    }
+}
+
+// Column arrays for the batched hostel upsert
+#[derive(Default)]
+struct HostelBatch {
+    college_id: Vec<i32>,
+    hostel_type: Vec<String>,
+    capacity: Vec<Option<i32>>,
+    annual_fee: Vec<Option<i32>>,
+    mess_available: Vec<Option<bool>>,
+    distance_km: Vec<Option<f64>>,
+    first_year_guaranteed: Vec<Option<bool>>,
+}
+
+impl HostelBatch {
+    // Queue each hostel type the row has data for
+    fn push(&mut self, college_id: i32, college: &CollegeImport) {
+        let hostels = [
+            (
+                "boys",
+                college.boys_hostel_capacity,
+                college.boys_hostel_fee,
+            ),
+            (
+                "girls",
+                college.girls_hostel_capacity,
+                college.girls_hostel_fee,
+            ),
+        ];
+        for (hostel_type, capacity, annual_fee) in hostels {
+            if capacity.is_none() && annual_fee.is_none() {
+                continue;
+            }
+            self.college_id.push(college_id);
+            self.hostel_type.push(hostel_type.to_string());
+            self.capacity.push(capacity);
+            self.annual_fee.push(annual_fee);
+            self.mess_available.push(college.hostel_mess_available);
+            self.distance_km.push(college.hostel_distance_km);
+            self.first_year_guaranteed
+                .push(college.hostel_first_year_guaranteed);
+        }
+    }
+}
+
+// Upsert one college with its contact info and hostels in `tx`; returns true if an
+// existing college was updated
+async fn write_college(
+    tx: &mut Transaction<'_, Postgres>,
+    universities: &mut UniversityCache,
     college: CollegeImport,
-) -> Result<bool, sqlx::Error> {
+) -> Result<bool, String> {
+    let ResolvedImport {
+        university_id,
+        gender_policy,
+        instruction_languages,
+    } = resolve_import(&mut **tx, universities, &college).await?;
+
     // Insert or update college
     let college_result = sqlx::query!(
         r#"
-        INSERT INTO colleges (name, category, district, city, type, autonomous, minority, hostel_available, established_year)
-        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+        INSERT INTO colleges (name, category, district, city, type, autonomous, minority, hostel_available, established_year, university_id, gender_policy, instruction_languages)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, COALESCE($11, 'co-ed'), COALESCE($12::text[], '{}'))
         ON CONFLICT (name, district, city)
         DO UPDATE SET
             category = EXCLUDED.category,
@@ -153,7 +1312,11 @@ async fn insert_or_update_college(
             minority = EXCLUDED.minority,
             hostel_available = EXCLUDED.hostel_available,
             established_year = EXCLUDED.established_year,
+            university_id = COALESCE(EXCLUDED.university_id, colleges.university_id),
+            gender_policy = COALESCE($11, colleges.gender_policy),
+            instruction_languages = COALESCE($12::text[], colleges.instruction_languages),
             updated_at = CURRENT_TIMESTAMP
+        WHERE colleges.deleted_at IS NULL
         RETURNING id, (xmax = 0) AS was_insert
         "#,
         college.name,
@@ -164,10 +1327,21 @@ async fn insert_or_update_college(
         college.autonomous.unwrap_or(false),
         college.minority.unwrap_or(false),
         college.hostel_available.unwrap_or(false),
-        college.established_year
+        college.established_year,
+        university_id,
+        gender_policy,
+        instruction_languages.as_deref()
     )
-    .fetch_one(pool)
-    .await?;
+    .fetch_optional(&mut **tx)
+    .await
+    .map_err(|e| e.to_string())?
+    // The conflicting college was deleted and is awaiting purge
+    .ok_or_else(|| {
+        format!(
+            "College '{}' was deleted; restore it before importing it again",
+            college.name
+        )
+    })?;
 
     let college_id = college_result.id;
     let was_insert = college_result.was_insert.unwrap_or(true);
@@ -198,8 +1372,53 @@ async fn insert_or_update_college(
             college.address,
             college.pincode
         )
-        .execute(pool)
-        .await?;
+        .execute(&mut **tx)
+        .await
+        .map_err(|e| e.to_string())?;
+    }
+
+    // Insert/update hostel details for each hostel type that has data
+    let hostels = [
+        (
+            "boys",
+            college.boys_hostel_capacity,
+            college.boys_hostel_fee,
+        ),
+        (
+            "girls",
+            college.girls_hostel_capacity,
+            college.girls_hostel_fee,
+        ),
+    ];
+    for (hostel_type, capacity, annual_fee) in hostels {
+        if capacity.is_none() && annual_fee.is_none() {
+            continue;
+        }
+
+        sqlx::query!(
+            r#"
+            INSERT INTO hostels (college_id, hostel_type, capacity, annual_fee, mess_available, distance_km, first_year_guaranteed)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            ON CONFLICT (college_id, hostel_type)
+            DO UPDATE SET
+                capacity = COALESCE(EXCLUDED.capacity, hostels.capacity),
+                annual_fee = COALESCE(EXCLUDED.annual_fee, hostels.annual_fee),
+                mess_available = COALESCE(EXCLUDED.mess_available, hostels.mess_available),
+                distance_km = COALESCE(EXCLUDED.distance_km, hostels.distance_km),
+                first_year_guaranteed = COALESCE(EXCLUDED.first_year_guaranteed, hostels.first_year_guaranteed),
+                updated_at = CURRENT_TIMESTAMP
+            "#,
+            college_id,
+            hostel_type,
+            capacity,
+            annual_fee,
+            college.hostel_mess_available,
+            college.hostel_distance_km,
+            college.hostel_first_year_guaranteed
+        )
+        .execute(&mut **tx)
+        .await
+        .map_err(|e| e.to_string())?;
     }
 
     Ok(!was_insert) // Return true if it was an update
@@ -208,18 +1427,29 @@ async fn insert_or_update_college(
 // Stats Handler
 pub async fn get_stats(
     Extension(pool): Extension<PgPool>,
+    admin: CurrentAdmin,
 ) -> Result<Json<HashMap<String, i64>>, ApiError> {
+    admin.require(Permission::Stats)?;
+
     let mut stats = HashMap::new();
 
     // Total colleges
-    let total: (i64,) = sqlx::query_as("SELECT COUNT(*) FROM colleges")
+    let total: (i64,) = sqlx::query_as("SELECT COUNT(*) FROM colleges WHERE deleted_at IS NULL")
         .fetch_one(&pool)
         .await?;
     stats.insert("total_colleges".to_string(), total.0);
 
+    // Deleted colleges awaiting restoration or purging
+    let deleted: (i64,) =
+        sqlx::query_as("SELECT COUNT(*) FROM colleges WHERE deleted_at IS NOT NULL")
+            .fetch_one(&pool)
+            .await?;
+    stats.insert("deleted_colleges".to_string(), deleted.0);
+
     // By district
     let by_district: Vec<(String, i64)> = sqlx::query_as(
-        "SELECT district, COUNT(*) FROM colleges GROUP BY district ORDER BY COUNT(*) DESC LIMIT 10",
+        "SELECT district, COUNT(*) FROM colleges WHERE deleted_at IS NULL \
+         GROUP BY district ORDER BY COUNT(*) DESC LIMIT 10",
     )
     .fetch_all(&pool)
     .await?;
@@ -233,7 +1463,8 @@ pub async fn get_stats(
 
     // By category
     let by_category: Vec<(String, i64)> = sqlx::query_as(
-        "SELECT category, COUNT(*) FROM colleges GROUP BY category ORDER BY COUNT(*) DESC",
+        "SELECT category, COUNT(*) FROM colleges WHERE deleted_at IS NULL \
+         GROUP BY category ORDER BY COUNT(*) DESC",
     )
     .fetch_all(&pool)
     .await?;
@@ -255,11 +1486,164 @@ pub async fn test_admin() -> Json<UploadResponse> {
     })
 }
 
-// Router
+// Router: every admin route requires a bearer token except login
 pub fn admin_routes() -> Router {
     Router::new()
         .route("/api/admin/test", get(test_admin))
-        .route("/api/admin/upload/csv", post(upload_colleges_csv))
+        .route("/api/admin/logout", post(auth::logout))
+        .route("/api/admin/me", get(auth::get_current_admin))
+        .route(
+            "/api/admin/users",
+            get(auth::list_admin_users).post(auth::create_admin_user),
+        )
+        .route("/api/admin/users/:id", put(auth::update_admin_user))
+        .route("/api/admin/roles", get(auth::list_roles))
+        .route("/api/admin/audit", get(audit_log::list_audit_log))
+        .route("/api/admin/claims", get(representative::list_claims))
+        .route(
+            "/api/admin/claims/:id/approve",
+            post(representative::approve_claim),
+        )
+        .route(
+            "/api/admin/claims/:id/reject",
+            post(representative::reject_claim),
+        )
+        .route(
+            "/api/admin/colleges/:id/submissions",
+            post(representative::create_submission),
+        )
+        .route(
+            "/api/admin/submissions",
+            get(representative::list_submissions),
+        )
+        .route(
+            "/api/admin/submissions/:id/approve",
+            post(representative::approve_submission),
+        )
+        .route(
+            "/api/admin/submissions/:id/reject",
+            post(representative::reject_submission),
+        )
+        .route(
+            "/api/admin/users/:id/revoke-tokens",
+            post(auth::revoke_admin_user_tokens),
+        )
+        .route(
+            "/api/admin/upload/csv",
+            // The handler enforces its own, configurable limit while streaming
+            post(upload_colleges_csv).layer(DefaultBodyLimit::disable()),
+        )
         .route("/api/admin/upload/json", post(upload_colleges_json))
+        .route(
+            "/api/admin/upload/spreadsheet",
+            post(upload_colleges_spreadsheet).layer(DefaultBodyLimit::max(MAX_SPREADSHEET_BYTES)),
+        )
+        .route("/api/admin/jobs", get(import_job::list_import_jobs))
+        .route("/api/admin/jobs/:id", get(import_job::get_import_job))
+        .route(
+            "/api/admin/jobs/:id/events",
+            get(import_job::import_job_events),
+        )
+        .route(
+            "/api/admin/jobs/:id/cancel",
+            post(import_job::cancel_import_job),
+        )
+        .route("/api/admin/upload/fees/csv", post(fee::upload_fees_csv))
+        .route(
+            "/api/admin/upload/placements/csv",
+            post(placement::upload_placements_csv),
+        )
+        .route(
+            "/api/admin/upload/cutoffs/csv",
+            post(cutoff::upload_cutoffs_csv).layer(DefaultBodyLimit::disable()),
+        )
+        .route(
+            "/api/admin/upload/cutoffs/spreadsheet",
+            post(cutoff::upload_cutoffs_spreadsheet)
+                .layer(DefaultBodyLimit::max(MAX_SPREADSHEET_BYTES)),
+        )
+        .route(
+            "/api/admin/upload/naac/csv",
+            post(accreditation::upload_naac_csv),
+        )
+        .route(
+            "/api/admin/upload/nba/csv",
+            post(accreditation::upload_nba_csv),
+        )
+        .route(
+            "/api/admin/upload/nirf/csv",
+            post(accreditation::upload_nirf_csv),
+        )
+        .route(
+            "/api/admin/concession-rules",
+            get(concession::list_concession_rules).post(concession::create_concession_rule),
+        )
+        .route(
+            "/api/admin/concession-rules/:id",
+            put(concession::update_concession_rule).delete(concession::delete_concession_rule),
+        )
+        .route(
+            "/api/admin/scholarships",
+            get(scholarship::list_all_scholarships).post(scholarship::create_scholarship),
+        )
+        .route(
+            "/api/admin/scholarships/:id",
+            put(scholarship::update_scholarship).delete(scholarship::delete_scholarship),
+        )
+        .route(
+            "/api/admin/universities",
+            post(university::create_university),
+        )
+        .route("/api/admin/facilities", post(facility::create_facility))
+        .route(
+            "/api/admin/facilities/:id",
+            put(facility::update_facility).delete(facility::delete_facility),
+        )
+        .route("/api/admin/colleges", post(college::create_college))
+        .route(
+            "/api/admin/colleges/:id",
+            put(college::replace_college)
+                .patch(college::patch_college)
+                .delete(college::delete_college),
+        )
+        .route(
+            "/api/admin/colleges/deleted",
+            get(college::list_deleted_colleges),
+        )
+        .route(
+            "/api/admin/colleges/:id/dependents",
+            get(college::get_college_dependents),
+        )
+        .route(
+            "/api/admin/colleges/:id/restore",
+            post(college::restore_college),
+        )
+        .route(
+            "/api/admin/colleges/:id/purge",
+            post(college::purge_college),
+        )
+        .route(
+            "/api/admin/colleges/:id/facilities",
+            put(facility::set_college_facilities),
+        )
+        .route(
+            "/api/admin/colleges/:id/images",
+            post(image::upload_college_image).layer(DefaultBodyLimit::max(image::MAX_IMAGE_BYTES)),
+        )
+        .route(
+            "/api/admin/images/:id",
+            put(image::update_college_image).delete(image::delete_college_image),
+        )
+        .route(
+            "/api/admin/colleges/:id/attachments",
+            post(attachment::upload_college_attachment)
+                .layer(DefaultBodyLimit::max(attachment::MAX_ATTACHMENT_BYTES)),
+        )
+        .route(
+            "/api/admin/attachments/:id",
+            delete(attachment::delete_college_attachment),
+        )
         .route("/api/admin/stats", get(get_stats))
+        .route_layer(middleware::from_fn(require_admin))
+        .route("/api/admin/login", post(auth::login))
 }
@@ -0,0 +1,226 @@
+use crate::auth::{CurrentAdmin, Permission};
+use crate::db::queries::{self, ATTACHMENT_SELECT};
+use crate::errors::ApiError;
+use crate::handlers::image::upload_dir;
+use crate::media;
+use crate::models::attachment::{detect_document_type, AttachmentInput};
+use crate::models::Attachment;
+use axum::{
+    body::Body,
+    extract::{Multipart, Path, Request},
+    http::{header, HeaderValue, StatusCode},
+    response::Response,
+    Extension, Json,
+};
+use sqlx::PgPool;
+use tower::ServiceExt;
+use tower_http::services::ServeFile;
+
+/// Largest accepted attachment upload, in bytes.
+pub const MAX_ATTACHMENT_BYTES: usize = 25 * 1024 * 1024;
+
+// Reduce an uploaded file name to characters safe in a Content-Disposition header
+fn sanitize_file_name(name: &str) -> String {
+    let name = name.rsplit(['/', '\\']).next().unwrap_or_default();
+    let sanitized: String = name
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '_' | ' ') {
+                c
+            } else {
+                '_'
+            }
+        })
+        .take(255)
+        .collect();
+    sanitized.trim().to_string()
+}
+
+/// List the documents attached to a college.
+pub async fn list_college_attachments(
+    Path(college_id): Path<i32>,
+    Extension(pool): Extension<PgPool>,
+) -> Result<Json<Vec<Attachment>>, ApiError> {
+    queries::fetch_college_by_id(&pool, college_id).await?;
+    let attachments = queries::fetch_attachments_by_college(&pool, college_id).await?;
+    Ok(Json(attachments))
+}
+
+/// Download an attachment, with support for HTTP range requests.
+pub async fn serve_attachment(
+    Path(id): Path<i32>,
+    Extension(pool): Extension<PgPool>,
+    request: Request,
+) -> Result<Response, ApiError> {
+    let attachment = queries::fetch_attachment_by_id(&pool, id).await?;
+    let mime = attachment
+        .content_type
+        .parse()
+        .map_err(|_| ApiError::InternalServerError("Invalid stored content type".to_string()))?;
+
+    let mut response = ServeFile::new_with_mime(upload_dir().join(&attachment.file_path), &mime)
+        .oneshot(request)
+        .await
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to read attachment: {}", e)))?
+        .map(Body::new);
+
+    if let Ok(disposition) =
+        HeaderValue::from_str(&format!("inline; filename=\"{}\"", attachment.file_name))
+    {
+        response
+            .headers_mut()
+            .insert(header::CONTENT_DISPOSITION, disposition);
+    }
+
+    Ok(response)
+}
+
+// Admin: upload a PDF or DOCX for a college (multipart fields: file, document_type,
+// academic_year, title); re-uploading an identical file returns the existing attachment
+pub async fn upload_college_attachment(
+    Path(college_id): Path<i32>,
+    Extension(pool): Extension<PgPool>,
+    admin: CurrentAdmin,
+    mut multipart: Multipart,
+) -> Result<(StatusCode, Json<Attachment>), ApiError> {
+    admin.require(Permission::Upload)?;
+
+    let mut file = None;
+    let mut document_type = None;
+    let mut academic_year = None;
+    let mut title = None;
+
+    while let Some(field) = multipart
+        .next_field()
+        .await
+        .map_err(|e| ApiError::BadRequest(format!("Invalid file upload: {}", e)))?
+    {
+        let name = field.name().unwrap_or_default().to_string();
+        if name == "file" {
+            let file_name = sanitize_file_name(field.file_name().unwrap_or_default());
+            let data = field
+                .bytes()
+                .await
+                .map_err(|e| ApiError::BadRequest(format!("Failed to read file: {}", e)))?;
+            file = Some((file_name, data));
+            continue;
+        }
+
+        let value = field
+            .text()
+            .await
+            .map_err(|e| ApiError::BadRequest(format!("Invalid {}: {}", name, e)))?;
+        let value = Some(value.trim().to_string()).filter(|value| !value.is_empty());
+        match name.as_str() {
+            "document_type" => document_type = value.map(|v| v.to_lowercase()),
+            "academic_year" => academic_year = value,
+            "title" => title = value,
+            _ => {}
+        }
+    }
+
+    let (file_name, data) =
+        file.ok_or_else(|| ApiError::BadRequest("Missing multipart field 'file'".to_string()))?;
+    let input = AttachmentInput {
+        document_type: document_type
+            .ok_or_else(|| ApiError::BadRequest("document_type is required".to_string()))?,
+        academic_year,
+        title: title.unwrap_or_default(),
+    };
+    input.validate().map_err(ApiError::BadRequest)?;
+
+    let (content_type, extension) = detect_document_type(&data)
+        .ok_or_else(|| ApiError::BadRequest("Attachment must be a PDF or DOCX file".to_string()))?;
+    let file_name = if file_name.is_empty() {
+        format!("document.{}", extension)
+    } else {
+        file_name
+    };
+
+    queries::fetch_college_by_id(&pool, college_id).await?;
+
+    let checksum = media::content_hash(&data);
+    let existing = sqlx::query_as::<_, Attachment>(&format!(
+        "{} WHERE college_id = $1 AND checksum = $2",
+        ATTACHMENT_SELECT
+    ))
+    .bind(college_id)
+    .bind(&checksum)
+    .fetch_optional(&pool)
+    .await?;
+    if let Some(existing) = existing {
+        return Ok((StatusCode::OK, Json(existing)));
+    }
+
+    let relative = format!("attachments/{}/{}.{}", college_id, checksum, extension);
+    let path = upload_dir().join(&relative);
+    if let Some(parent) = path.parent() {
+        tokio::fs::create_dir_all(parent).await.map_err(|e| {
+            ApiError::InternalServerError(format!("Failed to store attachment: {}", e))
+        })?;
+    }
+    tokio::fs::write(&path, &data)
+        .await
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to store attachment: {}", e)))?;
+
+    let attachment = sqlx::query_as::<_, Attachment>(
+        r#"
+        INSERT INTO college_attachments
+            (college_id, document_type, academic_year, title, file_name,
+             content_type, byte_size, checksum, file_path)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+        ON CONFLICT (college_id, checksum) DO NOTHING
+        RETURNING *, '/attachments/' || id AS url
+        "#,
+    )
+    .bind(college_id)
+    .bind(&input.document_type)
+    .bind(&input.academic_year)
+    .bind(input.title.trim())
+    .bind(&file_name)
+    .bind(content_type)
+    .bind(data.len() as i64)
+    .bind(&checksum)
+    .bind(&relative)
+    .fetch_optional(&pool)
+    .await?;
+
+    match attachment {
+        Some(attachment) => Ok((StatusCode::CREATED, Json(attachment))),
+        // A concurrent upload of the same file won the race; it owns the stored file
+        None => {
+            let existing = sqlx::query_as::<_, Attachment>(&format!(
+                "{} WHERE college_id = $1 AND checksum = $2",
+                ATTACHMENT_SELECT
+            ))
+            .bind(college_id)
+            .bind(&checksum)
+            .fetch_one(&pool)
+            .await?;
+            Ok((StatusCode::OK, Json(existing)))
+        }
+    }
+}
+
+// Admin: delete an attachment and its stored file
+pub async fn delete_college_attachment(
+    Path(id): Path<i32>,
+    Extension(pool): Extension<PgPool>,
+    admin: CurrentAdmin,
+) -> Result<StatusCode, ApiError> {
+    admin.require(Permission::Delete)?;
+
+    let file_path: String =
+        sqlx::query_scalar("DELETE FROM college_attachments WHERE id = $1 RETURNING file_path")
+            .bind(id)
+            .fetch_optional(&pool)
+            .await?
+            .ok_or_else(|| ApiError::NotFound("Attachment not found".to_string()))?;
+
+    let path = upload_dir().join(&file_path);
+    if let Err(e) = tokio::fs::remove_file(&path).await {
+        tracing::warn!("Failed to remove attachment file {}: {}", path.display(), e);
+    }
+
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,64 @@
+use crate::auth::{CurrentAdmin, Permission};
+use crate::errors::ApiError;
+use crate::models::audit::{AuditEntry, AuditFilters};
+use axum::{extract::Query, Extension, Json};
+use sqlx::{PgPool, QueryBuilder};
+
+// Admin: recorded changes, newest first, filtered by college, actor, date range,
+// table, action or source
+pub async fn list_audit_log(
+    Query(filters): Query<AuditFilters>,
+    Extension(pool): Extension<PgPool>,
+    admin: CurrentAdmin,
+) -> Result<Json<Vec<AuditEntry>>, ApiError> {
+    admin.require(Permission::Review)?;
+    filters.validate().map_err(ApiError::BadRequest)?;
+
+    let mut query_builder = QueryBuilder::new("SELECT * FROM audit_log WHERE TRUE");
+    if let Some(college_id) = filters.college_id {
+        query_builder.push(" AND college_id = ");
+        query_builder.push_bind(college_id);
+    }
+    if let Some(actor) = &filters.actor {
+        query_builder.push(" AND actor = ");
+        query_builder.push_bind(actor.trim().to_lowercase());
+    }
+    if let Some(from) = filters.from {
+        query_builder.push(" AND occurred_at >= ");
+        query_builder.push_bind(from.and_hms_opt(0, 0, 0).unwrap().and_utc());
+    }
+    if let Some(to) = filters.to {
+        query_builder.push(" AND occurred_at < ");
+        query_builder.push_bind(
+            (to + chrono::Days::new(1))
+                .and_hms_opt(0, 0, 0)
+                .unwrap()
+                .and_utc(),
+        );
+    }
+    if let Some(table) = &filters.table {
+        query_builder.push(" AND table_name = ");
+        query_builder.push_bind(table);
+    }
+    if let Some(action) = &filters.action {
+        query_builder.push(" AND action = ");
+        query_builder.push_bind(action);
+    }
+    if let Some(source) = &filters.source {
+        query_builder.push(" AND source = ");
+        query_builder.push_bind(source);
+    }
+
+    let limit = filters.limit.unwrap_or(AuditFilters::DEFAULT_LIMIT) as i64;
+    let page = filters.page.unwrap_or(1) as i64;
+    query_builder.push(" ORDER BY occurred_at DESC, id DESC LIMIT ");
+    query_builder.push_bind(limit);
+    query_builder.push(" OFFSET ");
+    query_builder.push_bind((page - 1) * limit);
+
+    let entries = query_builder
+        .build_query_as::<AuditEntry>()
+        .fetch_all(&pool)
+        .await?;
+    Ok(Json(entries))
+}
@@ -0,0 +1,276 @@
+use crate::auth::{self, AuthConfig, CurrentAdmin, Permission, Role};
+use crate::errors::ApiError;
+use crate::models::admin_user::{
+    AdminUserInput, AdminUserUpdate, LoginRequest, LoginResponse, RoleInfo,
+};
+use crate::models::AdminUser;
+use axum::{
+    extract::{rejection::JsonRejection, Path},
+    http::StatusCode,
+    Extension, Json,
+};
+use chrono::Utc;
+use sqlx::PgPool;
+
+// Run Argon2 off the async runtime; it is deliberately slow
+pub(crate) async fn hash_password(password: String) -> Result<String, ApiError> {
+    tokio::task::spawn_blocking(move || auth::hash_password(&password))
+        .await
+        .map_err(|e| ApiError::InternalServerError(e.to_string()))?
+        .map_err(ApiError::InternalServerError)
+}
+
+/// Exchange an admin username and password for a signed bearer token.
+pub async fn login(
+    Extension(pool): Extension<PgPool>,
+    Extension(config): Extension<AuthConfig>,
+    payload: Result<Json<LoginRequest>, JsonRejection>,
+) -> Result<Json<LoginResponse>, ApiError> {
+    let Json(input) = payload?;
+
+    let user =
+        sqlx::query_as::<_, AdminUser>("SELECT * FROM admin_users WHERE username = $1 AND active")
+            .bind(input.username.trim().to_lowercase())
+            .fetch_optional(&pool)
+            .await?;
+
+    let password_hash = user
+        .as_ref()
+        .map(|user| user.password_hash.clone())
+        .unwrap_or_else(|| auth::dummy_password_hash().to_string());
+    let password = input.password;
+    let verified =
+        tokio::task::spawn_blocking(move || auth::verify_password(&password, &password_hash))
+            .await
+            .map_err(|e| ApiError::InternalServerError(e.to_string()))?;
+
+    let user = match user {
+        Some(user) if verified => user,
+        _ => {
+            return Err(ApiError::Unauthorized(
+                "Invalid username or password".to_string(),
+            ))
+        }
+    };
+
+    let issued_at = Utc::now();
+    let expires_at = issued_at + config.token_ttl;
+    let session_id: i64 = sqlx::query_scalar(
+        "INSERT INTO admin_sessions (user_id, issued_at, expires_at) VALUES ($1, $2, $3) RETURNING id",
+    )
+    .bind(user.id)
+    .bind(issued_at)
+    .bind(expires_at)
+    .fetch_one(&pool)
+    .await?;
+
+    let user = sqlx::query_as::<_, AdminUser>(
+        "UPDATE admin_users SET last_login_at = $2 WHERE id = $1 RETURNING *",
+    )
+    .bind(user.id)
+    .bind(issued_at)
+    .fetch_one(&pool)
+    .await?;
+
+    let token = auth::issue_token(&config, user.id, session_id, issued_at, expires_at)?;
+
+    Ok(Json(LoginResponse {
+        token,
+        token_type: "Bearer",
+        expires_at,
+        user,
+    }))
+}
+
+// Admin: revoke the token used for this request
+pub async fn logout(
+    Extension(pool): Extension<PgPool>,
+    current: CurrentAdmin,
+) -> Result<StatusCode, ApiError> {
+    sqlx::query("UPDATE admin_sessions SET revoked_at = NOW() WHERE id = $1")
+        .bind(current.session_id)
+        .execute(&pool)
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+// Admin: the account the current token belongs to
+pub async fn get_current_admin(
+    Extension(pool): Extension<PgPool>,
+    current: CurrentAdmin,
+) -> Result<Json<AdminUser>, ApiError> {
+    let user = sqlx::query_as::<_, AdminUser>("SELECT * FROM admin_users WHERE id = $1")
+        .bind(current.id)
+        .fetch_one(&pool)
+        .await?;
+    Ok(Json(user))
+}
+
+// Admin: roles and the permissions each one grants
+pub async fn list_roles() -> Json<Vec<RoleInfo>> {
+    Json(
+        Role::ALL
+            .into_iter()
+            .map(|role| RoleInfo {
+                role,
+                permissions: role.permissions(),
+            })
+            .collect(),
+    )
+}
+
+// Admin: list admin accounts
+pub async fn list_admin_users(
+    Extension(pool): Extension<PgPool>,
+    admin: CurrentAdmin,
+) -> Result<Json<Vec<AdminUser>>, ApiError> {
+    admin.require(Permission::ManageUsers)?;
+
+    let users = sqlx::query_as::<_, AdminUser>("SELECT * FROM admin_users ORDER BY username")
+        .fetch_all(&pool)
+        .await?;
+    Ok(Json(users))
+}
+
+// Admin: create an admin account
+pub async fn create_admin_user(
+    Extension(pool): Extension<PgPool>,
+    admin: CurrentAdmin,
+    payload: Result<Json<AdminUserInput>, JsonRejection>,
+) -> Result<(StatusCode, Json<AdminUser>), ApiError> {
+    admin.require(Permission::ManageUsers)?;
+
+    let Json(input) = payload?;
+    input.validate().map_err(ApiError::BadRequest)?;
+
+    if let Some(college_id) = input.college_id {
+        let exists: bool = sqlx::query_scalar(
+            "SELECT EXISTS (SELECT 1 FROM colleges WHERE id = $1 AND deleted_at IS NULL)",
+        )
+        .bind(college_id)
+        .fetch_one(&pool)
+        .await?;
+        if !exists {
+            return Err(ApiError::BadRequest(format!(
+                "Unknown college_id {}",
+                college_id
+            )));
+        }
+    }
+
+    let password_hash = hash_password(input.password).await?;
+    let user = sqlx::query_as::<_, AdminUser>(
+        r#"
+        INSERT INTO admin_users (username, password_hash, role, college_id)
+        VALUES ($1, $2, $3, $4)
+        ON CONFLICT (username) DO NOTHING
+        RETURNING *
+        "#,
+    )
+    .bind(input.username.trim().to_lowercase())
+    .bind(password_hash)
+    .bind(input.role.as_deref().unwrap_or(Role::Volunteer.as_str()))
+    .bind(input.college_id)
+    .fetch_optional(&pool)
+    .await?
+    .ok_or_else(|| ApiError::Conflict("Username is already taken".to_string()))?;
+
+    Ok((StatusCode::CREATED, Json(user)))
+}
+
+// Admin: change an account's password, role or active flag. Password changes and
+// deactivation revoke the account's existing tokens; the last active superadmin
+// cannot be demoted or deactivated.
+pub async fn update_admin_user(
+    Path(id): Path<i32>,
+    Extension(pool): Extension<PgPool>,
+    admin: CurrentAdmin,
+    payload: Result<Json<AdminUserUpdate>, JsonRejection>,
+) -> Result<Json<AdminUser>, ApiError> {
+    admin.require(Permission::ManageUsers)?;
+
+    let Json(input) = payload?;
+    input.validate().map_err(ApiError::BadRequest)?;
+
+    let password_hash = match input.password {
+        Some(password) => Some(hash_password(password).await?),
+        None => None,
+    };
+
+    let mut tx = pool.begin().await?;
+    // Serialise role and activation changes against the superadmin check below
+    sqlx::query("SELECT id FROM admin_users WHERE role = 'superadmin' AND active FOR UPDATE")
+        .execute(&mut *tx)
+        .await?;
+
+    let user = sqlx::query_as::<_, AdminUser>(
+        r#"
+        UPDATE admin_users
+        SET password_hash = COALESCE($2, password_hash),
+            role = COALESCE($3, role),
+            -- Representatives moved to another role lose their college scope
+            college_id = CASE WHEN COALESCE($3, role) = 'representative' THEN college_id END,
+            active = COALESCE($4, active)
+        WHERE id = $1
+        RETURNING *
+        "#,
+    )
+    .bind(id)
+    .bind(&password_hash)
+    .bind(&input.role)
+    .bind(input.active)
+    .fetch_optional(&mut *tx)
+    .await?
+    .ok_or_else(|| ApiError::NotFound("Admin user not found".to_string()))?;
+
+    let superadmins: i64 =
+        sqlx::query_scalar("SELECT COUNT(*) FROM admin_users WHERE role = 'superadmin' AND active")
+            .fetch_one(&mut *tx)
+            .await?;
+    if superadmins == 0 {
+        return Err(ApiError::Conflict(
+            "At least one active superadmin account is required".to_string(),
+        ));
+    }
+
+    if password_hash.is_some() || input.active == Some(false) {
+        revoke_sessions(&mut *tx, id).await?;
+    }
+    tx.commit().await?;
+
+    Ok(Json(user))
+}
+
+// Admin: revoke every token issued to an account
+pub async fn revoke_admin_user_tokens(
+    Path(id): Path<i32>,
+    Extension(pool): Extension<PgPool>,
+    admin: CurrentAdmin,
+) -> Result<StatusCode, ApiError> {
+    admin.require(Permission::ManageUsers)?;
+
+    let exists: bool =
+        sqlx::query_scalar("SELECT EXISTS (SELECT 1 FROM admin_users WHERE id = $1)")
+            .bind(id)
+            .fetch_one(&pool)
+            .await?;
+    if !exists {
+        return Err(ApiError::NotFound("Admin user not found".to_string()));
+    }
+
+    revoke_sessions(&pool, id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+async fn revoke_sessions<'e, E>(executor: E, user_id: i32) -> Result<(), sqlx::Error>
+where
+    E: sqlx::PgExecutor<'e>,
+{
+    sqlx::query(
+        "UPDATE admin_sessions SET revoked_at = NOW() WHERE user_id = $1 AND revoked_at IS NULL",
+    )
+    .bind(user_id)
+    .execute(executor)
+    .await?;
+    Ok(())
+}
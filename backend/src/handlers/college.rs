@@ -1,34 +1,61 @@
+use crate::audit::{self, AuditSource};
+use crate::auth::{CurrentAdmin, Permission};
 use crate::db::queries;
 use crate::errors::ApiError;
-use crate::models::college::CollegeWithContact;
-use crate::models::{College, CollegeFilters};
+use crate::handlers::image::{local_path, remove_unreferenced_files, upload_dir};
+use crate::models::college::{
+    parse_gender_policy, CollegeDeletion, CollegeDependents, CollegeDetail, CollegeInput,
+    CollegePatch, CollegePurge, CollegeVersion, CollegeWithContact, DeletedCollege,
+};
+use crate::models::{Accreditation, AsOf, College, CollegeFilters, HistoryQuery};
 use axum::{
-    extract::{Path, Query},
+    extract::{rejection::JsonRejection, Path, Query},
+    http::StatusCode,
     Extension, Json,
 };
-use sqlx::PgPool;
+use chrono::{DateTime, Duration, Utc};
+use sqlx::{PgPool, Postgres, QueryBuilder, Transaction};
+
+// Days a deleted college stays restorable before it can be purged
+fn retention_days() -> i32 {
+    std::env::var("COLLEGE_RETENTION_DAYS")
+        .ok()
+        .and_then(|days| days.parse().ok())
+        .filter(|days| *days >= 0)
+        .unwrap_or(30)
+}
 
 pub async fn list_colleges(
-    Query(filters): Query<CollegeFilters>,
+    Query(mut filters): Query<CollegeFilters>,
     Extension(pool): Extension<PgPool>,
 ) -> Result<Json<Vec<College>>, ApiError> {
-    let colleges = if let Some(search) = &filters.search {
+    if let Some(policy) = filters.gender_policy.as_deref() {
+        filters.gender_policy = Some(parse_gender_policy(policy).map_err(ApiError::BadRequest)?);
+    }
+
+    // Historical listings always go through the filter query, which can read past versions
+    let colleges = if filters.as_of.is_some() {
+        queries::fetch_colleges_with_filters(&pool, &filters).await?
+    } else if let Some(search) = &filters.search {
         queries::search_colleges_by_name(&pool, search).await?
     } else if filters.district.is_some()
         || filters.category.is_some()
         || filters.college_type.is_some()
+        || filters.gender_policy.is_some()
+        || filters.language.is_some()
         || filters.autonomous.is_some()
         || filters.hostel_available.is_some()
+        || filters.girls_hostel.is_some()
+        || filters.max_hostel_fee.is_some()
+        || filters.university.is_some()
+        || filters.facilities.is_some()
+        || filters.max_fee.is_some()
+        || filters.naac_min.is_some()
+        || filters.nirf_max.is_some()
+        || filters.min_placement_rate.is_some()
+        || filters.sort.is_some()
     {
-        queries::fetch_colleges_with_filters(
-            &pool,
-            filters.district.as_deref(),
-            filters.category.as_deref(),
-            filters.college_type.as_deref(),
-            filters.autonomous,
-            filters.hostel_available,
-        )
-        .await?
+        queries::fetch_colleges_with_filters(&pool, &filters).await?
     } else {
         queries::fetch_all_colleges(&pool).await?
     };
@@ -36,13 +63,48 @@ pub async fn list_colleges(
     Ok(Json(colleges))
 }
 
-/// Get detailed college info with contact details by college ID.
+/// Get detailed college info with contact details, accreditation, facilities, hostels
+/// and applicable scholarships by college ID. With `as_of`, the college and contact
+/// fields are shown as they were at that time; the related records are current.
 pub async fn get_college(
     Path(id): Path<i32>,
+    Query(history): Query<HistoryQuery>,
     Extension(pool): Extension<PgPool>,
-) -> Result<Json<CollegeWithContact>, ApiError> {
-    let college = queries::fetch_college_with_contact_by_id(&pool, id).await?;
-    Ok(Json(college))
+) -> Result<Json<CollegeDetail>, ApiError> {
+    let college = match history.as_of {
+        Some(AsOf(at)) => queries::fetch_college_with_contact_as_of(&pool, id, at).await?,
+        None => queries::fetch_college_with_contact_by_id(&pool, id).await?,
+    };
+    let accreditation = Accreditation {
+        naac: queries::fetch_naac_by_college(&pool, id).await?,
+        nba: queries::fetch_nba_by_college(&pool, id).await?,
+        nirf: queries::fetch_nirf_by_college(&pool, id).await?,
+    };
+    let facilities = queries::fetch_facilities_by_college(&pool, id).await?;
+    let hostels = queries::fetch_hostels_by_college(&pool, id).await?;
+    let images = queries::fetch_images_by_college(&pool, id).await?;
+    let attachments = queries::fetch_attachments_by_college(&pool, id).await?;
+    let scholarships = queries::fetch_scholarships_for_course(&pool, &college.category).await?;
+
+    Ok(Json(CollegeDetail {
+        college,
+        accreditation,
+        facilities,
+        hostels,
+        images,
+        attachments,
+        scholarships,
+    }))
+}
+
+/// List every version of a college's profile and contact details, oldest first.
+pub async fn get_college_history(
+    Path(id): Path<i32>,
+    Extension(pool): Extension<PgPool>,
+) -> Result<Json<Vec<CollegeVersion>>, ApiError> {
+    queries::fetch_college_by_id(&pool, id).await?;
+    let versions = queries::fetch_college_history(&pool, id).await?;
+    Ok(Json(versions))
 }
 
 /// List colleges filtered by district.
@@ -62,3 +124,393 @@ pub async fn list_colleges_by_category(
     let colleges = queries::fetch_colleges_by_category(&pool, &category).await?;
     Ok(Json(colleges))
 }
+
+// Turn a duplicate (name, district, city) into a 409 instead of a database error
+fn map_duplicate(err: sqlx::Error) -> ApiError {
+    match &err {
+        sqlx::Error::Database(db_err)
+            if db_err.constraint() == Some("colleges_name_district_city_key") =>
+        {
+            ApiError::Conflict(
+                "A college with this name already exists in that district and city".to_string(),
+            )
+        }
+        _ => ApiError::from(err),
+    }
+}
+
+async fn ensure_university_exists(
+    tx: &mut Transaction<'_, Postgres>,
+    university_id: Option<Option<i32>>,
+) -> Result<(), ApiError> {
+    if let Some(Some(id)) = university_id {
+        let exists: bool =
+            sqlx::query_scalar("SELECT EXISTS (SELECT 1 FROM universities WHERE id = $1)")
+                .bind(id)
+                .fetch_one(&mut **tx)
+                .await?;
+        if !exists {
+            return Err(ApiError::BadRequest(format!(
+                "Unknown university_id {}",
+                id
+            )));
+        }
+    }
+    Ok(())
+}
+
+// Append `, column = value` to an UPDATE when the value was provided
+fn push_set<'a, T>(query_builder: &mut QueryBuilder<'a, Postgres>, column: &str, value: &Option<T>)
+where
+    T: 'a + Clone + Send + sqlx::Encode<'a, Postgres> + sqlx::Type<Postgres>,
+{
+    if let Some(value) = value {
+        query_builder.push(format!(", {} = ", column));
+        query_builder.push_bind(value.clone());
+    }
+}
+
+// Write the fields present in `patch` to the college and its contact info
+pub(crate) async fn apply_college_patch(
+    tx: &mut Transaction<'_, Postgres>,
+    id: i32,
+    patch: &CollegePatch,
+) -> Result<(), ApiError> {
+    let mut query_builder = QueryBuilder::new("UPDATE colleges SET updated_at = CURRENT_TIMESTAMP");
+    push_set(&mut query_builder, "name", &patch.name);
+    push_set(&mut query_builder, "category", &patch.category);
+    push_set(&mut query_builder, "district", &patch.district);
+    push_set(&mut query_builder, "city", &patch.city);
+    push_set(&mut query_builder, "type", &patch.r#type);
+    push_set(&mut query_builder, "autonomous", &patch.autonomous);
+    push_set(&mut query_builder, "minority", &patch.minority);
+    push_set(
+        &mut query_builder,
+        "hostel_available",
+        &patch.hostel_available,
+    );
+    push_set(
+        &mut query_builder,
+        "established_year",
+        &patch.established_year,
+    );
+    push_set(&mut query_builder, "university_id", &patch.university_id);
+    push_set(&mut query_builder, "gender_policy", &patch.gender_policy);
+    push_set(
+        &mut query_builder,
+        "instruction_languages",
+        &patch.instruction_languages,
+    );
+    query_builder.push(" WHERE id = ");
+    query_builder.push_bind(id);
+    query_builder
+        .build()
+        .execute(&mut **tx)
+        .await
+        .map_err(map_duplicate)?;
+
+    if patch.has_contact_changes() {
+        sqlx::query(
+            "INSERT INTO contact_info (college_id) VALUES ($1) ON CONFLICT (college_id) DO NOTHING",
+        )
+        .bind(id)
+        .execute(&mut **tx)
+        .await?;
+
+        let mut query_builder =
+            QueryBuilder::new("UPDATE contact_info SET college_id = college_id");
+        push_set(&mut query_builder, "phone", &patch.phone);
+        push_set(&mut query_builder, "email", &patch.email);
+        push_set(&mut query_builder, "website", &patch.website);
+        push_set(&mut query_builder, "address", &patch.address);
+        push_set(&mut query_builder, "pincode", &patch.pincode);
+        query_builder.push(" WHERE college_id = ");
+        query_builder.push_bind(id);
+        query_builder.build().execute(&mut **tx).await?;
+    }
+
+    Ok(())
+}
+
+// Admin: create a college with optional contact info
+pub async fn create_college(
+    Extension(pool): Extension<PgPool>,
+    admin: CurrentAdmin,
+    payload: Result<Json<CollegeInput>, JsonRejection>,
+) -> Result<(StatusCode, Json<CollegeWithContact>), ApiError> {
+    admin.require(Permission::Upload)?;
+
+    let Json(input) = payload?;
+    let patch = CollegePatch::from(input)
+        .normalize()
+        .map_err(ApiError::BadRequest)?;
+
+    let mut tx = pool.begin().await?;
+    audit::set_context(&mut tx, &admin, AuditSource::ManualEdit).await?;
+    ensure_university_exists(&mut tx, patch.university_id).await?;
+
+    let id: i32 = sqlx::query_scalar(
+        r#"
+        INSERT INTO colleges (name, category, district, city, type)
+        VALUES ($1, $2, $3, $4, $5)
+        RETURNING id
+        "#,
+    )
+    .bind(&patch.name)
+    .bind(&patch.category)
+    .bind(&patch.district)
+    .bind(&patch.city)
+    .bind(&patch.r#type)
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(map_duplicate)?;
+
+    apply_college_patch(&mut tx, id, &patch).await?;
+    tx.commit().await?;
+
+    let college = queries::fetch_college_with_contact_by_id(&pool, id).await?;
+    Ok((StatusCode::CREATED, Json(college)))
+}
+
+// Admin: replace every field of a college and its contact info
+pub async fn replace_college(
+    Path(id): Path<i32>,
+    Extension(pool): Extension<PgPool>,
+    admin: CurrentAdmin,
+    payload: Result<Json<CollegeInput>, JsonRejection>,
+) -> Result<Json<CollegeWithContact>, ApiError> {
+    admin.require(Permission::Edit)?;
+
+    let Json(input) = payload?;
+    update_college_fields(&pool, &admin, id, CollegePatch::from(input)).await
+}
+
+// Admin: change only the provided fields of a college and its contact info
+pub async fn patch_college(
+    Path(id): Path<i32>,
+    Extension(pool): Extension<PgPool>,
+    admin: CurrentAdmin,
+    payload: Result<Json<CollegePatch>, JsonRejection>,
+) -> Result<Json<CollegeWithContact>, ApiError> {
+    admin.require(Permission::Edit)?;
+
+    let Json(patch) = payload?;
+    update_college_fields(&pool, &admin, id, patch).await
+}
+
+async fn update_college_fields(
+    pool: &PgPool,
+    admin: &CurrentAdmin,
+    id: i32,
+    patch: CollegePatch,
+) -> Result<Json<CollegeWithContact>, ApiError> {
+    let patch = patch.normalize().map_err(ApiError::BadRequest)?;
+
+    let mut tx = pool.begin().await?;
+    audit::set_context(&mut tx, admin, AuditSource::ManualEdit).await?;
+    sqlx::query_scalar::<_, i32>(
+        "SELECT id FROM colleges WHERE id = $1 AND deleted_at IS NULL FOR UPDATE",
+    )
+    .bind(id)
+    .fetch_optional(&mut *tx)
+    .await?
+    .ok_or_else(|| ApiError::NotFound("College not found".to_string()))?;
+    ensure_university_exists(&mut tx, patch.university_id).await?;
+    apply_college_patch(&mut tx, id, &patch).await?;
+    tx.commit().await?;
+
+    let college = queries::fetch_college_with_contact_by_id(pool, id).await?;
+    Ok(Json(college))
+}
+
+// Admin: preview the records that purging a college would remove
+pub async fn get_college_dependents(
+    Path(id): Path<i32>,
+    Extension(pool): Extension<PgPool>,
+    admin: CurrentAdmin,
+) -> Result<Json<CollegeDependents>, ApiError> {
+    admin.require(Permission::Delete)?;
+
+    // Deleted colleges are included, since those are the ones that get purged
+    sqlx::query_scalar::<_, i32>("SELECT id FROM colleges WHERE id = $1")
+        .bind(id)
+        .fetch_optional(&pool)
+        .await?
+        .ok_or_else(|| ApiError::NotFound("College not found".to_string()))?;
+    let dependents = queries::fetch_college_dependents(&pool, id).await?;
+    Ok(Json(dependents))
+}
+
+// Admin: delete a college. It is hidden from every public query but kept, with
+// everything that references it, so it can be restored until it is purged.
+pub async fn delete_college(
+    Path(id): Path<i32>,
+    Extension(pool): Extension<PgPool>,
+    admin: CurrentAdmin,
+) -> Result<Json<CollegeDeletion>, ApiError> {
+    admin.require(Permission::Delete)?;
+
+    let mut tx = pool.begin().await?;
+    audit::set_context(&mut tx, &admin, AuditSource::ManualEdit).await?;
+    let (name, deleted_at): (String, DateTime<Utc>) = sqlx::query_as(
+        r#"
+        UPDATE colleges SET deleted_at = CURRENT_TIMESTAMP, deleted_by = $2
+        WHERE id = $1 AND deleted_at IS NULL
+        RETURNING name, deleted_at
+        "#,
+    )
+    .bind(id)
+    .bind(admin.id)
+    .fetch_optional(&mut *tx)
+    .await?
+    .ok_or_else(|| ApiError::NotFound("College not found".to_string()))?;
+    tx.commit().await?;
+
+    Ok(Json(CollegeDeletion {
+        id,
+        name,
+        deleted_at,
+        purge_after: deleted_at + Duration::days(retention_days().into()),
+    }))
+}
+
+// Admin: list deleted colleges that have not been purged, most recently deleted first
+pub async fn list_deleted_colleges(
+    Extension(pool): Extension<PgPool>,
+    admin: CurrentAdmin,
+) -> Result<Json<Vec<DeletedCollege>>, ApiError> {
+    admin.require(Permission::Delete)?;
+
+    let colleges = sqlx::query_as::<_, DeletedCollege>(
+        r#"
+        SELECT c.id, c.name, c.district, c.city, c.deleted_at, c.deleted_by,
+               a.username AS deleted_by_username,
+               c.deleted_at + make_interval(days => $1) AS purge_after
+        FROM colleges c
+        LEFT JOIN admin_users a ON a.id = c.deleted_by
+        WHERE c.deleted_at IS NOT NULL
+        ORDER BY c.deleted_at DESC, c.id
+        "#,
+    )
+    .bind(retention_days())
+    .fetch_all(&pool)
+    .await?;
+    Ok(Json(colleges))
+}
+
+// Admin: bring back a deleted college that has not been purged yet
+pub async fn restore_college(
+    Path(id): Path<i32>,
+    Extension(pool): Extension<PgPool>,
+    admin: CurrentAdmin,
+) -> Result<Json<CollegeWithContact>, ApiError> {
+    admin.require(Permission::Delete)?;
+
+    let mut tx = pool.begin().await?;
+    audit::set_context(&mut tx, &admin, AuditSource::ManualEdit).await?;
+    sqlx::query_scalar::<_, i32>(
+        r#"
+        UPDATE colleges SET deleted_at = NULL, deleted_by = NULL
+        WHERE id = $1 AND deleted_at IS NOT NULL
+        RETURNING id
+        "#,
+    )
+    .bind(id)
+    .fetch_optional(&mut *tx)
+    .await?
+    .ok_or_else(|| ApiError::NotFound("Deleted college not found".to_string()))?;
+    tx.commit().await?;
+
+    let college = queries::fetch_college_with_contact_by_id(&pool, id).await?;
+    Ok(Json(college))
+}
+
+// Admin: permanently remove a deleted college whose retention period has passed,
+// along with everything that references it and its stored image and attachment
+// files. Its version history is kept, like its audit log entries.
+pub async fn purge_college(
+    Path(id): Path<i32>,
+    Extension(pool): Extension<PgPool>,
+    admin: CurrentAdmin,
+) -> Result<Json<CollegePurge>, ApiError> {
+    admin.require(Permission::Purge)?;
+
+    let mut tx = pool.begin().await?;
+    audit::set_context(&mut tx, &admin, AuditSource::ManualEdit).await?;
+
+    let (name, deleted_at): (String, Option<DateTime<Utc>>) =
+        sqlx::query_as("SELECT name, deleted_at FROM colleges WHERE id = $1 FOR UPDATE")
+            .bind(id)
+            .fetch_optional(&mut *tx)
+            .await?
+            .ok_or_else(|| ApiError::NotFound("College not found".to_string()))?;
+    let deleted_at = deleted_at.ok_or_else(|| {
+        ApiError::Conflict("Only deleted colleges can be purged; delete it first".to_string())
+    })?;
+    let purge_after = deleted_at + Duration::days(retention_days().into());
+    if Utc::now() < purge_after {
+        return Err(ApiError::Conflict(format!(
+            "College was deleted at {} and can be purged after {}",
+            deleted_at.to_rfc3339(),
+            purge_after.to_rfc3339()
+        )));
+    }
+
+    // Representative accounts are not removed along with the college
+    let representatives: i64 =
+        sqlx::query_scalar("SELECT COUNT(*) FROM admin_users WHERE college_id = $1")
+            .bind(id)
+            .fetch_one(&mut *tx)
+            .await?;
+    if representatives > 0 {
+        return Err(ApiError::Conflict(format!(
+            "College has {} representative account(s); move them to another role before purging",
+            representatives
+        )));
+    }
+
+    let dependents = queries::fetch_college_dependents(&mut *tx, id).await?;
+    let rendition_files: Vec<String> = sqlx::query_scalar(
+        r#"
+        SELECT r.file_path FROM image_renditions r
+        JOIN college_images i ON i.id = r.image_id
+        WHERE i.college_id = $1
+        "#,
+    )
+    .bind(id)
+    .fetch_all(&mut *tx)
+    .await?;
+    let image_urls: Vec<String> =
+        sqlx::query_scalar("SELECT image_url FROM college_images WHERE college_id = $1")
+            .bind(id)
+            .fetch_all(&mut *tx)
+            .await?;
+    let attachment_files: Vec<String> =
+        sqlx::query_scalar("SELECT file_path FROM college_attachments WHERE college_id = $1")
+            .bind(id)
+            .fetch_all(&mut *tx)
+            .await?;
+
+    sqlx::query("DELETE FROM colleges WHERE id = $1")
+        .bind(id)
+        .execute(&mut *tx)
+        .await?;
+    tx.commit().await?;
+
+    remove_unreferenced_files(&pool, rendition_files).await;
+    let stored_files = image_urls
+        .iter()
+        .filter_map(|url| local_path(url))
+        .chain(attachment_files.iter().map(|file| upload_dir().join(file)));
+    for path in stored_files {
+        if let Err(e) = tokio::fs::remove_file(&path).await {
+            tracing::warn!("Failed to remove file {}: {}", path.display(), e);
+        }
+    }
+
+    Ok(Json(CollegePurge {
+        id,
+        name,
+        deleted: dependents,
+    }))
+}
@@ -0,0 +1,254 @@
+use crate::auth::{CurrentAdmin, Permission};
+use crate::db::queries;
+use crate::errors::ApiError;
+use crate::models::concession::{AppliedConcession, ConcessionRuleInput, YearCost};
+use crate::models::{ConcessionRule, CostEstimate, CostEstimateQuery, FeeStructure};
+use axum::{
+    extract::{Path, Query},
+    http::StatusCode,
+    Extension, Json,
+};
+use sqlx::PgPool;
+
+const DEFAULT_DURATION: i32 = 4;
+const MAX_DURATION: i32 = 6;
+
+/// Estimate the cost of attendance for a course after applicable concessions.
+pub async fn get_cost_estimate(
+    Path(id): Path<i32>,
+    Query(params): Query<CostEstimateQuery>,
+    Extension(pool): Extension<PgPool>,
+) -> Result<Json<CostEstimate>, ApiError> {
+    let duration = params.duration.unwrap_or(DEFAULT_DURATION);
+    if !(1..=MAX_DURATION).contains(&duration) {
+        return Err(ApiError::BadRequest(format!(
+            "duration must be between 1 and {}",
+            MAX_DURATION
+        )));
+    }
+
+    let fees = queries::fetch_fees_by_college(&pool, id).await?;
+    let fee = select_fee(fees, params.course.as_deref()).ok_or_else(|| {
+        ApiError::NotFound("No fee data found for this college and course".to_string())
+    })?;
+
+    let rules = queries::fetch_active_concession_rules(&pool, fee.year).await?;
+
+    Ok(Json(estimate_cost(&fee, &rules, &params, duration)))
+}
+
+// Latest-year fee row, for the requested course when one is given
+fn select_fee(fees: Vec<FeeStructure>, course: Option<&str>) -> Option<FeeStructure> {
+    fees.into_iter()
+        .filter(|f| course.is_none_or(|c| f.course.eq_ignore_ascii_case(c.trim())))
+        .max_by_key(|f| f.year)
+}
+
+/// Apply concession rules to a fee structure. For each fee component only the most
+/// generous matching rule counts, so overlapping schemes never stack past the fee.
+pub fn estimate_cost(
+    fee: &FeeStructure,
+    rules: &[ConcessionRule],
+    params: &CostEstimateQuery,
+    duration: i32,
+) -> CostEstimate {
+    let category = params
+        .category
+        .as_deref()
+        .unwrap_or("OPEN")
+        .trim()
+        .to_uppercase();
+    let hostel = params.hostel.unwrap_or(false);
+    let hostel_fee = if hostel {
+        fee.hostel_fee.unwrap_or(0)
+    } else {
+        0
+    };
+
+    let components = [
+        ("tuition", fee.tuition_fee),
+        ("development", fee.development_fee),
+        ("exam", fee.exam_fee),
+        ("hostel", hostel_fee),
+    ];
+
+    let mut concessions = Vec::new();
+    for (component, amount) in components {
+        let best = rules
+            .iter()
+            .filter(|r| r.component == component)
+            .filter(|r| r.applies_to(&category, params.income_band, hostel))
+            .map(|r| (r, r.concession_on(amount).min(amount)))
+            .max_by_key(|(_, concession)| *concession);
+
+        if let Some((rule, concession)) = best {
+            if concession > 0 {
+                concessions.push(AppliedConcession {
+                    rule_id: rule.id,
+                    name: rule.name.clone(),
+                    component: component.to_string(),
+                    percentage: rule.percentage,
+                    amount_per_year: concession,
+                });
+            }
+        }
+    }
+
+    let gross = fee.tuition_fee + fee.development_fee + fee.exam_fee + hostel_fee;
+    let concession: i32 = concessions.iter().map(|c| c.amount_per_year).sum();
+
+    let years: Vec<YearCost> = (1..=duration)
+        .map(|year_of_study| YearCost {
+            year_of_study,
+            tuition_fee: fee.tuition_fee,
+            development_fee: fee.development_fee,
+            exam_fee: fee.exam_fee,
+            hostel_fee,
+            gross,
+            concession,
+            net: gross - concession,
+        })
+        .collect();
+
+    CostEstimate {
+        college_id: fee.college_id,
+        course: fee.course.clone(),
+        fee_year: fee.year,
+        published_category_fee: fee.fee_for_category(&category),
+        category,
+        income_band: params.income_band,
+        hostel,
+        concessions,
+        total_gross: years.iter().map(|y| y.gross).sum(),
+        total_concession: years.iter().map(|y| y.concession).sum(),
+        total_net: years.iter().map(|y| y.net).sum(),
+        years,
+    }
+}
+
+// Admin: list all concession rules
+pub async fn list_concession_rules(
+    Extension(pool): Extension<PgPool>,
+    admin: CurrentAdmin,
+) -> Result<Json<Vec<ConcessionRule>>, ApiError> {
+    admin.require(Permission::Edit)?;
+
+    let rules = sqlx::query_as::<_, ConcessionRule>(
+        "SELECT * FROM concession_rules ORDER BY academic_year DESC NULLS FIRST, id",
+    )
+    .fetch_all(&pool)
+    .await?;
+
+    Ok(Json(rules))
+}
+
+// Admin: create a concession rule
+pub async fn create_concession_rule(
+    Extension(pool): Extension<PgPool>,
+    admin: CurrentAdmin,
+    Json(input): Json<ConcessionRuleInput>,
+) -> Result<(StatusCode, Json<ConcessionRule>), ApiError> {
+    admin.require(Permission::Edit)?;
+
+    input.validate().map_err(ApiError::BadRequest)?;
+
+    let rule = sqlx::query_as::<_, ConcessionRule>(
+        r#"
+        INSERT INTO concession_rules (
+            name, description, academic_year, categories, max_family_income,
+            requires_hostel, component, percentage, max_amount, active
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+        RETURNING *
+        "#,
+    )
+    .bind(input.name.trim())
+    .bind(&input.description)
+    .bind(input.academic_year)
+    .bind(normalize_categories(&input.categories))
+    .bind(input.max_family_income)
+    .bind(input.requires_hostel)
+    .bind(&input.component)
+    .bind(input.percentage)
+    .bind(input.max_amount)
+    .bind(input.active.unwrap_or(true))
+    .fetch_one(&pool)
+    .await?;
+
+    Ok((StatusCode::CREATED, Json(rule)))
+}
+
+// Admin: replace a concession rule
+pub async fn update_concession_rule(
+    Path(id): Path<i32>,
+    Extension(pool): Extension<PgPool>,
+    admin: CurrentAdmin,
+    Json(input): Json<ConcessionRuleInput>,
+) -> Result<Json<ConcessionRule>, ApiError> {
+    admin.require(Permission::Edit)?;
+
+    input.validate().map_err(ApiError::BadRequest)?;
+
+    let rule = sqlx::query_as::<_, ConcessionRule>(
+        r#"
+        UPDATE concession_rules SET
+            name = $2,
+            description = $3,
+            academic_year = $4,
+            categories = $5,
+            max_family_income = $6,
+            requires_hostel = $7,
+            component = $8,
+            percentage = $9,
+            max_amount = $10,
+            active = $11,
+            updated_at = CURRENT_TIMESTAMP
+        WHERE id = $1
+        RETURNING *
+        "#,
+    )
+    .bind(id)
+    .bind(input.name.trim())
+    .bind(&input.description)
+    .bind(input.academic_year)
+    .bind(normalize_categories(&input.categories))
+    .bind(input.max_family_income)
+    .bind(input.requires_hostel)
+    .bind(&input.component)
+    .bind(input.percentage)
+    .bind(input.max_amount)
+    .bind(input.active.unwrap_or(true))
+    .fetch_one(&pool)
+    .await?;
+
+    Ok(Json(rule))
+}
+
+// Admin: delete a concession rule
+pub async fn delete_concession_rule(
+    Path(id): Path<i32>,
+    Extension(pool): Extension<PgPool>,
+    admin: CurrentAdmin,
+) -> Result<StatusCode, ApiError> {
+    admin.require(Permission::Delete)?;
+
+    let result = sqlx::query("DELETE FROM concession_rules WHERE id = $1")
+        .bind(id)
+        .execute(&pool)
+        .await?;
+
+    if result.rows_affected() == 0 {
+        return Err(ApiError::NotFound("Concession rule not found".to_string()));
+    }
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+// Uppercase admission categories and drop blanks
+pub(crate) fn normalize_categories(categories: &[String]) -> Vec<String> {
+    categories
+        .iter()
+        .map(|c| c.trim().to_uppercase())
+        .filter(|c| !c.is_empty())
+        .collect()
+}
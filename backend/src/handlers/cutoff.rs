@@ -1,14 +1,31 @@
+use crate::auth::{CurrentAdmin, Permission};
 use crate::errors::ApiError;
+use crate::handlers::admin::{
+    check_declared_size, max_csv_upload_bytes, read_upload, BatchImport, SheetOptions,
+};
+use crate::handlers::import_job;
+use crate::models::cutoff::{CutoffImport, CutoffInput};
+use crate::models::import_job::ImportJob;
 use crate::models::Cutoff;
-use axum::{extract::Path, Extension, Json};
-use sqlx::PgPool;
+use axum::{
+    extract::{Multipart, Path, Query},
+    http::{HeaderMap, StatusCode},
+    Extension, Json,
+};
+use sqlx::{PgPool, Postgres, Transaction};
+use std::collections::HashMap;
 
 pub async fn get_cutoffs_by_college(
     Path(college_id): Path<i32>,
     Extension(pool): Extension<PgPool>,
 ) -> Result<Json<Vec<Cutoff>>, ApiError> {
     let cutoffs = sqlx::query_as::<_, Cutoff>(
-        "SELECT * FROM cutoffs WHERE college_id = $1 ORDER BY year DESC",
+        r#"
+        SELECT * FROM cutoffs
+        WHERE college_id = $1
+          AND college_id IN (SELECT id FROM colleges WHERE deleted_at IS NULL)
+        ORDER BY year DESC
+        "#,
     )
     .bind(college_id)
     .fetch_all(&pool)
@@ -16,3 +33,208 @@ pub async fn get_cutoffs_by_college(
 
     Ok(Json(cutoffs))
 }
+
+// CSV Upload Handler for cutoffs; the file is stored and imported by a background job
+// whose id is returned straight away. Its size is bounded by `MAX_CSV_UPLOAD_BYTES`.
+pub async fn upload_cutoffs_csv(
+    Extension(pool): Extension<PgPool>,
+    admin: CurrentAdmin,
+    headers: HeaderMap,
+    mut multipart: Multipart,
+) -> Result<(StatusCode, Json<ImportJob>), ApiError> {
+    admin.require(Permission::Upload)?;
+
+    let max_bytes = max_csv_upload_bytes();
+    check_declared_size(&headers, max_bytes)?;
+    let job = import_job::start_csv_job(
+        &pool,
+        &admin,
+        "cutoffs_csv",
+        false,
+        &mut multipart,
+        max_bytes,
+    )
+    .await?;
+    Ok((StatusCode::ACCEPTED, Json(job)))
+}
+
+// Spreadsheet Upload Handler for cutoffs, imported by a background job; `sheet` and
+// `header_row` choose the table to read from the workbook
+pub async fn upload_cutoffs_spreadsheet(
+    Query(sheet_options): Query<SheetOptions>,
+    Extension(pool): Extension<PgPool>,
+    admin: CurrentAdmin,
+    mut multipart: Multipart,
+) -> Result<(StatusCode, Json<ImportJob>), ApiError> {
+    admin.require(Permission::Upload)?;
+
+    let data = read_upload(&mut multipart).await?;
+    let sheet = sheet_options.read(data.clone()).await?;
+    let job = import_job::start_spreadsheet_job(
+        &pool,
+        &admin,
+        "cutoffs_spreadsheet",
+        false,
+        &data,
+        &sheet,
+    )
+    .await?;
+    Ok((StatusCode::ACCEPTED, Json(job)))
+}
+
+// Uploaded cutoffs are written in batches; a row replaces the cutoff recorded for
+// the same college, year, branch and category
+impl BatchImport for CutoffImport {
+    // A missing branch or category is the same key as an empty one, as in the
+    // unique index
+    type Key = (i32, i32, String, String);
+    type Cache = ();
+    const NOUN: &'static str = "cutoff";
+
+    fn key(&self) -> Self::Key {
+        (
+            self.college_id,
+            self.year,
+            self.branch.clone().unwrap_or_default(),
+            self.category.clone().unwrap_or_default(),
+        )
+    }
+
+    async fn write_batch(
+        tx: &mut Transaction<'_, Postgres>,
+        _: &mut (),
+        rows: &[(usize, CutoffImport)],
+    ) -> Result<Vec<Result<bool, String>>, sqlx::Error> {
+        let mut results: Vec<Result<bool, String>> = Vec::with_capacity(rows.len());
+        let mut batch = CutoffBatch::default();
+        for (_, row) in rows {
+            let (college_id, cutoff) = row.to_input();
+            match cutoff.validate() {
+                Ok(()) => {
+                    batch.push(college_id, cutoff);
+                    results.push(Ok(false));
+                }
+                Err(e) => results.push(Err(e)),
+            }
+        }
+        if batch.college_id.is_empty() {
+            return Ok(results);
+        }
+
+        // Rows for missing or deleted colleges are left out and reported below
+        let written: Vec<(i32, i32, String, String, bool)> = sqlx::query_as(
+            r#"
+            INSERT INTO cutoffs (college_id, year, branch, category, cutoff_marks, pdf_url)
+            SELECT * FROM UNNEST(
+                $1::int4[], $2::int4[], $3::text[], $4::text[], $5::float8[], $6::text[]
+            ) AS input(college_id, year, branch, category, cutoff_marks, pdf_url)
+            WHERE input.college_id IN (SELECT id FROM colleges WHERE deleted_at IS NULL)
+            ON CONFLICT (college_id, year, COALESCE(branch, ''), COALESCE(category, ''))
+            DO UPDATE SET cutoff_marks = EXCLUDED.cutoff_marks, pdf_url = EXCLUDED.pdf_url
+            RETURNING college_id, year, COALESCE(branch, ''), COALESCE(category, ''),
+                      (xmax = 0) AS was_insert
+            "#,
+        )
+        .bind(&batch.college_id)
+        .bind(&batch.year)
+        .bind(&batch.branch)
+        .bind(&batch.category)
+        .bind(&batch.cutoff_marks)
+        .bind(&batch.pdf_url)
+        .fetch_all(&mut **tx)
+        .await?;
+
+        let written: HashMap<(i32, i32, String, String), bool> = written
+            .into_iter()
+            .map(|(college_id, year, branch, category, was_insert)| {
+                ((college_id, year, branch, category), was_insert)
+            })
+            .collect();
+        for ((_, row), result) in rows.iter().zip(results.iter_mut()) {
+            if result.is_err() {
+                continue;
+            }
+            *result = match written.get(&row.key()) {
+                Some(was_insert) => Ok(!was_insert),
+                None => Err(format!("College {} not found", row.college_id)),
+            };
+        }
+        Ok(results)
+    }
+
+    async fn write_one(
+        tx: &mut Transaction<'_, Postgres>,
+        _: &mut (),
+        row: CutoffImport,
+    ) -> Result<bool, String> {
+        let (college_id, cutoff) = row.to_input();
+        cutoff.validate()?;
+
+        let exists: bool = sqlx::query_scalar(
+            "SELECT EXISTS (SELECT 1 FROM colleges WHERE id = $1 AND deleted_at IS NULL)",
+        )
+        .bind(college_id)
+        .fetch_one(&mut **tx)
+        .await
+        .map_err(|e| e.to_string())?;
+        if !exists {
+            return Err(format!("College {} not found", college_id));
+        }
+
+        upsert_cutoff(&mut **tx, college_id, &cutoff)
+            .await
+            .map_err(|e| e.to_string())
+    }
+}
+
+// Column arrays for the batched cutoff upsert
+#[derive(Default)]
+struct CutoffBatch {
+    college_id: Vec<i32>,
+    year: Vec<i32>,
+    branch: Vec<Option<String>>,
+    category: Vec<Option<String>>,
+    cutoff_marks: Vec<Option<f64>>,
+    pdf_url: Vec<Option<String>>,
+}
+
+impl CutoffBatch {
+    fn push(&mut self, college_id: i32, cutoff: CutoffInput) {
+        self.college_id.push(college_id);
+        self.year.push(cutoff.year);
+        self.branch.push(cutoff.branch);
+        self.category.push(cutoff.category);
+        self.cutoff_marks.push(cutoff.cutoff_marks);
+        self.pdf_url.push(cutoff.pdf_url);
+    }
+}
+
+// Record a cutoff for a college, replacing the one already recorded for its year,
+// branch and category; returns true if one was replaced
+pub(crate) async fn upsert_cutoff<'e, E>(
+    executor: E,
+    college_id: i32,
+    cutoff: &CutoffInput,
+) -> Result<bool, sqlx::Error>
+where
+    E: sqlx::PgExecutor<'e>,
+{
+    let was_insert = sqlx::query_scalar::<_, bool>(
+        r#"
+        INSERT INTO cutoffs (college_id, year, branch, category, cutoff_marks, pdf_url)
+        VALUES ($1, $2, $3, $4, $5, $6)
+        ON CONFLICT (college_id, year, COALESCE(branch, ''), COALESCE(category, ''))
+        DO UPDATE SET cutoff_marks = EXCLUDED.cutoff_marks, pdf_url = EXCLUDED.pdf_url
+        RETURNING (xmax = 0) AS was_insert
+        "#,
+    )
+    .bind(college_id)
+    .bind(cutoff.year)
+    .bind(&cutoff.branch)
+    .bind(&cutoff.category)
+    .bind(cutoff.cutoff_marks)
+    .bind(&cutoff.pdf_url)
+    .fetch_one(executor)
+    .await?;
+    Ok(!was_insert)
+}
@@ -1,5 +1,6 @@
 use crate::db::queries;
 use crate::errors::ApiError;
+use crate::models::Facets;
 use axum::{Extension, Json};
 use sqlx::PgPool;
 
@@ -23,3 +24,22 @@ pub async fn list_college_types(
     let types = queries::fetch_all_college_types(&pool).await?;
     Ok(Json(types))
 }
+
+pub async fn list_gender_policies(
+    Extension(pool): Extension<PgPool>,
+) -> Result<Json<Vec<String>>, ApiError> {
+    let policies = queries::fetch_all_gender_policies(&pool).await?;
+    Ok(Json(policies))
+}
+
+pub async fn list_instruction_languages(
+    Extension(pool): Extension<PgPool>,
+) -> Result<Json<Vec<String>>, ApiError> {
+    let languages = queries::fetch_all_instruction_languages(&pool).await?;
+    Ok(Json(languages))
+}
+
+pub async fn list_facets(Extension(pool): Extension<PgPool>) -> Result<Json<Facets>, ApiError> {
+    let facets = queries::fetch_facets(&pool).await?;
+    Ok(Json(facets))
+}
@@ -0,0 +1,138 @@
+use crate::auth::{CurrentAdmin, Permission};
+use crate::db::queries;
+use crate::errors::ApiError;
+use crate::models::facility::{CollegeFacilitiesInput, FacilityInput};
+use crate::models::{Facility, FacilityWithCount};
+use axum::{extract::Path, http::StatusCode, Extension, Json};
+use sqlx::PgPool;
+
+/// List the facility taxonomy with the number of colleges offering each.
+pub async fn list_facilities(
+    Extension(pool): Extension<PgPool>,
+) -> Result<Json<Vec<FacilityWithCount>>, ApiError> {
+    let facilities = queries::fetch_facilities_with_counts(&pool).await?;
+    Ok(Json(facilities))
+}
+
+// Admin: add a facility to the taxonomy
+pub async fn create_facility(
+    Extension(pool): Extension<PgPool>,
+    admin: CurrentAdmin,
+    Json(input): Json<FacilityInput>,
+) -> Result<(StatusCode, Json<Facility>), ApiError> {
+    admin.require(Permission::Edit)?;
+
+    input.validate().map_err(ApiError::BadRequest)?;
+
+    let facility = sqlx::query_as::<_, Facility>(
+        "INSERT INTO facilities (slug, name, description) VALUES ($1, $2, $3) RETURNING *",
+    )
+    .bind(&input.slug)
+    .bind(input.name.trim())
+    .bind(&input.description)
+    .fetch_one(&pool)
+    .await?;
+
+    Ok((StatusCode::CREATED, Json(facility)))
+}
+
+// Admin: rename or re-describe a facility
+pub async fn update_facility(
+    Path(id): Path<i32>,
+    Extension(pool): Extension<PgPool>,
+    admin: CurrentAdmin,
+    Json(input): Json<FacilityInput>,
+) -> Result<Json<Facility>, ApiError> {
+    admin.require(Permission::Edit)?;
+
+    input.validate().map_err(ApiError::BadRequest)?;
+
+    let facility = sqlx::query_as::<_, Facility>(
+        "UPDATE facilities SET slug = $2, name = $3, description = $4 WHERE id = $1 RETURNING *",
+    )
+    .bind(id)
+    .bind(&input.slug)
+    .bind(input.name.trim())
+    .bind(&input.description)
+    .fetch_one(&pool)
+    .await?;
+
+    Ok(Json(facility))
+}
+
+// Admin: remove a facility and its college links
+pub async fn delete_facility(
+    Path(id): Path<i32>,
+    Extension(pool): Extension<PgPool>,
+    admin: CurrentAdmin,
+) -> Result<StatusCode, ApiError> {
+    admin.require(Permission::Delete)?;
+
+    let result = sqlx::query("DELETE FROM facilities WHERE id = $1")
+        .bind(id)
+        .execute(&pool)
+        .await?;
+
+    if result.rows_affected() == 0 {
+        return Err(ApiError::NotFound("Facility not found".to_string()));
+    }
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+// Admin: replace the set of facilities linked to a college
+pub async fn set_college_facilities(
+    Path(college_id): Path<i32>,
+    Extension(pool): Extension<PgPool>,
+    admin: CurrentAdmin,
+    Json(input): Json<CollegeFacilitiesInput>,
+) -> Result<Json<Vec<Facility>>, ApiError> {
+    admin.require(Permission::Edit)?;
+
+    let slugs: Vec<String> = input
+        .facilities
+        .iter()
+        .map(|slug| slug.trim().to_lowercase())
+        .collect();
+
+    let known: Vec<String> = sqlx::query_scalar("SELECT slug FROM facilities WHERE slug = ANY($1)")
+        .bind(&slugs)
+        .fetch_all(&pool)
+        .await?;
+    let unknown: Vec<&str> = slugs
+        .iter()
+        .filter(|slug| !known.contains(slug))
+        .map(String::as_str)
+        .collect();
+    if !unknown.is_empty() {
+        return Err(ApiError::BadRequest(format!(
+            "Unknown facilities: {}",
+            unknown.join(", ")
+        )));
+    }
+
+    queries::fetch_college_by_id(&pool, college_id).await?;
+
+    let mut tx = pool.begin().await?;
+
+    sqlx::query("DELETE FROM college_facilities WHERE college_id = $1")
+        .bind(college_id)
+        .execute(&mut *tx)
+        .await?;
+
+    sqlx::query(
+        r#"
+        INSERT INTO college_facilities (college_id, facility_id)
+        SELECT $1, id FROM facilities WHERE slug = ANY($2)
+        "#,
+    )
+    .bind(college_id)
+    .bind(&slugs)
+    .execute(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+
+    let facilities = queries::fetch_facilities_by_college(&pool, college_id).await?;
+    Ok(Json(facilities))
+}
@@ -0,0 +1,94 @@
+use crate::auth::{CurrentAdmin, Permission};
+use crate::db::queries;
+use crate::errors::ApiError;
+use crate::handlers::admin::{import_csv, read_csv_file, UploadResponse};
+use crate::models::fee::FeeImport;
+use crate::models::FeeStructure;
+use axum::{
+    extract::{Multipart, Path},
+    Extension, Json,
+};
+use sqlx::PgPool;
+
+/// List fee structures for a college, most recent year first.
+pub async fn get_college_fees(
+    Path(id): Path<i32>,
+    Extension(pool): Extension<PgPool>,
+) -> Result<Json<Vec<FeeStructure>>, ApiError> {
+    let fees = queries::fetch_fees_by_college(&pool, id).await?;
+    Ok(Json(fees))
+}
+
+// CSV Upload Handler for fee structures
+pub async fn upload_fees_csv(
+    Extension(pool): Extension<PgPool>,
+    admin: CurrentAdmin,
+    mut multipart: Multipart,
+) -> Result<Json<UploadResponse>, ApiError> {
+    admin.require(Permission::Upload)?;
+
+    let content = read_csv_file(&mut multipart).await?;
+
+    let response = import_csv("Fee CSV upload completed", &content, |fee: FeeImport| {
+        let pool = pool.clone();
+        async move {
+            fee.validate()?;
+            insert_or_update_fee(&pool, &fee)
+                .await
+                .map_err(|e| e.to_string())
+        }
+    })
+    .await;
+
+    Ok(Json(response))
+}
+
+// Insert or update one fee row; returns true if an existing row was updated
+pub(crate) async fn insert_or_update_fee<'e, E>(
+    executor: E,
+    fee: &FeeImport,
+) -> Result<bool, sqlx::Error>
+where
+    E: sqlx::PgExecutor<'e>,
+{
+    let was_insert = sqlx::query_scalar::<_, bool>(
+        r#"
+        INSERT INTO fee_structures (
+            college_id, course, year, tuition_fee, development_fee, exam_fee, hostel_fee,
+            open_fee, obc_fee, sc_fee, st_fee, ews_fee, tfws_fee
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13)
+        ON CONFLICT (college_id, course, year)
+        DO UPDATE SET
+            tuition_fee = EXCLUDED.tuition_fee,
+            development_fee = EXCLUDED.development_fee,
+            exam_fee = EXCLUDED.exam_fee,
+            hostel_fee = EXCLUDED.hostel_fee,
+            open_fee = EXCLUDED.open_fee,
+            obc_fee = EXCLUDED.obc_fee,
+            sc_fee = EXCLUDED.sc_fee,
+            st_fee = EXCLUDED.st_fee,
+            ews_fee = EXCLUDED.ews_fee,
+            tfws_fee = EXCLUDED.tfws_fee,
+            updated_at = CURRENT_TIMESTAMP
+        RETURNING (xmax = 0) AS was_insert
+        "#,
+    )
+    .bind(fee.college_id)
+    .bind(fee.course.trim())
+    .bind(fee.year)
+    .bind(fee.tuition_fee.unwrap_or(0))
+    .bind(fee.development_fee.unwrap_or(0))
+    .bind(fee.exam_fee.unwrap_or(0))
+    .bind(fee.hostel_fee)
+    .bind(fee.open_fee)
+    .bind(fee.obc_fee)
+    .bind(fee.sc_fee)
+    .bind(fee.st_fee)
+    .bind(fee.ews_fee)
+    .bind(fee.tfws_fee)
+    .fetch_one(executor)
+    .await?;
+
+    Ok(!was_insert)
+}
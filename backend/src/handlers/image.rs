@@ -0,0 +1,533 @@
+use crate::audit::{self, AuditSource};
+use crate::auth::{CurrentAdmin, Permission};
+use crate::db::queries;
+use crate::errors::ApiError;
+use crate::media::{self, ProcessedImage};
+use crate::models::college_image::{CollegeImageUpdate, MediaQuery};
+use crate::models::{CollegeImage, CollegeImageWithRenditions};
+use axum::{
+    extract::{Multipart, Path, Query},
+    http::{header, StatusCode},
+    response::IntoResponse,
+    Extension, Json,
+};
+use sqlx::{PgPool, Postgres, Transaction};
+use std::path::PathBuf;
+
+/// URL prefix under which uploaded files are served.
+pub const UPLOADS_URL_PREFIX: &str = "/uploads";
+
+/// Subdirectory of the upload directory holding images uploaded before renditions
+/// existed; the only part of it served as static files. Attachments and renditions
+/// are served through handlers that check they are live.
+pub const LEGACY_IMAGE_DIR: &str = "colleges";
+
+/// URL prefix under which processed images are served.
+pub const MEDIA_URL_PREFIX: &str = "/media";
+
+/// Largest accepted image upload, in bytes.
+pub const MAX_IMAGE_BYTES: usize = 10 * 1024 * 1024;
+
+/// Directory uploaded files are stored in, from `UPLOAD_DIR` (default `uploads`).
+pub fn upload_dir() -> PathBuf {
+    std::env::var("UPLOAD_DIR")
+        .unwrap_or_else(|_| "uploads".to_string())
+        .into()
+}
+
+// Local path of a file uploaded before renditions existed; None for external URLs
+pub(crate) fn local_path(image_url: &str) -> Option<PathBuf> {
+    let relative = image_url
+        .strip_prefix(UPLOADS_URL_PREFIX)?
+        .trim_start_matches('/');
+    if relative.split('/').any(|part| part == "..") {
+        return None;
+    }
+    Some(upload_dir().join(relative))
+}
+
+// Lock the college row so concurrent primary switches for it are serialized; deleted
+// colleges are not found
+pub(crate) async fn lock_college(
+    tx: &mut Transaction<'_, Postgres>,
+    college_id: i32,
+) -> Result<(), ApiError> {
+    sqlx::query_scalar::<_, i32>(
+        "SELECT id FROM colleges WHERE id = $1 AND deleted_at IS NULL FOR UPDATE",
+    )
+    .bind(college_id)
+    .fetch_optional(&mut **tx)
+    .await?
+    .ok_or_else(|| ApiError::NotFound("College not found".to_string()))?;
+    Ok(())
+}
+
+/// List the image gallery of a college, primary image first, with each image's renditions.
+pub async fn list_college_images(
+    Path(college_id): Path<i32>,
+    Extension(pool): Extension<PgPool>,
+) -> Result<Json<Vec<CollegeImageWithRenditions>>, ApiError> {
+    queries::fetch_college_by_id(&pool, college_id).await?;
+    let images = queries::fetch_images_by_college(&pool, college_id).await?;
+    let ids: Vec<i32> = images.iter().map(|image| image.id).collect();
+    let mut renditions = queries::fetch_renditions_by_images(&pool, &ids).await?;
+
+    let gallery = images
+        .into_iter()
+        .map(|image| {
+            let (own, rest) = renditions
+                .drain(..)
+                .partition(|rendition| rendition.image_id == image.id);
+            renditions = rest;
+            CollegeImageWithRenditions {
+                image,
+                renditions: own,
+            }
+        })
+        .collect();
+
+    Ok(Json(gallery))
+}
+
+/// Serve the WebP rendition of an image closest to the requested width `w`
+/// (the largest rendition when `w` is omitted).
+pub async fn serve_media(
+    Path(id): Path<i32>,
+    Query(query): Query<MediaQuery>,
+    Extension(pool): Extension<PgPool>,
+) -> Result<impl IntoResponse, ApiError> {
+    let renditions = queries::fetch_renditions_by_images(&pool, &[id]).await?;
+    let rendition = media::nearest_rendition(&renditions, |r| r.width, query.w)
+        .ok_or_else(|| ApiError::NotFound("Image not found".to_string()))?;
+
+    let data = tokio::fs::read(upload_dir().join(&rendition.file_path))
+        .await
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to read image: {}", e)))?;
+
+    Ok((
+        [
+            (header::CONTENT_TYPE, "image/webp"),
+            // Renditions of an image id never change
+            (header::CACHE_CONTROL, "public, max-age=31536000, immutable"),
+        ],
+        data,
+    ))
+}
+
+// Admin: upload an image for a college (multipart fields: file, alt_text, is_primary).
+// The image is validated and stored as WebP renditions without metadata; re-uploading
+// identical bytes for the same college returns the existing image. The first image of
+// a college always becomes its primary image. Images uploaded by a college
+// representative stay unpublished until a reviewer approves the submission created
+// for them.
+pub async fn upload_college_image(
+    Path(college_id): Path<i32>,
+    Extension(pool): Extension<PgPool>,
+    admin: CurrentAdmin,
+    mut multipart: Multipart,
+) -> Result<(StatusCode, Json<CollegeImage>), ApiError> {
+    let for_review = !admin.role.allows(Permission::Upload);
+    if for_review {
+        admin.require_college(college_id)?;
+    }
+
+    let mut file = None;
+    let mut alt_text = None;
+    let mut is_primary = false;
+
+    while let Some(field) = multipart
+        .next_field()
+        .await
+        .map_err(|e| ApiError::BadRequest(format!("Invalid file upload: {}", e)))?
+    {
+        match field.name() {
+            Some("file") => {
+                let data = field
+                    .bytes()
+                    .await
+                    .map_err(|e| ApiError::BadRequest(format!("Failed to read file: {}", e)))?;
+                file = Some(data);
+            }
+            Some("alt_text") => {
+                let text = field
+                    .text()
+                    .await
+                    .map_err(|e| ApiError::BadRequest(format!("Invalid alt_text: {}", e)))?;
+                alt_text = Some(text.trim().to_string()).filter(|text| !text.is_empty());
+            }
+            Some("is_primary") => {
+                let text = field
+                    .text()
+                    .await
+                    .map_err(|e| ApiError::BadRequest(format!("Invalid is_primary: {}", e)))?;
+                is_primary = text.trim().parse().map_err(|_| {
+                    ApiError::BadRequest("is_primary must be true or false".to_string())
+                })?;
+            }
+            _ => {}
+        }
+    }
+
+    let data =
+        file.ok_or_else(|| ApiError::BadRequest("Missing multipart field 'file'".to_string()))?;
+    if data.is_empty() {
+        return Err(ApiError::BadRequest("Uploaded image is empty".to_string()));
+    }
+    CollegeImageUpdate {
+        alt_text: alt_text.clone(),
+        is_primary: None,
+    }
+    .validate()
+    .map_err(ApiError::BadRequest)?;
+
+    queries::fetch_college_by_id(&pool, college_id).await?;
+
+    let content_hash = media::content_hash(&data);
+    if let Some(existing) = find_by_hash(&pool, college_id, &content_hash).await? {
+        return Ok((StatusCode::OK, Json(existing)));
+    }
+
+    let processed = tokio::task::spawn_blocking(move || media::process_image(&data))
+        .await
+        .map_err(|e| ApiError::InternalServerError(format!("Image processing failed: {}", e)))?
+        .map_err(ApiError::BadRequest)?;
+
+    // Renditions are content-addressed, so identical uploads for different
+    // colleges share the same files
+    let files: Vec<String> = processed
+        .renditions
+        .iter()
+        .map(|rendition| format!("media/{}/{}.webp", content_hash, rendition.variant))
+        .collect();
+
+    let result = insert_image(
+        &pool,
+        college_id,
+        &content_hash,
+        &processed,
+        &files,
+        ImageUpload {
+            alt_text,
+            is_primary,
+            uploader: &admin,
+            for_review,
+        },
+    )
+    .await;
+    match result {
+        Ok((status, image)) => Ok((status, Json(image))),
+        Err(e) => {
+            remove_unreferenced_files(&pool, files).await;
+            Err(e)
+        }
+    }
+}
+
+async fn find_by_hash<'e, E>(
+    executor: E,
+    college_id: i32,
+    content_hash: &str,
+) -> Result<Option<CollegeImage>, sqlx::Error>
+where
+    E: sqlx::PgExecutor<'e>,
+{
+    sqlx::query_as::<_, CollegeImage>(
+        "SELECT * FROM college_images WHERE college_id = $1 AND content_hash = $2",
+    )
+    .bind(college_id)
+    .bind(content_hash)
+    .fetch_optional(executor)
+    .await
+}
+
+// Form fields of an upload, and whether it awaits review as a representative's submission
+struct ImageUpload<'a> {
+    alt_text: Option<String>,
+    is_primary: bool,
+    uploader: &'a CurrentAdmin,
+    for_review: bool,
+}
+
+async fn insert_image(
+    pool: &PgPool,
+    college_id: i32,
+    content_hash: &str,
+    processed: &ProcessedImage,
+    files: &[String],
+    upload: ImageUpload<'_>,
+) -> Result<(StatusCode, CollegeImage), ApiError> {
+    let published = !upload.for_review;
+    let mut tx = pool.begin().await?;
+    audit::set_context(&mut tx, upload.uploader, AuditSource::ImageUpload).await?;
+    lock_college(&mut tx, college_id).await?;
+
+    // A concurrent upload of the same bytes may have won the race
+    if let Some(existing) = find_by_hash(&mut *tx, college_id, content_hash).await? {
+        return Ok((StatusCode::OK, existing));
+    }
+
+    // The files are written while holding the lock that removing unreferenced files
+    // takes, so they cannot be removed before this transaction references them
+    lock_media_dir(&mut tx, &format!("media/{}", content_hash)).await?;
+    for (rendition, file) in processed.renditions.iter().zip(files) {
+        let path = upload_dir().join(file);
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent).await.map_err(|e| {
+                ApiError::InternalServerError(format!("Failed to store image: {}", e))
+            })?;
+        }
+        tokio::fs::write(&path, &rendition.data)
+            .await
+            .map_err(|e| ApiError::InternalServerError(format!("Failed to store image: {}", e)))?;
+    }
+
+    let has_primary: bool = sqlx::query_scalar(
+        "SELECT EXISTS (SELECT 1 FROM college_images WHERE college_id = $1 AND is_primary)",
+    )
+    .bind(college_id)
+    .fetch_one(&mut *tx)
+    .await?;
+    // Unpublished images can only become primary once approved
+    let is_primary = published && (upload.is_primary || !has_primary);
+
+    if is_primary {
+        sqlx::query("UPDATE college_images SET is_primary = FALSE WHERE college_id = $1")
+            .bind(college_id)
+            .execute(&mut *tx)
+            .await?;
+    }
+
+    // Reserve the id first so the row is written once with its final URL
+    let image_id: i32 = sqlx::query_scalar(
+        "SELECT nextval(pg_get_serial_sequence('college_images', 'id'))::INTEGER",
+    )
+    .fetch_one(&mut *tx)
+    .await?;
+    let image = sqlx::query_as::<_, CollegeImage>(
+        r#"
+        INSERT INTO college_images
+            (id, college_id, image_url, alt_text, is_primary, content_hash, width, height, published)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+        RETURNING *
+        "#,
+    )
+    .bind(image_id)
+    .bind(college_id)
+    .bind(format!("{}/{}", MEDIA_URL_PREFIX, image_id))
+    .bind(upload.alt_text)
+    .bind(is_primary)
+    .bind(content_hash)
+    .bind(processed.width as i32)
+    .bind(processed.height as i32)
+    .bind(published)
+    .fetch_one(&mut *tx)
+    .await?;
+
+    for (rendition, file_path) in processed.renditions.iter().zip(files) {
+        sqlx::query(
+            r#"
+            INSERT INTO image_renditions (image_id, variant, width, height, byte_size, file_path)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            "#,
+        )
+        .bind(image_id)
+        .bind(rendition.variant)
+        .bind(rendition.width as i32)
+        .bind(rendition.height as i32)
+        .bind(rendition.data.len() as i32)
+        .bind(file_path)
+        .execute(&mut *tx)
+        .await?;
+    }
+
+    if upload.for_review {
+        sqlx::query(
+            r#"
+            INSERT INTO college_submissions (college_id, submitted_by, kind, changes)
+            VALUES ($1, $2, 'image', jsonb_build_object('image_id', $3::INTEGER))
+            "#,
+        )
+        .bind(college_id)
+        .bind(upload.uploader.id)
+        .bind(image_id)
+        .execute(&mut *tx)
+        .await?;
+    }
+
+    tx.commit().await?;
+    Ok((StatusCode::CREATED, image))
+}
+
+// Serialize writing, referencing and removing the rendition files under one media
+// directory; the lock is held until the transaction ends
+async fn lock_media_dir(tx: &mut Transaction<'_, Postgres>, dir: &str) -> Result<(), sqlx::Error> {
+    sqlx::query("SELECT pg_advisory_xact_lock(hashtextextended($1, 0))")
+        .bind(dir)
+        .execute(&mut **tx)
+        .await?;
+    Ok(())
+}
+
+// Remove stored rendition files that no image references any more
+pub(crate) async fn remove_unreferenced_files(pool: &PgPool, files: Vec<String>) {
+    if let Err(e) = remove_unreferenced(pool, &files).await {
+        tracing::warn!("Failed to check image file references: {}", e);
+    }
+}
+
+// Checks the references and removes the files under the locks of their directories,
+// so an upload of the same content cannot reference a file in between
+async fn remove_unreferenced(pool: &PgPool, files: &[String]) -> Result<(), sqlx::Error> {
+    let mut tx = pool.begin().await?;
+    let mut dirs: Vec<&str> = files
+        .iter()
+        .filter_map(|file| file.rsplit_once('/'))
+        .map(|(dir, _)| dir)
+        .collect();
+    dirs.sort();
+    dirs.dedup();
+    for dir in dirs {
+        lock_media_dir(&mut tx, dir).await?;
+    }
+
+    let referenced: Vec<String> = sqlx::query_scalar(
+        "SELECT DISTINCT file_path FROM image_renditions WHERE file_path = ANY($1)",
+    )
+    .bind(files)
+    .fetch_all(&mut *tx)
+    .await?;
+
+    // A failed upload may not have written its files yet
+    for file in files.iter().filter(|file| !referenced.contains(file)) {
+        let path = upload_dir().join(file);
+        match tokio::fs::remove_file(&path).await {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => {
+                tracing::warn!("Failed to remove image file {}: {}", path.display(), e);
+            }
+            _ => {}
+        }
+    }
+
+    tx.commit().await
+}
+
+// Admin: edit an image's alt text and/or make it the college's primary image
+pub async fn update_college_image(
+    Path(id): Path<i32>,
+    Extension(pool): Extension<PgPool>,
+    admin: CurrentAdmin,
+    Json(input): Json<CollegeImageUpdate>,
+) -> Result<Json<CollegeImage>, ApiError> {
+    admin.require(Permission::Edit)?;
+
+    input.validate().map_err(ApiError::BadRequest)?;
+
+    let (college_id, published): (i32, bool) =
+        sqlx::query_as("SELECT college_id, published FROM college_images WHERE id = $1")
+            .bind(id)
+            .fetch_optional(&pool)
+            .await?
+            .ok_or_else(|| ApiError::NotFound("Image not found".to_string()))?;
+    if input.is_primary == Some(true) && !published {
+        return Err(ApiError::BadRequest(
+            "An image awaiting review cannot be the primary image".to_string(),
+        ));
+    }
+
+    let mut tx = pool.begin().await?;
+    audit::set_context(&mut tx, &admin, AuditSource::ManualEdit).await?;
+    lock_college(&mut tx, college_id).await?;
+
+    if input.is_primary == Some(true) {
+        sqlx::query("UPDATE college_images SET is_primary = (id = $2) WHERE college_id = $1")
+            .bind(college_id)
+            .bind(id)
+            .execute(&mut *tx)
+            .await?;
+    }
+
+    let image = sqlx::query_as::<_, CollegeImage>(
+        r#"
+        UPDATE college_images
+        SET alt_text = CASE WHEN $2 THEN NULLIF(TRIM($3), '') ELSE alt_text END
+        WHERE id = $1
+        RETURNING *
+        "#,
+    )
+    .bind(id)
+    .bind(input.alt_text.is_some())
+    .bind(&input.alt_text)
+    .fetch_one(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+    Ok(Json(image))
+}
+
+// Admin: delete an image and its stored files; if it was the primary image, the
+// oldest remaining published image of the college is promoted
+pub async fn delete_college_image(
+    Path(id): Path<i32>,
+    Extension(pool): Extension<PgPool>,
+    admin: CurrentAdmin,
+) -> Result<StatusCode, ApiError> {
+    admin.require(Permission::Delete)?;
+
+    delete_image(&pool, id, &admin, AuditSource::ManualEdit).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+pub(crate) async fn delete_image(
+    pool: &PgPool,
+    id: i32,
+    actor: &CurrentAdmin,
+    source: AuditSource,
+) -> Result<(), ApiError> {
+    let college_id: i32 = sqlx::query_scalar("SELECT college_id FROM college_images WHERE id = $1")
+        .bind(id)
+        .fetch_optional(pool)
+        .await?
+        .ok_or_else(|| ApiError::NotFound("Image not found".to_string()))?;
+
+    let mut tx = pool.begin().await?;
+    audit::set_context(&mut tx, actor, source).await?;
+    lock_college(&mut tx, college_id).await?;
+
+    let files: Vec<String> =
+        sqlx::query_scalar("SELECT file_path FROM image_renditions WHERE image_id = $1")
+            .bind(id)
+            .fetch_all(&mut *tx)
+            .await?;
+
+    let (image_url, was_primary): (String, bool) =
+        sqlx::query_as("DELETE FROM college_images WHERE id = $1 RETURNING image_url, is_primary")
+            .bind(id)
+            .fetch_optional(&mut *tx)
+            .await?
+            .ok_or_else(|| ApiError::NotFound("Image not found".to_string()))?;
+
+    if was_primary {
+        sqlx::query(
+            r#"
+            UPDATE college_images SET is_primary = TRUE
+            WHERE id = (
+                SELECT MIN(id) FROM college_images WHERE college_id = $1 AND published
+            )
+            "#,
+        )
+        .bind(college_id)
+        .execute(&mut *tx)
+        .await?;
+    }
+
+    tx.commit().await?;
+
+    remove_unreferenced_files(pool, files).await;
+    if let Some(path) = local_path(&image_url) {
+        if let Err(e) = tokio::fs::remove_file(&path).await {
+            tracing::warn!("Failed to remove image file {}: {}", path.display(), e);
+        }
+    }
+
+    Ok(())
+}
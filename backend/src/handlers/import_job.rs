@@ -0,0 +1,722 @@
+use crate::audit::AuditSource;
+use crate::auth::{CurrentAdmin, Permission};
+use crate::csv_stream::CsvStream;
+use crate::errors::ApiError;
+use crate::handlers::admin::{
+    next_upload_chunk, BatchImport, BulkCollegeData, CollegeImport, ImportWriter, SheetOptions,
+    UploadResponse, IMPORT_BATCH_SIZE,
+};
+use crate::models::cutoff::CutoffImport;
+use crate::models::import_job::{ImportJob, ImportJobDetail, JobFilters};
+use crate::spreadsheet::Sheet;
+use axum::{
+    body::Bytes,
+    extract::{Multipart, Path, Query},
+    response::sse::{Event, KeepAlive, Sse},
+    Extension, Json,
+};
+use futures_util::stream::{self, Stream};
+use serde::{de::IgnoredAny, Deserialize};
+use sqlx::{PgPool, QueryBuilder};
+use std::convert::Infallible;
+use std::path::PathBuf;
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::sync::Semaphore;
+
+// Imports run one at a time, in the order they were queued
+static IMPORT_SLOTS: Semaphore = Semaphore::const_new(1);
+
+// How often an event stream looks for progress
+const EVENT_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+// Size of the chunks a stored CSV is read back in
+const READ_CHUNK_BYTES: usize = 64 * 1024;
+
+// Jobs listed by `/api/admin/jobs`, newest first
+const LIST_LIMIT: i64 = 50;
+
+/// Directory uploads are kept in while their import job runs, from `IMPORT_DIR`
+/// (default `imports`). Unlike `UPLOAD_DIR`, nothing in it is ever served.
+pub fn import_dir() -> PathBuf {
+    std::env::var("IMPORT_DIR")
+        .unwrap_or_else(|_| "imports".to_string())
+        .into()
+}
+
+// Where a job's upload is kept until the job ends
+fn job_file(id: i32, kind: &str) -> PathBuf {
+    let extension = match kind {
+        "colleges_json" => "json",
+        "colleges_spreadsheet" | "cutoffs_spreadsheet" => "workbook",
+        _ => "csv",
+    };
+    import_dir().join(format!("{}.{}", id, extension))
+}
+
+fn storage_error(e: impl std::fmt::Display) -> ApiError {
+    ApiError::InternalServerError(format!("Failed to store upload: {}", e))
+}
+
+// Store an uploaded CSV and queue a job of `kind` importing it. Rows are counted as
+// the file is stored, so the job's total is known before it starts.
+pub(crate) async fn start_csv_job(
+    pool: &PgPool,
+    admin: &CurrentAdmin,
+    kind: &str,
+    atomic: bool,
+    multipart: &mut Multipart,
+    max_bytes: u64,
+) -> Result<ImportJob, ApiError> {
+    let job = create_job(pool, admin, kind, atomic, None).await?;
+    let path = job_file(job.id, &job.kind);
+    match store_csv(&path, multipart, max_bytes).await {
+        Ok(total_rows) => queue_job(pool, job.id, total_rows).await,
+        Err(e) => {
+            discard_job(pool, job.id, &path).await;
+            Err(e)
+        }
+    }
+}
+
+// Store the colleges of a JSON upload and queue a job importing them
+pub(crate) async fn start_json_job(
+    pool: &PgPool,
+    admin: &CurrentAdmin,
+    atomic: bool,
+    data: &BulkCollegeData,
+) -> Result<ImportJob, ApiError> {
+    let job = create_job(pool, admin, "colleges_json", atomic, None).await?;
+    let path = job_file(job.id, &job.kind);
+    let stored = match serde_json::to_vec(data) {
+        Ok(contents) => store_file(&path, &contents).await,
+        Err(e) => Err(storage_error(e)),
+    };
+    match stored {
+        Ok(()) => queue_job(pool, job.id, data.colleges.len()).await,
+        Err(e) => {
+            discard_job(pool, job.id, &path).await;
+            Err(e)
+        }
+    }
+}
+
+// Store an uploaded workbook and queue a job of `kind` importing the sheet read from it
+pub(crate) async fn start_spreadsheet_job(
+    pool: &PgPool,
+    admin: &CurrentAdmin,
+    kind: &str,
+    atomic: bool,
+    data: &[u8],
+    sheet: &Sheet,
+) -> Result<ImportJob, ApiError> {
+    let job = create_job(pool, admin, kind, atomic, Some(sheet)).await?;
+    let path = job_file(job.id, &job.kind);
+    match store_file(&path, data).await {
+        Ok(()) => queue_job(pool, job.id, sheet.len()).await,
+        Err(e) => {
+            discard_job(pool, job.id, &path).await;
+            Err(e)
+        }
+    }
+}
+
+/// Restart the imports a previous run of the server left queued or running.
+pub async fn resume_import_jobs(pool: &PgPool) -> Result<(), sqlx::Error> {
+    let ids: Vec<i32> = sqlx::query_scalar(
+        "SELECT id FROM import_jobs WHERE status IN ('queued', 'running') ORDER BY id",
+    )
+    .fetch_all(pool)
+    .await?;
+    for id in ids {
+        tracing::info!("Resuming import job {}", id);
+        tokio::spawn(run_job(pool.clone(), id));
+    }
+    Ok(())
+}
+
+async fn create_job(
+    pool: &PgPool,
+    admin: &CurrentAdmin,
+    kind: &str,
+    atomic: bool,
+    sheet: Option<&Sheet>,
+) -> Result<ImportJob, ApiError> {
+    let job = sqlx::query_as::<_, ImportJob>(
+        r#"
+        INSERT INTO import_jobs (kind, atomic, sheet, header_row, created_by)
+        VALUES ($1, $2, $3, $4, $5)
+        RETURNING *
+        "#,
+    )
+    .bind(kind)
+    .bind(atomic)
+    .bind(sheet.map(|sheet| sheet.name.clone()))
+    .bind(sheet.map(|sheet| sheet.header_row as i32))
+    .bind(admin.id)
+    .fetch_one(pool)
+    .await?;
+    Ok(job)
+}
+
+// Write the `file` field to `path`, returning how many rows it holds
+async fn store_csv(
+    path: &std::path::Path,
+    multipart: &mut Multipart,
+    max_bytes: u64,
+) -> Result<usize, ApiError> {
+    while let Some(mut field) = multipart
+        .next_field()
+        .await
+        .map_err(|e| ApiError::BadRequest(format!("Invalid file upload: {}", e)))?
+    {
+        if field.name() != Some("file") {
+            continue;
+        }
+
+        let mut file = create_file(path).await?;
+        let mut stream = CsvStream::new();
+        let mut received: u64 = 0;
+        let mut rows = 0;
+        while let Some(chunk) = next_upload_chunk(&mut field, &mut received, max_bytes).await? {
+            rows += stream.feed::<IgnoredAny>(&chunk).len();
+            file.write_all(&chunk).await.map_err(storage_error)?;
+        }
+        rows += stream.finish::<IgnoredAny>().len();
+        file.flush().await.map_err(storage_error)?;
+        return Ok(rows);
+    }
+    Err(ApiError::BadRequest(
+        "Missing multipart field 'file'".to_string(),
+    ))
+}
+
+async fn store_file(path: &std::path::Path, contents: &[u8]) -> Result<(), ApiError> {
+    let mut file = create_file(path).await?;
+    file.write_all(contents).await.map_err(storage_error)?;
+    file.flush().await.map_err(storage_error)
+}
+
+async fn create_file(path: &std::path::Path) -> Result<tokio::fs::File, ApiError> {
+    if let Some(parent) = path.parent() {
+        tokio::fs::create_dir_all(parent)
+            .await
+            .map_err(storage_error)?;
+    }
+    tokio::fs::File::create(path).await.map_err(storage_error)
+}
+
+// Record the job's size and hand it to a background task
+async fn queue_job(pool: &PgPool, id: i32, total_rows: usize) -> Result<ImportJob, ApiError> {
+    let job = sqlx::query_as::<_, ImportJob>(
+        "UPDATE import_jobs SET total_rows = $2 WHERE id = $1 RETURNING *",
+    )
+    .bind(id)
+    .bind(total_rows as i32)
+    .fetch_one(pool)
+    .await?;
+    tokio::spawn(run_job(pool.clone(), id));
+    Ok(job)
+}
+
+// Forget a job whose upload could not be stored
+async fn discard_job(pool: &PgPool, id: i32, path: &std::path::Path) {
+    remove_job_file(path).await;
+    if let Err(e) = sqlx::query("DELETE FROM import_jobs WHERE id = $1")
+        .bind(id)
+        .execute(pool)
+        .await
+    {
+        tracing::warn!("Failed to remove import job {}: {}", id, e);
+    }
+}
+
+async fn remove_job_file(path: &std::path::Path) {
+    if let Err(e) = tokio::fs::remove_file(path).await {
+        if e.kind() != std::io::ErrorKind::NotFound {
+            tracing::warn!("Failed to remove import file {}: {}", path.display(), e);
+        }
+    }
+}
+
+// How a run of a job ended
+enum Outcome {
+    Completed(UploadResponse),
+    // Stopped at an admin's request, after this many rows
+    Cancelled(usize),
+}
+
+// Run a job to its end and record how it ended. The stored upload is removed once
+// the job is over.
+async fn run_job(pool: PgPool, id: i32) {
+    let Ok(_slot) = IMPORT_SLOTS.acquire().await else {
+        return;
+    };
+    let job = match claim_job(&pool, id).await {
+        Ok(Some(job)) => job,
+        // Cancelled while it was queued
+        Ok(None) => return,
+        Err(e) => {
+            tracing::error!("Failed to start import job {}: {}", id, e);
+            return;
+        }
+    };
+
+    let path = job_file(job.id, &job.kind);
+    let outcome = match job.kind.as_str() {
+        "cutoffs_csv" | "cutoffs_spreadsheet" => import::<CutoffImport>(&pool, &job, &path).await,
+        _ => import::<CollegeImport>(&pool, &job, &path).await,
+    };
+    if let Err(e) = record_outcome(&pool, &job, outcome).await {
+        tracing::error!("Failed to record the outcome of import job {}: {}", id, e);
+    }
+    remove_job_file(&path).await;
+}
+
+// Mark a queued job, or one interrupted by a restart, as running. An interrupted
+// atomic import left nothing behind, so it starts over.
+async fn claim_job(pool: &PgPool, id: i32) -> Result<Option<ImportJob>, sqlx::Error> {
+    let mut tx = pool.begin().await?;
+    let job = sqlx::query_as::<_, ImportJob>(
+        r#"
+        UPDATE import_jobs SET
+            status = 'running',
+            started_at = COALESCE(started_at, CURRENT_TIMESTAMP),
+            processed_rows = CASE WHEN atomic THEN 0 ELSE processed_rows END,
+            inserted = CASE WHEN atomic THEN 0 ELSE inserted END,
+            updated = CASE WHEN atomic THEN 0 ELSE updated END,
+            error_count = CASE WHEN atomic THEN 0 ELSE error_count END
+        WHERE id = $1 AND status IN ('queued', 'running')
+        RETURNING *
+        "#,
+    )
+    .bind(id)
+    .fetch_optional(&mut *tx)
+    .await?;
+    if job.as_ref().is_some_and(|job| job.atomic) {
+        sqlx::query("DELETE FROM import_job_errors WHERE job_id = $1")
+            .bind(id)
+            .execute(&mut *tx)
+            .await?;
+    }
+    tx.commit().await?;
+    Ok(job)
+}
+
+// Import the job's stored upload as the admin who uploaded it
+async fn import<T: BatchImport>(
+    pool: &PgPool,
+    job: &ImportJob,
+    path: &std::path::Path,
+) -> Result<Outcome, ApiError> {
+    if job.cancel_requested {
+        return Ok(Outcome::Cancelled(job.processed_rows as usize));
+    }
+
+    let admin = match job.created_by {
+        Some(id) => CurrentAdmin::load(pool, id).await?,
+        None => None,
+    }
+    .ok_or_else(|| {
+        ApiError::Forbidden("The admin who uploaded this file is no longer active".to_string())
+    })?;
+    admin.require(Permission::Upload)?;
+
+    // A workbook is read up front, as its rows are referred to by sheet name
+    let sheet = match job.kind.as_str() {
+        "colleges_spreadsheet" | "cutoffs_spreadsheet" => Some(read_stored_sheet(job, path).await?),
+        _ => None,
+    };
+    let (source, label) = match (&sheet, job.kind.as_str()) {
+        (Some(sheet), _) => (AuditSource::SpreadsheetUpload, sheet.label()),
+        (None, "colleges_json") => (AuditSource::JsonUpload, "College #".to_string()),
+        _ => (AuditSource::CsvUpload, "Line ".to_string()),
+    };
+    let message = match job.kind.as_str() {
+        "colleges_json" => "Upload completed",
+        "colleges_spreadsheet" => "Spreadsheet upload completed",
+        "cutoffs_csv" => "Cutoff CSV upload completed",
+        "cutoffs_spreadsheet" => "Cutoff spreadsheet upload completed",
+        _ => "CSV upload completed",
+    };
+    let writer = ImportWriter::<T>::begin(pool, &admin, source, label, job.atomic).await?;
+    let mut run = JobRun {
+        pool,
+        job,
+        writer,
+        seen: 0,
+        saved_rows: 0,
+        saved_errors: 0,
+    };
+
+    let completed = match (&sheet, job.kind.as_str()) {
+        (Some(sheet), _) => run.import_sheet(sheet).await?,
+        (None, "colleges_json") => run.import_json(path).await?,
+        _ => run.import_csv(path).await?,
+    };
+    if !completed {
+        // Dropping the writer rolls back an atomic import
+        return Ok(Outcome::Cancelled(run.processed()));
+    }
+
+    run.writer.flush().await?;
+    run.checkpoint().await?;
+    Ok(Outcome::Completed(run.writer.finish(message).await?))
+}
+
+// Read the sheet a spreadsheet job was queued with from its stored workbook
+async fn read_stored_sheet(job: &ImportJob, path: &std::path::Path) -> Result<Sheet, ApiError> {
+    let contents = tokio::fs::read(path)
+        .await
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to read upload: {}", e)))?;
+    let options = SheetOptions {
+        sheet: job.sheet.clone(),
+        header_row: job.header_row.map(|row| row as usize),
+    };
+    options.read(Bytes::from(contents)).await
+}
+
+// The rows of a stored JSON upload, as sent in its "colleges" field
+#[derive(Deserialize)]
+struct StoredJson<T> {
+    colleges: Vec<T>,
+}
+
+// Feeds a job's rows to the writer, passing over those an interrupted run already
+// handled, and saves the job's progress after every batch. A batch written just before
+// a restart is written again, so its new rows then count as updated.
+struct JobRun<'a, T: BatchImport> {
+    pool: &'a PgPool,
+    job: &'a ImportJob,
+    writer: ImportWriter<'a, T>,
+    // Rows read so far, including those passed over
+    seen: usize,
+    // Writer rows and errors as of the last checkpoint
+    saved_rows: usize,
+    saved_errors: usize,
+}
+
+impl<T: BatchImport> JobRun<'_, T> {
+    // Rows handled by this and earlier runs, as of the last checkpoint
+    fn processed(&self) -> usize {
+        self.job.processed_rows as usize + self.saved_rows
+    }
+
+    // Returns false once the job has been cancelled
+    async fn import_csv(&mut self, path: &std::path::Path) -> Result<bool, ApiError> {
+        let mut file = tokio::fs::File::open(path)
+            .await
+            .map_err(|e| ApiError::InternalServerError(format!("Failed to read upload: {}", e)))?;
+        let mut stream = CsvStream::new();
+        let mut buffer = vec![0; READ_CHUNK_BYTES];
+        loop {
+            let read = file.read(&mut buffer).await.map_err(|e| {
+                ApiError::InternalServerError(format!("Failed to read upload: {}", e))
+            })?;
+            let rows = match read {
+                0 => stream.finish::<T>(),
+                _ => stream.feed::<T>(&buffer[..read]),
+            };
+            for (line, row) in rows {
+                let row = row.map_err(|e| format!("CSV parsing error: {}", e));
+                if !self.row(line, row).await? {
+                    return Ok(false);
+                }
+            }
+            if read == 0 {
+                return Ok(true);
+            }
+        }
+    }
+
+    // Returns false once the job has been cancelled
+    async fn import_json(&mut self, path: &std::path::Path) -> Result<bool, ApiError> {
+        let contents = tokio::fs::read(path)
+            .await
+            .map_err(|e| ApiError::InternalServerError(format!("Failed to read upload: {}", e)))?;
+        let data: StoredJson<T> = serde_json::from_slice(&contents)
+            .map_err(|e| ApiError::InternalServerError(format!("Failed to read upload: {}", e)))?;
+        for (index, record) in data.colleges.into_iter().enumerate() {
+            if !self.row(index + 1, Ok(record)).await? {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+
+    // Returns false once the job has been cancelled
+    async fn import_sheet(&mut self, sheet: &Sheet) -> Result<bool, ApiError> {
+        for (row, record) in sheet.rows::<T>() {
+            if !self.row(row, record).await? {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+
+    // Import one row, saving progress once a batch has been written. Returns false
+    // if the job has been cancelled.
+    async fn row(&mut self, row: usize, record: Result<T, String>) -> Result<bool, ApiError> {
+        self.seen += 1;
+        if self.seen <= self.job.processed_rows as usize {
+            return Ok(true);
+        }
+        match record {
+            Ok(record) => self.writer.write(row, record).await?,
+            Err(e) => self.writer.reject(row, e),
+        }
+
+        let (rows, ..) = self.writer.progress();
+        if self.writer.is_idle() && rows - self.saved_rows >= IMPORT_BATCH_SIZE {
+            return self.checkpoint().await;
+        }
+        Ok(true)
+    }
+
+    // Save the rows handled and the errors found since the last checkpoint. Returns
+    // false if the job has been cancelled meanwhile.
+    async fn checkpoint(&mut self) -> Result<bool, ApiError> {
+        let (rows, inserted, updated, failed) = self.writer.progress();
+        let (row_numbers, messages): (Vec<i32>, Vec<String>) = self
+            .writer
+            .errors_since(self.saved_errors)
+            .into_iter()
+            .map(|(row, e)| (row as i32, e))
+            .unzip();
+
+        let mut tx = self.pool.begin().await?;
+        if !messages.is_empty() {
+            sqlx::query(
+                "INSERT INTO import_job_errors (job_id, row_number, message) \
+                 SELECT $1, * FROM UNNEST($2::int[], $3::text[])",
+            )
+            .bind(self.job.id)
+            .bind(&row_numbers)
+            .bind(&messages)
+            .execute(&mut *tx)
+            .await?;
+        }
+        let cancel_requested: bool = sqlx::query_scalar(
+            r#"
+            UPDATE import_jobs
+            SET processed_rows = $2, inserted = $3, updated = $4, error_count = $5
+            WHERE id = $1
+            RETURNING cancel_requested
+            "#,
+        )
+        .bind(self.job.id)
+        .bind((self.job.processed_rows as usize + rows) as i32)
+        .bind(self.job.inserted + inserted as i32)
+        .bind(self.job.updated + updated as i32)
+        .bind(self.job.error_count + failed as i32)
+        .fetch_one(&mut *tx)
+        .await?;
+        tx.commit().await?;
+
+        self.saved_rows = rows;
+        self.saved_errors = failed;
+        Ok(!cancel_requested)
+    }
+}
+
+// Record the final status, counts and message of a run
+async fn record_outcome(
+    pool: &PgPool,
+    job: &ImportJob,
+    outcome: Result<Outcome, ApiError>,
+) -> Result<(), sqlx::Error> {
+    let mut query_builder =
+        QueryBuilder::new("UPDATE import_jobs SET finished_at = CURRENT_TIMESTAMP");
+    let (status, message) = match outcome {
+        Ok(Outcome::Completed(response)) => {
+            query_builder.push(", inserted = ");
+            query_builder.push_bind(job.inserted + response.inserted as i32);
+            query_builder.push(", updated = ");
+            query_builder.push_bind(job.updated + response.updated as i32);
+            query_builder.push(", error_count = ");
+            query_builder.push_bind(job.error_count + response.errors.len() as i32);
+            ("completed", response.message)
+        }
+        Ok(Outcome::Cancelled(processed)) => {
+            query_builder.push(", processed_rows = ");
+            query_builder.push_bind(processed as i32);
+            if job.atomic {
+                query_builder.push(", inserted = 0, updated = 0");
+                (
+                    "cancelled",
+                    "Import cancelled, nothing was written".to_string(),
+                )
+            } else {
+                (
+                    "cancelled",
+                    format!(
+                        "Import cancelled after {} row(s); rows before that were imported",
+                        processed
+                    ),
+                )
+            }
+        }
+        Err(e) => {
+            if job.atomic {
+                query_builder.push(", inserted = 0, updated = 0");
+            }
+            ("failed", failure_message(e))
+        }
+    };
+    query_builder.push(", status = ");
+    query_builder.push_bind(status);
+    query_builder.push(", message = ");
+    query_builder.push_bind(message);
+    query_builder.push(" WHERE id = ");
+    query_builder.push_bind(job.id);
+    query_builder.build().execute(pool).await?;
+    Ok(())
+}
+
+// What to tell admins about a job that stopped on an error
+fn failure_message(error: ApiError) -> String {
+    match error {
+        ApiError::DatabaseError(e) => {
+            tracing::error!("Import job failed: {}", e);
+            "Import failed: database error occurred".to_string()
+        }
+        ApiError::NotFound(msg)
+        | ApiError::BadRequest(msg)
+        | ApiError::Unauthorized(msg)
+        | ApiError::Forbidden(msg)
+        | ApiError::Conflict(msg)
+        | ApiError::PayloadTooLarge(msg)
+        | ApiError::InternalServerError(msg) => format!("Import failed: {}", msg),
+    }
+}
+
+async fn fetch_job(pool: &PgPool, id: i32) -> Result<ImportJob, ApiError> {
+    sqlx::query_as::<_, ImportJob>("SELECT * FROM import_jobs WHERE id = $1")
+        .bind(id)
+        .fetch_optional(pool)
+        .await?
+        .ok_or_else(|| ApiError::NotFound("Import job not found".to_string()))
+}
+
+// Admin: the latest import jobs, optionally only those with a given status
+pub async fn list_import_jobs(
+    Query(filters): Query<JobFilters>,
+    Extension(pool): Extension<PgPool>,
+    admin: CurrentAdmin,
+) -> Result<Json<Vec<ImportJob>>, ApiError> {
+    admin.require(Permission::Upload)?;
+    filters.validate().map_err(ApiError::BadRequest)?;
+
+    let mut query_builder = QueryBuilder::new("SELECT * FROM import_jobs WHERE TRUE");
+    if let Some(status) = &filters.status {
+        query_builder.push(" AND status = ");
+        query_builder.push_bind(status);
+    }
+    query_builder.push(" ORDER BY id DESC LIMIT ");
+    query_builder.push_bind(LIST_LIMIT);
+
+    let jobs = query_builder
+        .build_query_as::<ImportJob>()
+        .fetch_all(&pool)
+        .await?;
+    Ok(Json(jobs))
+}
+
+// Admin: an import job's status and counts, with the rows it rejected so far
+pub async fn get_import_job(
+    Path(id): Path<i32>,
+    Extension(pool): Extension<PgPool>,
+    admin: CurrentAdmin,
+) -> Result<Json<ImportJobDetail>, ApiError> {
+    admin.require(Permission::Upload)?;
+
+    let job = fetch_job(&pool, id).await?;
+    let errors = sqlx::query_scalar(
+        "SELECT message FROM import_job_errors WHERE job_id = $1 ORDER BY row_number",
+    )
+    .bind(id)
+    .fetch_all(&pool)
+    .await?;
+    Ok(Json(ImportJobDetail { job, errors }))
+}
+
+// Admin: follow an import job as Server-Sent Events. A `progress` event carries the
+// job whenever it changes and a last `finished` event its outcome, then the stream
+// ends.
+pub async fn import_job_events(
+    Path(id): Path<i32>,
+    Extension(pool): Extension<PgPool>,
+    admin: CurrentAdmin,
+) -> Result<Sse<impl Stream<Item = Result<Event, Infallible>>>, ApiError> {
+    admin.require(Permission::Upload)?;
+    fetch_job(&pool, id).await?;
+
+    // State: the last job sent, and whether the stream is over
+    let events = stream::unfold(
+        (pool, None::<String>, false),
+        move |(pool, last, done)| async move {
+            if done {
+                return None;
+            }
+            loop {
+                let job = match fetch_job(&pool, id).await {
+                    Ok(job) => job,
+                    Err(_) => {
+                        let event = Event::default()
+                            .event("error")
+                            .data("Failed to load import job");
+                        return Some((Ok(event), (pool, last, true)));
+                    }
+                };
+                let data = serde_json::to_string(&job).unwrap_or_default();
+                if last.as_deref() != Some(data.as_str()) {
+                    let finished = job.is_finished();
+                    let event = Event::default()
+                        .event(if finished { "finished" } else { "progress" })
+                        .data(data.clone());
+                    return Some((Ok(event), (pool, Some(data), finished)));
+                }
+                tokio::time::sleep(EVENT_POLL_INTERVAL).await;
+            }
+        },
+    );
+    Ok(Sse::new(events).keep_alive(KeepAlive::default()))
+}
+
+// Admin: cancel an import job. A queued job is cancelled at once; a running one stops
+// at its next batch, keeping the rows written so far unless it is atomic.
+pub async fn cancel_import_job(
+    Path(id): Path<i32>,
+    Extension(pool): Extension<PgPool>,
+    admin: CurrentAdmin,
+) -> Result<Json<ImportJob>, ApiError> {
+    admin.require(Permission::Upload)?;
+
+    let job = sqlx::query_as::<_, ImportJob>(
+        r#"
+        UPDATE import_jobs SET
+            cancel_requested = TRUE,
+            status = CASE WHEN status = 'queued' THEN 'cancelled' ELSE status END,
+            message = CASE WHEN status = 'queued' THEN 'Import cancelled before it started'
+                ELSE message END,
+            finished_at = CASE WHEN status = 'queued' THEN CURRENT_TIMESTAMP
+                ELSE finished_at END
+        WHERE id = $1 AND status IN ('queued', 'running')
+        RETURNING *
+        "#,
+    )
+    .bind(id)
+    .fetch_optional(&pool)
+    .await?;
+
+    let Some(job) = job else {
+        fetch_job(&pool, id).await?;
+        return Err(ApiError::Conflict(
+            "Import job has already finished".to_string(),
+        ));
+    };
+    if job.is_finished() {
+        remove_job_file(&job_file(job.id, &job.kind)).await;
+    }
+    Ok(Json(job))
+}
@@ -1,5 +1,18 @@
+pub mod accreditation;
 pub mod admin;
 pub mod admission;
+pub mod attachment;
+pub mod audit_log;
+pub mod auth;
 pub mod college;
+pub mod concession;
 pub mod cutoff;
 pub mod district;
+pub mod facility;
+pub mod fee;
+pub mod image;
+pub mod import_job;
+pub mod placement;
+pub mod representative;
+pub mod scholarship;
+pub mod university;
@@ -0,0 +1,119 @@
+use crate::auth::{CurrentAdmin, Permission};
+use crate::db::queries;
+use crate::errors::ApiError;
+use crate::handlers::admin::{import_csv, read_csv_file, UploadResponse};
+use crate::models::PlacementStat;
+use axum::{
+    extract::{Multipart, Path},
+    Extension, Json,
+};
+use serde::Deserialize;
+use sqlx::PgPool;
+
+#[derive(Deserialize)]
+pub struct PlacementImport {
+    college_id: i32,
+    year: i32,
+    branch: String,
+    students_eligible: i32,
+    students_placed: i32,
+    median_package: Option<f64>,
+    highest_package: Option<f64>,
+    /// Semicolon-separated recruiter names
+    top_recruiters: Option<String>,
+}
+
+impl PlacementImport {
+    fn validate(&self) -> Result<(), String> {
+        if self.branch.trim().is_empty() {
+            return Err("branch is required".to_string());
+        }
+        if self.students_eligible < 0 || self.students_placed < 0 {
+            return Err("student counts cannot be negative".to_string());
+        }
+        if self.students_placed > self.students_eligible {
+            return Err("students_placed cannot exceed students_eligible".to_string());
+        }
+        if self.median_package.is_some_and(|p| p < 0.0)
+            || self.highest_package.is_some_and(|p| p < 0.0)
+        {
+            return Err("packages cannot be negative".to_string());
+        }
+        Ok(())
+    }
+
+    fn recruiters(&self) -> Vec<String> {
+        self.top_recruiters
+            .as_deref()
+            .unwrap_or_default()
+            .split(';')
+            .map(str::trim)
+            .filter(|r| !r.is_empty())
+            .map(String::from)
+            .collect()
+    }
+}
+
+/// List placement statistics for a college, most recent year first.
+pub async fn get_college_placements(
+    Path(id): Path<i32>,
+    Extension(pool): Extension<PgPool>,
+) -> Result<Json<Vec<PlacementStat>>, ApiError> {
+    let placements = queries::fetch_placements_by_college(&pool, id).await?;
+    Ok(Json(placements))
+}
+
+// CSV Upload Handler for placement statistics
+pub async fn upload_placements_csv(
+    Extension(pool): Extension<PgPool>,
+    admin: CurrentAdmin,
+    mut multipart: Multipart,
+) -> Result<Json<UploadResponse>, ApiError> {
+    admin.require(Permission::Upload)?;
+
+    let content = read_csv_file(&mut multipart).await?;
+
+    let response = import_csv(
+        "Placement CSV upload completed",
+        &content,
+        |row: PlacementImport| {
+            let pool = pool.clone();
+            async move {
+                row.validate()?;
+
+                sqlx::query_scalar::<_, bool>(
+                    r#"
+                    INSERT INTO placement_stats (
+                        college_id, year, branch, students_eligible, students_placed,
+                        median_package, highest_package, top_recruiters
+                    )
+                    VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+                    ON CONFLICT (college_id, branch, year)
+                    DO UPDATE SET
+                        students_eligible = EXCLUDED.students_eligible,
+                        students_placed = EXCLUDED.students_placed,
+                        median_package = EXCLUDED.median_package,
+                        highest_package = EXCLUDED.highest_package,
+                        top_recruiters = EXCLUDED.top_recruiters,
+                        updated_at = CURRENT_TIMESTAMP
+                    RETURNING (xmax <> 0) AS was_update
+                    "#,
+                )
+                .bind(row.college_id)
+                .bind(row.year)
+                .bind(row.branch.trim())
+                .bind(row.students_eligible)
+                .bind(row.students_placed)
+                .bind(row.median_package)
+                .bind(row.highest_package)
+                .bind(row.recruiters())
+                .fetch_one(&pool)
+                .await
+                .map_err(|e| e.to_string())
+            }
+        },
+    )
+    .await;
+
+    Ok(Json(response))
+}
@@ -0,0 +1,457 @@
+use crate::audit::{self, AuditSource};
+use crate::auth::{CurrentAdmin, Permission};
+use crate::db::queries;
+use crate::errors::ApiError;
+use crate::handlers::auth::hash_password;
+use crate::handlers::college::apply_college_patch;
+use crate::handlers::cutoff::upsert_cutoff;
+use crate::handlers::fee::insert_or_update_fee;
+use crate::handlers::image::{delete_image, lock_college};
+use crate::models::representative::{
+    ClaimInput, CollegeSubmission, RepresentativeClaim, ReviewDecision, ReviewFilters,
+    SubmissionChanges,
+};
+use axum::{
+    extract::{rejection::JsonRejection, Path, Query},
+    http::StatusCode,
+    Extension, Json,
+};
+use sqlx::{PgPool, Postgres, QueryBuilder, Transaction};
+
+const CLAIM_SELECT: &str = "SELECT c.id, c.college_id, col.name AS college_name, c.username, \
+     c.full_name, c.designation, c.email, c.phone, c.message, c.status, c.review_note, \
+     c.reviewed_by, c.reviewed_at, c.user_id, c.created_at \
+     FROM representative_claims c JOIN colleges col ON col.id = c.college_id";
+
+const SUBMISSION_SELECT: &str = "SELECT s.id, s.college_id, col.name AS college_name, \
+     s.submitted_by, u.username AS submitted_by_username, s.kind, s.changes, s.status, \
+     s.review_note, s.reviewed_by, s.reviewed_at, s.created_at \
+     FROM college_submissions s JOIN colleges col ON col.id = s.college_id \
+     LEFT JOIN admin_users u ON u.id = s.submitted_by";
+
+// Blank review notes are stored as NULL
+fn review_note(decision: Option<Json<ReviewDecision>>) -> Option<String> {
+    decision
+        .and_then(|Json(decision)| decision.note)
+        .map(|note| note.trim().to_string())
+        .filter(|note| !note.is_empty())
+}
+
+async fn fetch_claim<'e, E>(executor: E, id: i32) -> Result<RepresentativeClaim, ApiError>
+where
+    E: sqlx::PgExecutor<'e>,
+{
+    sqlx::query_as::<_, RepresentativeClaim>(&format!("{} WHERE c.id = $1", CLAIM_SELECT))
+        .bind(id)
+        .fetch_optional(executor)
+        .await?
+        .ok_or_else(|| ApiError::NotFound("Claim not found".to_string()))
+}
+
+async fn fetch_submission<'e, E>(executor: E, id: i32) -> Result<CollegeSubmission, ApiError>
+where
+    E: sqlx::PgExecutor<'e>,
+{
+    sqlx::query_as::<_, CollegeSubmission>(&format!("{} WHERE s.id = $1", SUBMISSION_SELECT))
+        .bind(id)
+        .fetch_optional(executor)
+        .await?
+        .ok_or_else(|| ApiError::NotFound("Submission not found".to_string()))
+}
+
+/// Ask to manage a college's profile. An admin verifies the claimant and, on
+/// approval, the claim's username and password become a representative account
+/// limited to this college.
+pub async fn claim_college(
+    Path(college_id): Path<i32>,
+    Extension(pool): Extension<PgPool>,
+    payload: Result<Json<ClaimInput>, JsonRejection>,
+) -> Result<(StatusCode, Json<RepresentativeClaim>), ApiError> {
+    let Json(input) = payload?;
+    input.validate().map_err(ApiError::BadRequest)?;
+    queries::fetch_college_by_id(&pool, college_id).await?;
+
+    let username = input.username.trim().to_lowercase();
+    let taken: bool =
+        sqlx::query_scalar("SELECT EXISTS (SELECT 1 FROM admin_users WHERE username = $1)")
+            .bind(&username)
+            .fetch_one(&pool)
+            .await?;
+    if taken {
+        return Err(ApiError::Conflict("Username is already taken".to_string()));
+    }
+
+    let password_hash = hash_password(input.password).await?;
+    let id: i32 = sqlx::query_scalar(
+        r#"
+        INSERT INTO representative_claims
+            (college_id, username, password_hash, full_name, designation, email, phone, message)
+        VALUES ($1, $2, $3, $4, NULLIF($5, ''), $6, NULLIF($7, ''), NULLIF($8, ''))
+        ON CONFLICT (username) WHERE status = 'pending' DO NOTHING
+        RETURNING id
+        "#,
+    )
+    .bind(college_id)
+    .bind(&username)
+    .bind(password_hash)
+    .bind(input.full_name.trim())
+    .bind(input.designation.as_deref().map(str::trim))
+    .bind(input.email.trim())
+    .bind(input.phone.as_deref().map(str::trim))
+    .bind(input.message.as_deref().map(str::trim))
+    .fetch_optional(&pool)
+    .await?
+    .ok_or_else(|| {
+        ApiError::Conflict("A claim with this username is already awaiting review".to_string())
+    })?;
+
+    let claim = fetch_claim(&pool, id).await?;
+    Ok((StatusCode::CREATED, Json(claim)))
+}
+
+// Admin: list representative claims, oldest first
+pub async fn list_claims(
+    Query(filters): Query<ReviewFilters>,
+    Extension(pool): Extension<PgPool>,
+    admin: CurrentAdmin,
+) -> Result<Json<Vec<RepresentativeClaim>>, ApiError> {
+    admin.require(Permission::ManageUsers)?;
+    filters.validate().map_err(ApiError::BadRequest)?;
+
+    let mut query_builder = QueryBuilder::new(CLAIM_SELECT);
+    query_builder.push(" WHERE TRUE");
+    if let Some(status) = &filters.status {
+        query_builder.push(" AND c.status = ");
+        query_builder.push_bind(status);
+    }
+    if let Some(college_id) = filters.college_id {
+        query_builder.push(" AND c.college_id = ");
+        query_builder.push_bind(college_id);
+    }
+    query_builder.push(" ORDER BY c.created_at, c.id");
+
+    let claims = query_builder
+        .build_query_as::<RepresentativeClaim>()
+        .fetch_all(&pool)
+        .await?;
+    Ok(Json(claims))
+}
+
+// Lock a pending claim or submission row for review
+async fn lock_pending(
+    tx: &mut Transaction<'_, Postgres>,
+    table: &str,
+    what: &str,
+    id: i32,
+) -> Result<(), ApiError> {
+    let status: String = sqlx::query_scalar(&format!(
+        "SELECT status FROM {} WHERE id = $1 FOR UPDATE",
+        table
+    ))
+    .bind(id)
+    .fetch_optional(&mut **tx)
+    .await?
+    .ok_or_else(|| ApiError::NotFound(format!("{} not found", what)))?;
+    if status != "pending" {
+        return Err(ApiError::Conflict(format!(
+            "{} was already {}",
+            what, status
+        )));
+    }
+    Ok(())
+}
+
+// Admin: approve a claim, creating the representative account
+pub async fn approve_claim(
+    Path(id): Path<i32>,
+    Extension(pool): Extension<PgPool>,
+    admin: CurrentAdmin,
+    decision: Option<Json<ReviewDecision>>,
+) -> Result<Json<RepresentativeClaim>, ApiError> {
+    admin.require(Permission::ManageUsers)?;
+
+    let mut tx = pool.begin().await?;
+    lock_pending(&mut tx, "representative_claims", "Claim", id).await?;
+    // A college deleted since the claim was made gets no representative
+    let college_id: i32 =
+        sqlx::query_scalar("SELECT college_id FROM representative_claims WHERE id = $1")
+            .bind(id)
+            .fetch_one(&mut *tx)
+            .await?;
+    lock_college(&mut tx, college_id).await?;
+
+    let user_id: i32 = sqlx::query_scalar(
+        r#"
+        INSERT INTO admin_users (username, password_hash, role, college_id)
+        SELECT username, password_hash, 'representative', college_id
+        FROM representative_claims WHERE id = $1
+        ON CONFLICT (username) DO NOTHING
+        RETURNING id
+        "#,
+    )
+    .bind(id)
+    .fetch_optional(&mut *tx)
+    .await?
+    .ok_or_else(|| ApiError::Conflict("Username is already taken".to_string()))?;
+
+    sqlx::query(
+        r#"
+        UPDATE representative_claims
+        SET status = 'approved', user_id = $2, reviewed_by = $3, reviewed_at = NOW(),
+            review_note = $4
+        WHERE id = $1
+        "#,
+    )
+    .bind(id)
+    .bind(user_id)
+    .bind(admin.id)
+    .bind(review_note(decision))
+    .execute(&mut *tx)
+    .await?;
+    tx.commit().await?;
+
+    Ok(Json(fetch_claim(&pool, id).await?))
+}
+
+// Admin: reject a claim
+pub async fn reject_claim(
+    Path(id): Path<i32>,
+    Extension(pool): Extension<PgPool>,
+    admin: CurrentAdmin,
+    decision: Option<Json<ReviewDecision>>,
+) -> Result<Json<RepresentativeClaim>, ApiError> {
+    admin.require(Permission::ManageUsers)?;
+
+    let mut tx = pool.begin().await?;
+    lock_pending(&mut tx, "representative_claims", "Claim", id).await?;
+    sqlx::query(
+        r#"
+        UPDATE representative_claims
+        SET status = 'rejected', reviewed_by = $2, reviewed_at = NOW(), review_note = $3
+        WHERE id = $1
+        "#,
+    )
+    .bind(id)
+    .bind(admin.id)
+    .bind(review_note(decision))
+    .execute(&mut *tx)
+    .await?;
+    tx.commit().await?;
+
+    Ok(Json(fetch_claim(&pool, id).await?))
+}
+
+// Admin: a representative proposes contact info, course fee or cutoff changes for
+// their college, as {"kind": "contact_info" | "courses" | "cutoffs", "changes": ...}
+pub async fn create_submission(
+    Path(college_id): Path<i32>,
+    Extension(pool): Extension<PgPool>,
+    admin: CurrentAdmin,
+    payload: Result<Json<serde_json::Value>, JsonRejection>,
+) -> Result<(StatusCode, Json<CollegeSubmission>), ApiError> {
+    admin.require_college(college_id)?;
+
+    let Json(mut payload) = payload?;
+    // Courses always belong to the representative's own college
+    if payload.get("kind").and_then(|kind| kind.as_str()) == Some("courses") {
+        if let Some(courses) = payload
+            .get_mut("changes")
+            .and_then(|changes| changes.as_array_mut())
+        {
+            for course in courses
+                .iter_mut()
+                .filter_map(|course| course.as_object_mut())
+            {
+                course.insert("college_id".to_string(), college_id.into());
+            }
+        }
+    }
+    let changes = serde_json::from_value::<SubmissionChanges>(payload)
+        .map_err(|e| ApiError::BadRequest(format!("Invalid submission: {}", e)))?
+        .normalize(college_id)
+        .map_err(ApiError::BadRequest)?;
+
+    let kind = changes.kind();
+    let stored = serde_json::to_value(&changes)
+        .map_err(|e| ApiError::InternalServerError(e.to_string()))?["changes"]
+        .take();
+
+    let id: i32 = sqlx::query_scalar(
+        r#"
+        INSERT INTO college_submissions (college_id, submitted_by, kind, changes)
+        VALUES ($1, $2, $3, $4)
+        RETURNING id
+        "#,
+    )
+    .bind(college_id)
+    .bind(admin.id)
+    .bind(kind)
+    .bind(sqlx::types::Json(stored))
+    .fetch_one(&pool)
+    .await?;
+
+    let submission = fetch_submission(&pool, id).await?;
+    Ok((StatusCode::CREATED, Json(submission)))
+}
+
+// Admin: list submissions, oldest first. Reviewers see every college's submissions;
+// representatives only their own college's.
+pub async fn list_submissions(
+    Query(filters): Query<ReviewFilters>,
+    Extension(pool): Extension<PgPool>,
+    admin: CurrentAdmin,
+) -> Result<Json<Vec<CollegeSubmission>>, ApiError> {
+    filters.validate().map_err(ApiError::BadRequest)?;
+    let scope = if admin.role.allows(Permission::Review) {
+        filters.college_id
+    } else {
+        admin.require(Permission::Submit)?;
+        admin.college_id
+    };
+
+    let mut query_builder = QueryBuilder::new(SUBMISSION_SELECT);
+    query_builder.push(" WHERE TRUE");
+    if let Some(status) = &filters.status {
+        query_builder.push(" AND s.status = ");
+        query_builder.push_bind(status);
+    }
+    if let Some(college_id) = scope {
+        query_builder.push(" AND s.college_id = ");
+        query_builder.push_bind(college_id);
+    }
+    query_builder.push(" ORDER BY s.created_at, s.id");
+
+    let submissions = query_builder
+        .build_query_as::<CollegeSubmission>()
+        .fetch_all(&pool)
+        .await?;
+    Ok(Json(submissions))
+}
+
+// Write approved changes to the college's live records
+async fn apply_submission(
+    tx: &mut Transaction<'_, Postgres>,
+    college_id: i32,
+    changes: SubmissionChanges,
+) -> Result<(), ApiError> {
+    match changes {
+        SubmissionChanges::ContactInfo(change) => {
+            apply_college_patch(tx, college_id, &change.into_patch()).await?;
+        }
+        SubmissionChanges::Courses(fees) => {
+            for fee in &fees {
+                insert_or_update_fee(&mut **tx, fee).await?;
+            }
+        }
+        SubmissionChanges::Cutoffs(cutoffs) => {
+            for cutoff in &cutoffs {
+                upsert_cutoff(&mut **tx, college_id, cutoff).await?;
+            }
+        }
+        SubmissionChanges::Image { image_id } => {
+            // The first published image becomes the primary image
+            sqlx::query(
+                r#"
+                UPDATE college_images
+                SET published = TRUE,
+                    is_primary = NOT EXISTS (
+                        SELECT 1 FROM college_images WHERE college_id = $2 AND is_primary
+                    )
+                WHERE id = $1 AND college_id = $2
+                RETURNING id
+                "#,
+            )
+            .bind(image_id)
+            .bind(college_id)
+            .fetch_optional(&mut **tx)
+            .await?
+            .ok_or_else(|| ApiError::Conflict("The submitted image was deleted".to_string()))?;
+        }
+    }
+    Ok(())
+}
+
+async fn load_changes(
+    tx: &mut Transaction<'_, Postgres>,
+    id: i32,
+) -> Result<(i32, SubmissionChanges), ApiError> {
+    let (college_id, kind, changes): (i32, String, sqlx::types::Json<serde_json::Value>) =
+        sqlx::query_as("SELECT college_id, kind, changes FROM college_submissions WHERE id = $1")
+            .bind(id)
+            .fetch_one(&mut **tx)
+            .await?;
+    let changes = serde_json::from_value(serde_json::json!({ "kind": kind, "changes": changes.0 }))
+        .map_err(|e| ApiError::InternalServerError(format!("Invalid stored submission: {}", e)))?;
+    Ok((college_id, changes))
+}
+
+// Admin: approve a submission and apply its changes
+pub async fn approve_submission(
+    Path(id): Path<i32>,
+    Extension(pool): Extension<PgPool>,
+    admin: CurrentAdmin,
+    decision: Option<Json<ReviewDecision>>,
+) -> Result<Json<CollegeSubmission>, ApiError> {
+    admin.require(Permission::Review)?;
+
+    let mut tx = pool.begin().await?;
+    audit::set_context(&mut tx, &admin, AuditSource::Submission).await?;
+    lock_pending(&mut tx, "college_submissions", "Submission", id).await?;
+    let (college_id, changes) = load_changes(&mut tx, id).await?;
+    // Changes to a deleted college are not applied
+    lock_college(&mut tx, college_id).await?;
+    apply_submission(&mut tx, college_id, changes).await?;
+
+    sqlx::query(
+        r#"
+        UPDATE college_submissions
+        SET status = 'approved', reviewed_by = $2, reviewed_at = NOW(), review_note = $3
+        WHERE id = $1
+        "#,
+    )
+    .bind(id)
+    .bind(admin.id)
+    .bind(review_note(decision))
+    .execute(&mut *tx)
+    .await?;
+    tx.commit().await?;
+
+    Ok(Json(fetch_submission(&pool, id).await?))
+}
+
+// Admin: reject a submission; a rejected image is deleted
+pub async fn reject_submission(
+    Path(id): Path<i32>,
+    Extension(pool): Extension<PgPool>,
+    admin: CurrentAdmin,
+    decision: Option<Json<ReviewDecision>>,
+) -> Result<Json<CollegeSubmission>, ApiError> {
+    admin.require(Permission::Review)?;
+
+    let mut tx = pool.begin().await?;
+    lock_pending(&mut tx, "college_submissions", "Submission", id).await?;
+    let (_, changes) = load_changes(&mut tx, id).await?;
+
+    sqlx::query(
+        r#"
+        UPDATE college_submissions
+        SET status = 'rejected', reviewed_by = $2, reviewed_at = NOW(), review_note = $3
+        WHERE id = $1
+        "#,
+    )
+    .bind(id)
+    .bind(admin.id)
+    .bind(review_note(decision))
+    .execute(&mut *tx)
+    .await?;
+    tx.commit().await?;
+
+    if let SubmissionChanges::Image { image_id } = changes {
+        match delete_image(&pool, image_id, &admin, AuditSource::Submission).await {
+            Ok(()) | Err(ApiError::NotFound(_)) => {}
+            Err(e) => return Err(e),
+        }
+    }
+
+    Ok(Json(fetch_submission(&pool, id).await?))
+}
@@ -0,0 +1,157 @@
+use crate::auth::{CurrentAdmin, Permission};
+use crate::db::queries;
+use crate::errors::ApiError;
+use crate::handlers::concession::normalize_categories;
+use crate::models::scholarship::ScholarshipInput;
+use crate::models::{Scholarship, StudentProfile};
+use axum::{
+    extract::{Path, Query},
+    http::StatusCode,
+    Extension, Json,
+};
+use sqlx::PgPool;
+
+/// List every active scholarship scheme.
+pub async fn list_scholarships(
+    Extension(pool): Extension<PgPool>,
+) -> Result<Json<Vec<Scholarship>>, ApiError> {
+    let scholarships = queries::fetch_active_scholarships(&pool).await?;
+    Ok(Json(scholarships))
+}
+
+/// List the active schemes a student profile is eligible for.
+pub async fn match_scholarships(
+    Query(profile): Query<StudentProfile>,
+    Extension(pool): Extension<PgPool>,
+) -> Result<Json<Vec<Scholarship>>, ApiError> {
+    let scholarships = queries::fetch_active_scholarships(&pool)
+        .await?
+        .into_iter()
+        .filter(|s| s.is_eligible(&profile))
+        .collect();
+
+    Ok(Json(scholarships))
+}
+
+// Admin: list all scholarships, including inactive ones
+pub async fn list_all_scholarships(
+    Extension(pool): Extension<PgPool>,
+    admin: CurrentAdmin,
+) -> Result<Json<Vec<Scholarship>>, ApiError> {
+    admin.require(Permission::Edit)?;
+
+    let scholarships =
+        sqlx::query_as::<_, Scholarship>("SELECT * FROM scholarships ORDER BY provider, name")
+            .fetch_all(&pool)
+            .await?;
+
+    Ok(Json(scholarships))
+}
+
+// Admin: create a scholarship
+pub async fn create_scholarship(
+    Extension(pool): Extension<PgPool>,
+    admin: CurrentAdmin,
+    Json(input): Json<ScholarshipInput>,
+) -> Result<(StatusCode, Json<Scholarship>), ApiError> {
+    admin.require(Permission::Edit)?;
+
+    input.validate().map_err(ApiError::BadRequest)?;
+
+    let scholarship = sqlx::query_as::<_, Scholarship>(
+        r#"
+        INSERT INTO scholarships (
+            name, provider, description, url, max_amount, categories, max_family_income,
+            gender, courses, minority_only, requires_domicile, active
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
+        RETURNING *
+        "#,
+    )
+    .bind(input.name.trim())
+    .bind(input.provider.trim())
+    .bind(&input.description)
+    .bind(&input.url)
+    .bind(input.max_amount)
+    .bind(normalize_categories(&input.categories))
+    .bind(input.max_family_income)
+    .bind(&input.gender)
+    .bind(&input.courses)
+    .bind(input.minority_only)
+    .bind(input.requires_domicile)
+    .bind(input.active.unwrap_or(true))
+    .fetch_one(&pool)
+    .await?;
+
+    Ok((StatusCode::CREATED, Json(scholarship)))
+}
+
+// Admin: replace a scholarship
+pub async fn update_scholarship(
+    Path(id): Path<i32>,
+    Extension(pool): Extension<PgPool>,
+    admin: CurrentAdmin,
+    Json(input): Json<ScholarshipInput>,
+) -> Result<Json<Scholarship>, ApiError> {
+    admin.require(Permission::Edit)?;
+
+    input.validate().map_err(ApiError::BadRequest)?;
+
+    let scholarship = sqlx::query_as::<_, Scholarship>(
+        r#"
+        UPDATE scholarships SET
+            name = $2,
+            provider = $3,
+            description = $4,
+            url = $5,
+            max_amount = $6,
+            categories = $7,
+            max_family_income = $8,
+            gender = $9,
+            courses = $10,
+            minority_only = $11,
+            requires_domicile = $12,
+            active = $13,
+            updated_at = CURRENT_TIMESTAMP
+        WHERE id = $1
+        RETURNING *
+        "#,
+    )
+    .bind(id)
+    .bind(input.name.trim())
+    .bind(input.provider.trim())
+    .bind(&input.description)
+    .bind(&input.url)
+    .bind(input.max_amount)
+    .bind(normalize_categories(&input.categories))
+    .bind(input.max_family_income)
+    .bind(&input.gender)
+    .bind(&input.courses)
+    .bind(input.minority_only)
+    .bind(input.requires_domicile)
+    .bind(input.active.unwrap_or(true))
+    .fetch_one(&pool)
+    .await?;
+
+    Ok(Json(scholarship))
+}
+
+// Admin: delete a scholarship
+pub async fn delete_scholarship(
+    Path(id): Path<i32>,
+    Extension(pool): Extension<PgPool>,
+    admin: CurrentAdmin,
+) -> Result<StatusCode, ApiError> {
+    admin.require(Permission::Delete)?;
+
+    let result = sqlx::query("DELETE FROM scholarships WHERE id = $1")
+        .bind(id)
+        .execute(&pool)
+        .await?;
+
+    if result.rows_affected() == 0 {
+        return Err(ApiError::NotFound("Scholarship not found".to_string()));
+    }
+
+    Ok(StatusCode::NO_CONTENT)
+}
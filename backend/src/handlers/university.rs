@@ -0,0 +1,63 @@
+use crate::auth::{CurrentAdmin, Permission};
+use crate::db::queries;
+use crate::errors::ApiError;
+use crate::models::university::UniversityInput;
+use crate::models::{University, UniversityWithCount};
+use axum::{http::StatusCode, Extension, Json};
+use sqlx::PgPool;
+
+/// List universities with the number of affiliated colleges.
+pub async fn list_universities(
+    Extension(pool): Extension<PgPool>,
+) -> Result<Json<Vec<UniversityWithCount>>, ApiError> {
+    let universities = queries::fetch_universities_with_counts(&pool).await?;
+    Ok(Json(universities))
+}
+
+// Admin: add a university
+pub async fn create_university(
+    Extension(pool): Extension<PgPool>,
+    admin: CurrentAdmin,
+    Json(input): Json<UniversityInput>,
+) -> Result<(StatusCode, Json<University>), ApiError> {
+    admin.require(Permission::Edit)?;
+
+    if input.name.trim().is_empty() || input.short_name.trim().is_empty() {
+        return Err(ApiError::BadRequest(
+            "name and short_name are required".to_string(),
+        ));
+    }
+
+    let university = sqlx::query_as::<_, University>(
+        r#"
+        INSERT INTO universities (name, short_name, city, university_type, website)
+        VALUES ($1, $2, $3, $4, $5)
+        RETURNING *
+        "#,
+    )
+    .bind(input.name.trim())
+    .bind(input.short_name.trim())
+    .bind(&input.city)
+    .bind(&input.university_type)
+    .bind(&input.website)
+    .fetch_one(&pool)
+    .await
+    .map_err(map_duplicate)?;
+
+    Ok((StatusCode::CREATED, Json(university)))
+}
+
+fn map_duplicate(err: sqlx::Error) -> ApiError {
+    match &err {
+        sqlx::Error::Database(db_err) => match db_err.constraint() {
+            Some("universities_name_key") => {
+                ApiError::Conflict("A university with this name already exists".to_string())
+            }
+            Some("universities_short_name_key") => {
+                ApiError::Conflict("A university with this short name already exists".to_string())
+            }
+            _ => ApiError::from(err),
+        },
+        _ => ApiError::from(err),
+    }
+}
@@ -0,0 +1,105 @@
+use axum::{
+    routing::{get, post},
+    Extension, Router,
+};
+use sqlx::PgPool;
+use tower_http::{cors::CorsLayer, services::ServeDir};
+
+pub mod audit;
+pub mod auth;
+pub mod csv_stream;
+pub mod db;
+pub mod errors;
+pub mod handlers;
+pub mod media;
+pub mod models;
+pub mod spreadsheet;
+
+use handlers::admin;
+use handlers::admission::get_admission_requirements;
+use handlers::attachment::{list_college_attachments, serve_attachment};
+use handlers::college::{
+    get_college, get_college_history, list_colleges, list_colleges_by_category,
+    list_colleges_by_district,
+};
+use handlers::concession::get_cost_estimate;
+use handlers::cutoff::get_cutoffs_by_college;
+use handlers::district::{
+    list_categories, list_college_types, list_districts, list_facets, list_gender_policies,
+    list_instruction_languages,
+};
+use handlers::facility::list_facilities;
+use handlers::fee::get_college_fees;
+use handlers::image::{
+    list_college_images, serve_media, upload_dir, LEGACY_IMAGE_DIR, UPLOADS_URL_PREFIX,
+};
+use handlers::placement::get_college_placements;
+use handlers::representative::claim_college;
+use handlers::scholarship::{list_scholarships, match_scholarships};
+use handlers::university::list_universities;
+
+/// Build the application router backed by the given connection pool.
+pub async fn create_app_with_pool(pool: PgPool) -> Router {
+    Router::new()
+        // Health check routes
+        .route(
+            "/",
+            get(|| async { "Maharashtra Colleges API is running!" }),
+        )
+        .route("/health", get(|| async { "OK" }))
+        // College routes with filtering
+        .route("/api/colleges", get(list_colleges))
+        .route("/api/colleges/:id", get(get_college))
+        .route("/api/colleges/:id/history", get(get_college_history))
+        .route("/api/colleges/:id/fees", get(get_college_fees))
+        .route("/api/colleges/:id/cost-estimate", get(get_cost_estimate))
+        .route("/api/colleges/:id/placements", get(get_college_placements))
+        .route("/api/colleges/:id/images", get(list_college_images))
+        .route("/api/colleges/:id/claims", post(claim_college))
+        .route(
+            "/api/colleges/:id/attachments",
+            get(list_college_attachments),
+        )
+        .route(
+            "/api/colleges/district/:district",
+            get(list_colleges_by_district),
+        )
+        .route(
+            "/api/colleges/category/:category",
+            get(list_colleges_by_category),
+        )
+        // Utility routes
+        .route("/api/districts", get(list_districts))
+        .route("/api/categories", get(list_categories))
+        .route("/api/college-types", get(list_college_types))
+        .route("/api/gender-policies", get(list_gender_policies))
+        .route(
+            "/api/instruction-languages",
+            get(list_instruction_languages),
+        )
+        .route("/api/facets", get(list_facets))
+        .route("/api/universities", get(list_universities))
+        .route("/api/facilities", get(list_facilities))
+        // Scholarship routes
+        .route("/api/scholarships", get(list_scholarships))
+        .route("/api/scholarships/match", get(match_scholarships))
+        // Cutoff routes
+        .route("/api/cutoffs/:college_id", get(get_cutoffs_by_college))
+        // Admission requirements routes
+        .route(
+            "/api/admission-requirements/:category",
+            get(get_admission_requirements),
+        )
+        // Uploaded media
+        .route("/media/:id", get(serve_media))
+        .route("/attachments/:id", get(serve_attachment))
+        .nest_service(
+            &format!("{}/{}", UPLOADS_URL_PREFIX, LEGACY_IMAGE_DIR),
+            ServeDir::new(upload_dir().join(LEGACY_IMAGE_DIR)),
+        )
+        // Merge admin routes - THIS IS THE KEY ADDITION
+        .merge(admin::admin_routes())
+        .layer(CorsLayer::permissive())
+        .layer(Extension(pool))
+        .layer(Extension(auth::AuthConfig::from_env()))
+}
@@ -0,0 +1,117 @@
+//! Image validation and rendition generation for uploaded college photos.
+
+use image::{imageops::FilterType, DynamicImage, ImageDecoder, ImageFormat, ImageReader, Limits};
+use sha2::{Digest, Sha256};
+use std::io::Cursor;
+
+/// Rendition variants generated for every upload, with their target widths.
+pub const RENDITIONS: [(&str, u32); 3] = [("thumbnail", 320), ("card", 800), ("hero", 1600)];
+
+/// Lossy WebP quality (0-100) used for every rendition.
+const WEBP_QUALITY: f32 = 80.0;
+
+/// Largest accepted width or height of an uploaded image, in pixels.
+const MAX_DIMENSION: u32 = 12_000;
+
+pub struct RenditionFile {
+    pub variant: &'static str,
+    pub width: u32,
+    pub height: u32,
+    pub data: Vec<u8>,
+}
+
+pub struct ProcessedImage {
+    pub width: u32,
+    pub height: u32,
+    pub renditions: Vec<RenditionFile>,
+}
+
+/// Hex-encoded SHA-256 of the uploaded bytes.
+pub fn content_hash(data: &[u8]) -> String {
+    format!("{:x}", Sha256::digest(data))
+}
+
+/// Validate that `data` is a JPEG, PNG or WebP image and re-encode it as WebP
+/// renditions. Decoding and re-encoding drops all EXIF/GPS metadata; the EXIF
+/// orientation is applied to the pixels first so photos stay upright. Images
+/// are never upscaled, so small uploads yield renditions at their own size.
+pub fn process_image(data: &[u8]) -> Result<ProcessedImage, String> {
+    let format = image::guess_format(data).map_err(|_| "Unrecognized image format".to_string())?;
+    if !matches!(
+        format,
+        ImageFormat::Jpeg | ImageFormat::Png | ImageFormat::WebP
+    ) {
+        return Err("Image must be JPEG, PNG or WebP".to_string());
+    }
+
+    let mut limits = Limits::default();
+    limits.max_image_width = Some(MAX_DIMENSION);
+    limits.max_image_height = Some(MAX_DIMENSION);
+
+    let mut reader = ImageReader::with_format(Cursor::new(data), format);
+    reader.limits(limits);
+    let mut decoder = reader
+        .into_decoder()
+        .map_err(|e| format!("Invalid image: {}", e))?;
+    let orientation = decoder
+        .orientation()
+        .map_err(|e| format!("Invalid image: {}", e))?;
+    let mut img =
+        DynamicImage::from_decoder(decoder).map_err(|e| format!("Invalid image: {}", e))?;
+    img.apply_orientation(orientation);
+
+    // Downscale from the largest rendition to the smallest, each step starting
+    // from the previous result, so only the first resize touches the full image
+    let (width, height) = (img.width(), img.height());
+    let mut source = img;
+    let mut renditions = Vec::with_capacity(RENDITIONS.len());
+    for &(variant, target_width) in RENDITIONS.iter().rev() {
+        if source.width() > target_width {
+            source = source.resize(target_width, u32::MAX, FilterType::CatmullRom);
+        }
+        renditions.push(RenditionFile {
+            variant,
+            width: source.width(),
+            height: source.height(),
+            data: encode_webp(&source),
+        });
+    }
+    renditions.reverse();
+
+    Ok(ProcessedImage {
+        width,
+        height,
+        renditions,
+    })
+}
+
+// Encode as lossy WebP, keeping an alpha channel only when the source has one
+fn encode_webp(img: &DynamicImage) -> Vec<u8> {
+    if img.color().has_alpha() {
+        let rgba = img.to_rgba8();
+        webp::Encoder::from_rgba(&rgba, rgba.width(), rgba.height())
+            .encode(WEBP_QUALITY)
+            .to_vec()
+    } else {
+        let rgb = img.to_rgb8();
+        webp::Encoder::from_rgb(&rgb, rgb.width(), rgb.height())
+            .encode(WEBP_QUALITY)
+            .to_vec()
+    }
+}
+
+/// Pick the rendition whose width is closest to `requested`, preferring the
+/// larger one on a tie; without a requested width the largest is chosen.
+pub fn nearest_rendition<T>(
+    renditions: &[T],
+    width_of: impl Fn(&T) -> i32,
+    requested: Option<u32>,
+) -> Option<&T> {
+    match requested {
+        Some(w) => renditions.iter().min_by_key(|r| {
+            let width = width_of(r);
+            ((i64::from(width) - i64::from(w)).abs(), -i64::from(width))
+        }),
+        None => renditions.iter().max_by_key(|r| width_of(r)),
+    }
+}
@@ -0,0 +1,100 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+#[derive(Serialize, Deserialize, FromRow)]
+pub struct NaacAccreditation {
+    pub id: i32,
+    pub college_id: i32,
+    pub grade: String,
+    pub cgpa: Option<f64>,
+    pub cycle: Option<i32>,
+    pub valid_from: NaiveDate,
+    pub valid_until: NaiveDate,
+    pub created_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Serialize, Deserialize, FromRow)]
+pub struct NbaAccreditation {
+    pub id: i32,
+    pub college_id: i32,
+    pub programme: String,
+    pub valid_from: NaiveDate,
+    pub valid_until: NaiveDate,
+    pub created_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Serialize, Deserialize, FromRow)]
+pub struct NirfRanking {
+    pub id: i32,
+    pub college_id: i32,
+    pub year: i32,
+    pub category: String,
+    pub rank: Option<i32>,
+    pub rank_band: Option<String>,
+    pub score: Option<f64>,
+    pub created_at: Option<DateTime<Utc>>,
+}
+
+/// Accreditation and ranking records shown on the college detail response.
+#[derive(Serialize)]
+pub struct Accreditation {
+    pub naac: Vec<NaacAccreditation>,
+    pub nba: Vec<NbaAccreditation>,
+    pub nirf: Vec<NirfRanking>,
+}
+
+/// NAAC grades, ordered from lowest to highest like the SQL `naac_grade_rank`.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(try_from = "String", into = "String")]
+pub enum NaacGrade {
+    D,
+    C,
+    B,
+    BPlus,
+    BPlusPlus,
+    A,
+    APlus,
+    APlusPlus,
+}
+
+impl NaacGrade {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            NaacGrade::D => "D",
+            NaacGrade::C => "C",
+            NaacGrade::B => "B",
+            NaacGrade::BPlus => "B+",
+            NaacGrade::BPlusPlus => "B++",
+            NaacGrade::A => "A",
+            NaacGrade::APlus => "A+",
+            NaacGrade::APlusPlus => "A++",
+        }
+    }
+}
+
+impl TryFrom<String> for NaacGrade {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        // An unescaped '+' in a query string arrives as a space
+        let grade = value.trim_start().replace(' ', "+").to_uppercase();
+        match grade.as_str() {
+            "D" => Ok(NaacGrade::D),
+            "C" => Ok(NaacGrade::C),
+            "B" => Ok(NaacGrade::B),
+            "B+" => Ok(NaacGrade::BPlus),
+            "B++" => Ok(NaacGrade::BPlusPlus),
+            "A" => Ok(NaacGrade::A),
+            "A+" => Ok(NaacGrade::APlus),
+            "A++" => Ok(NaacGrade::APlusPlus),
+            _ => Err(format!("unknown NAAC grade '{}'", value)),
+        }
+    }
+}
+
+impl From<NaacGrade> for String {
+    fn from(grade: NaacGrade) -> Self {
+        grade.as_str().to_string()
+    }
+}
@@ -0,0 +1,130 @@
+use crate::auth::Role;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+#[derive(Serialize, Deserialize, FromRow)]
+pub struct AdminUser {
+    pub id: i32,
+    pub username: String,
+    #[serde(skip_serializing, default)]
+    pub password_hash: String,
+    pub role: String,
+    /// Set only for representative accounts
+    pub college_id: Option<i32>,
+    pub active: bool,
+    pub created_at: Option<DateTime<Utc>>,
+    pub last_login_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Deserialize)]
+pub struct LoginRequest {
+    pub username: String,
+    pub password: String,
+}
+
+#[derive(Serialize)]
+pub struct LoginResponse {
+    pub token: String,
+    pub token_type: &'static str,
+    pub expires_at: DateTime<Utc>,
+    pub user: AdminUser,
+}
+
+/// Minimum accepted password length for admin accounts.
+pub const MIN_PASSWORD_LENGTH: usize = 10;
+
+pub(crate) fn validate_password(password: &str) -> Result<(), String> {
+    if password.chars().count() < MIN_PASSWORD_LENGTH {
+        return Err(format!(
+            "password must be at least {} characters",
+            MIN_PASSWORD_LENGTH
+        ));
+    }
+    Ok(())
+}
+
+fn validate_role(role: &str) -> Result<(), String> {
+    match Role::parse(role) {
+        Some(_) => Ok(()),
+        None => Err(format!(
+            "role must be one of: {}",
+            Role::ALL.map(Role::as_str).join(", ")
+        )),
+    }
+}
+
+/// Admin payload for creating an admin account.
+#[derive(Deserialize)]
+pub struct AdminUserInput {
+    pub username: String,
+    pub password: String,
+    /// Defaults to `volunteer`
+    pub role: Option<String>,
+    /// Required for, and only allowed with, the `representative` role
+    pub college_id: Option<i32>,
+}
+
+pub(crate) fn validate_username(username: &str) -> Result<(), String> {
+    let username = username.trim();
+    if username.is_empty() || username.len() > 100 {
+        return Err("username must be between 1 and 100 characters".to_string());
+    }
+    if !username
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '-' | '@'))
+    {
+        return Err("username may only contain letters, digits, '.', '_', '-' and '@'".to_string());
+    }
+    Ok(())
+}
+
+impl AdminUserInput {
+    pub fn validate(&self) -> Result<(), String> {
+        validate_username(&self.username)?;
+        if let Some(role) = &self.role {
+            validate_role(role)?;
+        }
+        let is_representative = self.role.as_deref() == Some(Role::Representative.as_str());
+        if is_representative != self.college_id.is_some() {
+            return Err(
+                "college_id is required for representatives and not allowed for other roles"
+                    .to_string(),
+            );
+        }
+        validate_password(&self.password)
+    }
+}
+
+/// Admin payload for changing an account's password, role or active flag.
+#[derive(Deserialize)]
+pub struct AdminUserUpdate {
+    pub password: Option<String>,
+    pub role: Option<String>,
+    pub active: Option<bool>,
+}
+
+impl AdminUserUpdate {
+    pub fn validate(&self) -> Result<(), String> {
+        if let Some(password) = &self.password {
+            validate_password(password)?;
+        }
+        if let Some(role) = &self.role {
+            validate_role(role)?;
+            if role == Role::Representative.as_str() {
+                return Err(
+                    "representative accounts are created from approved claims or with a college_id"
+                        .to_string(),
+                );
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A role and the permissions it grants, as listed by `/api/admin/roles`.
+#[derive(Serialize)]
+pub struct RoleInfo {
+    pub role: Role,
+    pub permissions: &'static [crate::auth::Permission],
+}
@@ -0,0 +1,90 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+/// Accepted values for `college_attachments.document_type`.
+pub const DOCUMENT_TYPES: [&str; 5] = ["prospectus", "fee_notice", "cutoff", "brochure", "other"];
+
+pub const PDF_CONTENT_TYPE: &str = "application/pdf";
+pub const DOCX_CONTENT_TYPE: &str =
+    "application/vnd.openxmlformats-officedocument.wordprocessingml.document";
+
+#[derive(Serialize, Deserialize, FromRow)]
+pub struct Attachment {
+    pub id: i32,
+    pub college_id: i32,
+    pub document_type: String,
+    /// Academic year such as `2024-25`.
+    pub academic_year: Option<String>,
+    pub title: String,
+    pub file_name: String,
+    pub content_type: String,
+    pub byte_size: i64,
+    /// Hex-encoded SHA-256 of the file.
+    pub checksum: String,
+    #[serde(skip_serializing)]
+    pub file_path: String,
+    /// Download URL, `/attachments/:id`.
+    pub url: String,
+    pub uploaded_at: Option<DateTime<Utc>>,
+}
+
+/// Metadata sent alongside an uploaded attachment.
+pub struct AttachmentInput {
+    pub document_type: String,
+    pub academic_year: Option<String>,
+    pub title: String,
+}
+
+impl AttachmentInput {
+    pub fn validate(&self) -> Result<(), String> {
+        if !DOCUMENT_TYPES.contains(&self.document_type.as_str()) {
+            return Err(format!(
+                "document_type must be one of: {}",
+                DOCUMENT_TYPES.join(", ")
+            ));
+        }
+        if let Some(year) = &self.academic_year {
+            validate_academic_year(year)?;
+        }
+        if self.title.trim().is_empty() {
+            return Err("title is required".to_string());
+        }
+        if self.title.len() > 255 {
+            return Err("title must be at most 255 characters".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// Check an academic year of the form `2024-25`, where the second year follows the first.
+pub fn validate_academic_year(year: &str) -> Result<(), String> {
+    let error = || format!("academic_year must look like 2024-25, got '{}'", year);
+    let (start, end) = year.split_once('-').ok_or_else(error)?;
+    if start.len() != 4 || end.len() != 2 {
+        return Err(error());
+    }
+    let start: u32 = start.parse().map_err(|_| error())?;
+    let end: u32 = end.parse().map_err(|_| error())?;
+    if (start + 1) % 100 != end {
+        return Err(error());
+    }
+    Ok(())
+}
+
+/// Detect a PDF or DOCX document from its leading bytes, returning its content
+/// type and file extension.
+pub fn detect_document_type(data: &[u8]) -> Option<(&'static str, &'static str)> {
+    if data.starts_with(b"%PDF-") {
+        return Some((PDF_CONTENT_TYPE, "pdf"));
+    }
+    // DOCX files are ZIP archives whose entry names are stored uncompressed
+    let is_zip = data.starts_with(b"PK\x03\x04");
+    let has_document = data
+        .windows(b"word/document.xml".len())
+        .any(|window| window == b"word/document.xml");
+    if is_zip && has_document {
+        return Some((DOCX_CONTENT_TYPE, "docx"));
+    }
+    None
+}
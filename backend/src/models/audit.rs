@@ -0,0 +1,78 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+/// Tables whose changes are recorded in the audit log.
+pub const AUDITED_TABLES: [&str; 4] = ["colleges", "contact_info", "cutoffs", "college_images"];
+
+pub const AUDIT_ACTIONS: [&str; 3] = ["create", "update", "delete"];
+
+/// One recorded change. `changes` maps each changed column to its
+/// `{"before": ..., "after": ...}` values.
+#[derive(Serialize, FromRow)]
+pub struct AuditEntry {
+    pub id: i64,
+    pub occurred_at: DateTime<Utc>,
+    pub actor_id: Option<i32>,
+    pub actor: Option<String>,
+    pub source: String,
+    pub table_name: String,
+    pub record_id: i32,
+    pub college_id: Option<i32>,
+    pub action: String,
+    pub changes: sqlx::types::Json<serde_json::Value>,
+}
+
+/// Query parameters for `/api/admin/audit`; `from` and `to` are inclusive dates (UTC).
+#[derive(Deserialize)]
+pub struct AuditFilters {
+    pub college_id: Option<i32>,
+    /// Admin username
+    pub actor: Option<String>,
+    pub from: Option<NaiveDate>,
+    pub to: Option<NaiveDate>,
+    pub table: Option<String>,
+    pub action: Option<String>,
+    pub source: Option<String>,
+    pub page: Option<u32>,
+    pub limit: Option<u32>,
+}
+
+impl AuditFilters {
+    pub const DEFAULT_LIMIT: u32 = 50;
+    pub const MAX_LIMIT: u32 = 500;
+
+    pub fn validate(&self) -> Result<(), String> {
+        if let Some(table) = &self.table {
+            if !AUDITED_TABLES.contains(&table.as_str()) {
+                return Err(format!(
+                    "table must be one of: {}",
+                    AUDITED_TABLES.join(", ")
+                ));
+            }
+        }
+        if let Some(action) = &self.action {
+            if !AUDIT_ACTIONS.contains(&action.as_str()) {
+                return Err(format!(
+                    "action must be one of: {}",
+                    AUDIT_ACTIONS.join(", ")
+                ));
+            }
+        }
+        if let (Some(from), Some(to)) = (self.from, self.to) {
+            if from > to {
+                return Err("from must not be after to".to_string());
+            }
+        }
+        if self.page == Some(0) {
+            return Err("page starts at 1".to_string());
+        }
+        if self
+            .limit
+            .is_some_and(|limit| limit == 0 || limit > Self::MAX_LIMIT)
+        {
+            return Err(format!("limit must be between 1 and {}", Self::MAX_LIMIT));
+        }
+        Ok(())
+    }
+}
@@ -1,4 +1,11 @@
-use serde::{Deserialize, Serialize};
+use crate::models::accreditation::Accreditation;
+use crate::models::attachment::Attachment;
+use crate::models::college_image::CollegeImage;
+use crate::models::facility::Facility;
+use crate::models::hostel::Hostel;
+use crate::models::scholarship::ScholarshipLink;
+use chrono::{DateTime, Datelike, Utc};
+use serde::{Deserialize, Deserializer, Serialize};
 use sqlx::FromRow;
 
 #[derive(Serialize, Deserialize, FromRow)]
@@ -13,6 +20,10 @@ pub struct College {
     pub minority: bool,
     pub hostel_available: bool,
     pub established_year: Option<i32>,
+    pub university_id: Option<i32>,
+    pub gender_policy: String,
+    pub instruction_languages: Vec<String>,
+    pub primary_image_url: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, FromRow)]
@@ -27,6 +38,10 @@ pub struct CollegeWithContact {
     pub minority: bool,
     pub hostel_available: bool,
     pub established_year: Option<i32>,
+    pub university_id: Option<i32>,
+    pub university: Option<String>,
+    pub gender_policy: String,
+    pub instruction_languages: Vec<String>,
     // Contact info fields
     pub phone: Option<String>,
     pub email: Option<String>,
@@ -34,3 +49,311 @@ pub struct CollegeWithContact {
     pub address: Option<String>,
     pub pincode: Option<String>,
 }
+
+/// The college and contact fields as they were from `valid_from` until `valid_to`
+/// (`None` for the current version).
+#[derive(Serialize, FromRow)]
+pub struct CollegeVersion {
+    pub valid_from: DateTime<Utc>,
+    pub valid_to: Option<DateTime<Utc>>,
+    #[serde(flatten)]
+    #[sqlx(flatten)]
+    pub college: CollegeWithContact,
+}
+
+/// College detail response: contact info plus related records.
+#[derive(Serialize)]
+pub struct CollegeDetail {
+    #[serde(flatten)]
+    pub college: CollegeWithContact,
+    pub accreditation: Accreditation,
+    pub facilities: Vec<Facility>,
+    pub hostels: Vec<Hostel>,
+    pub images: Vec<CollegeImage>,
+    pub attachments: Vec<Attachment>,
+    pub scholarships: Vec<ScholarshipLink>,
+}
+
+/// Accepted values for `colleges.gender_policy`.
+pub const GENDER_POLICIES: [&str; 3] = ["co-ed", "women-only", "men-only"];
+
+/// Normalize a gender policy such as "Women Only" or "coed" to its stored form.
+pub fn parse_gender_policy(value: &str) -> Result<String, String> {
+    let normalized = value.trim().to_lowercase().replace(['_', ' '], "-");
+    let normalized = if normalized == "coed" {
+        "co-ed".to_string()
+    } else {
+        normalized
+    };
+
+    if GENDER_POLICIES.contains(&normalized.as_str()) {
+        Ok(normalized)
+    } else {
+        Err(format!(
+            "gender_policy must be one of: {}",
+            GENDER_POLICIES.join(", ")
+        ))
+    }
+}
+
+/// Split a comma- or semicolon-separated language list into capitalized names.
+pub fn parse_languages(value: &str) -> Vec<String> {
+    value
+        .split([',', ';'])
+        .map(str::trim)
+        .filter(|language| !language.is_empty())
+        .map(|language| {
+            let lower = language.to_lowercase();
+            let mut chars = lower.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+/// Admin payload for creating a college, or replacing one with `PUT`.
+#[derive(Deserialize)]
+pub struct CollegeInput {
+    pub name: String,
+    pub category: String,
+    pub district: String,
+    pub city: String,
+    pub r#type: String,
+    #[serde(default)]
+    pub autonomous: bool,
+    #[serde(default)]
+    pub minority: bool,
+    #[serde(default)]
+    pub hostel_available: bool,
+    pub established_year: Option<i32>,
+    pub university_id: Option<i32>,
+    pub gender_policy: Option<String>,
+    #[serde(default)]
+    pub instruction_languages: Vec<String>,
+    pub phone: Option<String>,
+    pub email: Option<String>,
+    pub website: Option<String>,
+    pub address: Option<String>,
+    pub pincode: Option<String>,
+}
+
+// Distinguish a field explicitly set to null (Some(None)) from one left out (None)
+pub(crate) fn deserialize_nullable<'de, T, D>(
+    deserializer: D,
+) -> Result<Option<Option<T>>, D::Error>
+where
+    T: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    Option::<T>::deserialize(deserializer).map(Some)
+}
+
+/// Admin payload for `PATCH`: only the fields present are changed, and nullable
+/// fields can be cleared with an explicit `null`.
+#[derive(Deserialize, Default)]
+pub struct CollegePatch {
+    pub name: Option<String>,
+    pub category: Option<String>,
+    pub district: Option<String>,
+    pub city: Option<String>,
+    pub r#type: Option<String>,
+    pub autonomous: Option<bool>,
+    pub minority: Option<bool>,
+    pub hostel_available: Option<bool>,
+    #[serde(default, deserialize_with = "deserialize_nullable")]
+    pub established_year: Option<Option<i32>>,
+    #[serde(default, deserialize_with = "deserialize_nullable")]
+    pub university_id: Option<Option<i32>>,
+    pub gender_policy: Option<String>,
+    pub instruction_languages: Option<Vec<String>>,
+    #[serde(default, deserialize_with = "deserialize_nullable")]
+    pub phone: Option<Option<String>>,
+    #[serde(default, deserialize_with = "deserialize_nullable")]
+    pub email: Option<Option<String>>,
+    #[serde(default, deserialize_with = "deserialize_nullable")]
+    pub website: Option<Option<String>>,
+    #[serde(default, deserialize_with = "deserialize_nullable")]
+    pub address: Option<Option<String>>,
+    #[serde(default, deserialize_with = "deserialize_nullable")]
+    pub pincode: Option<Option<String>>,
+}
+
+impl From<CollegeInput> for CollegePatch {
+    fn from(input: CollegeInput) -> Self {
+        CollegePatch {
+            name: Some(input.name),
+            category: Some(input.category),
+            district: Some(input.district),
+            city: Some(input.city),
+            r#type: Some(input.r#type),
+            autonomous: Some(input.autonomous),
+            minority: Some(input.minority),
+            hostel_available: Some(input.hostel_available),
+            established_year: Some(input.established_year),
+            university_id: Some(input.university_id),
+            gender_policy: Some(input.gender_policy.unwrap_or_else(|| "co-ed".to_string())),
+            instruction_languages: Some(input.instruction_languages),
+            phone: Some(input.phone),
+            email: Some(input.email),
+            website: Some(input.website),
+            address: Some(input.address),
+            pincode: Some(input.pincode),
+        }
+    }
+}
+
+// Trim an optional text field, treating blank strings as null
+fn clean(value: Option<Option<String>>) -> Option<Option<String>> {
+    value.map(|v| v.map(|s| s.trim().to_string()).filter(|s| !s.is_empty()))
+}
+
+fn require_text(field: &str, value: &mut Option<String>, max_len: usize) -> Result<(), String> {
+    if let Some(text) = value {
+        *text = text.trim().to_string();
+        if text.is_empty() {
+            return Err(format!("{} must not be empty", field));
+        }
+        if text.len() > max_len {
+            return Err(format!("{} must be at most {} characters", field, max_len));
+        }
+    }
+    Ok(())
+}
+
+pub(crate) fn validate_phone(phone: &str) -> Result<(), String> {
+    let valid = phone.len() <= 20
+        && phone
+            .chars()
+            .all(|c| c.is_ascii_digit() || matches!(c, '+' | '-' | ' ' | '(' | ')'));
+    if !valid {
+        return Err("phone must be at most 20 digits, spaces, '+', '-' or parentheses".to_string());
+    }
+    Ok(())
+}
+
+pub(crate) fn validate_email(email: &str) -> Result<(), String> {
+    let valid = email.len() <= 255
+        && !email.contains(char::is_whitespace)
+        && email
+            .split_once('@')
+            .is_some_and(|(user, domain)| !user.is_empty() && domain.contains('.'));
+    if !valid {
+        return Err(format!("email '{}' is not a valid address", email));
+    }
+    Ok(())
+}
+
+impl CollegePatch {
+    /// Trim and normalize the provided fields, rejecting invalid values.
+    pub fn normalize(mut self) -> Result<Self, String> {
+        require_text("name", &mut self.name, 255)?;
+        require_text("category", &mut self.category, 50)?;
+        require_text("district", &mut self.district, 100)?;
+        require_text("city", &mut self.city, 100)?;
+        require_text("type", &mut self.r#type, 50)?;
+
+        if let Some(Some(year)) = self.established_year {
+            let current_year = chrono::Utc::now().year();
+            if !(1800..=current_year).contains(&year) {
+                return Err(format!(
+                    "established_year must be between 1800 and {}",
+                    current_year
+                ));
+            }
+        }
+
+        if let Some(policy) = &self.gender_policy {
+            self.gender_policy = Some(parse_gender_policy(policy)?);
+        }
+        if let Some(languages) = &self.instruction_languages {
+            self.instruction_languages = Some(parse_languages(&languages.join(",")));
+        }
+
+        self.phone = clean(self.phone);
+        self.email = clean(self.email);
+        self.website = clean(self.website);
+        self.address = clean(self.address);
+        self.pincode = clean(self.pincode);
+
+        if let Some(Some(phone)) = &self.phone {
+            validate_phone(phone)?;
+        }
+        if let Some(Some(email)) = &self.email {
+            validate_email(email)?;
+        }
+        if let Some(Some(website)) = &self.website {
+            if website.len() > 255
+                || !(website.starts_with("http://") || website.starts_with("https://"))
+            {
+                return Err("website must be an http:// or https:// URL".to_string());
+            }
+        }
+        if let Some(Some(pincode)) = &self.pincode {
+            let valid = pincode.len() == 6
+                && pincode.chars().all(|c| c.is_ascii_digit())
+                && !pincode.starts_with('0');
+            if !valid {
+                return Err("pincode must be a 6-digit Indian PIN code".to_string());
+            }
+        }
+
+        Ok(self)
+    }
+
+    pub fn has_contact_changes(&self) -> bool {
+        self.phone.is_some()
+            || self.email.is_some()
+            || self.website.is_some()
+            || self.address.is_some()
+            || self.pincode.is_some()
+    }
+}
+
+/// Records that reference a college and are removed when it is purged.
+#[derive(Serialize, FromRow)]
+pub struct CollegeDependents {
+    pub contact_info: i64,
+    pub cutoffs: i64,
+    pub fee_structures: i64,
+    pub placement_stats: i64,
+    pub naac_accreditations: i64,
+    pub nba_accreditations: i64,
+    pub nirf_rankings: i64,
+    pub facilities: i64,
+    pub hostels: i64,
+    pub images: i64,
+    pub attachments: i64,
+}
+
+/// Response for a college deletion. The college stays restorable until it is
+/// purged, which is possible from `purge_after`.
+#[derive(Serialize)]
+pub struct CollegeDeletion {
+    pub id: i32,
+    pub name: String,
+    pub deleted_at: DateTime<Utc>,
+    pub purge_after: DateTime<Utc>,
+}
+
+/// A deleted college awaiting restoration or purging.
+#[derive(Serialize, FromRow)]
+pub struct DeletedCollege {
+    pub id: i32,
+    pub name: String,
+    pub district: String,
+    pub city: String,
+    pub deleted_at: DateTime<Utc>,
+    pub deleted_by: Option<i32>,
+    pub deleted_by_username: Option<String>,
+    pub purge_after: DateTime<Utc>,
+}
+
+/// Response for a purge, listing the dependents that were removed with the college.
+#[derive(Serialize)]
+pub struct CollegePurge {
+    pub id: i32,
+    pub name: String,
+    pub deleted: CollegeDependents,
+}
@@ -10,4 +10,58 @@ pub struct CollegeImage {
     pub alt_text: Option<String>,
     pub is_primary: bool,
     pub uploaded_at: Option<DateTime<Utc>>,
+    pub content_hash: Option<String>,
+    pub width: Option<i32>,
+    pub height: Option<i32>,
+    /// False while an image uploaded by a college representative awaits review
+    pub published: bool,
+}
+
+#[derive(Serialize, Deserialize, FromRow)]
+pub struct ImageRendition {
+    pub id: i32,
+    pub image_id: i32,
+    pub variant: String,
+    pub width: i32,
+    pub height: i32,
+    pub byte_size: i32,
+    #[serde(skip_serializing)]
+    pub file_path: String,
+    pub created_at: Option<DateTime<Utc>>,
+}
+
+/// Gallery entry: an image with its generated renditions.
+#[derive(Serialize)]
+pub struct CollegeImageWithRenditions {
+    #[serde(flatten)]
+    pub image: CollegeImage,
+    pub renditions: Vec<ImageRendition>,
+}
+
+/// Admin payload for editing an image's alt text or making it the primary image.
+#[derive(Deserialize)]
+pub struct CollegeImageUpdate {
+    pub alt_text: Option<String>,
+    pub is_primary: Option<bool>,
+}
+
+impl CollegeImageUpdate {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.alt_text.as_ref().is_some_and(|alt| alt.len() > 255) {
+            return Err("alt_text must be at most 255 characters".to_string());
+        }
+        if self.is_primary == Some(false) {
+            return Err(
+                "is_primary can only be set to true; mark another image primary instead"
+                    .to_string(),
+            );
+        }
+        Ok(())
+    }
+}
+
+#[derive(Deserialize)]
+pub struct MediaQuery {
+    /// Desired display width in pixels
+    pub w: Option<u32>,
 }
@@ -0,0 +1,169 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+/// Fee components a concession rule can reduce.
+pub const FEE_COMPONENTS: [&str; 4] = ["tuition", "development", "exam", "hostel"];
+
+#[derive(Serialize, Deserialize, FromRow)]
+pub struct ConcessionRule {
+    pub id: i32,
+    pub name: String,
+    pub description: Option<String>,
+    pub academic_year: Option<i32>,
+    pub categories: Vec<String>,
+    pub max_family_income: Option<i32>,
+    pub requires_hostel: bool,
+    pub component: String,
+    pub percentage: i32,
+    pub max_amount: Option<i32>,
+    pub active: bool,
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl ConcessionRule {
+    /// Whether a student with this profile qualifies for the rule.
+    pub fn applies_to(
+        &self,
+        category: &str,
+        income_band: Option<IncomeBand>,
+        hostel: bool,
+    ) -> bool {
+        let category_matches = self.categories.is_empty()
+            || self
+                .categories
+                .iter()
+                .any(|c| c.eq_ignore_ascii_case(category));
+
+        let income_matches = match self.max_family_income {
+            None => true,
+            Some(cap) => income_band
+                .and_then(IncomeBand::upper_limit)
+                .is_some_and(|limit| limit <= cap),
+        };
+
+        category_matches && income_matches && (hostel || !self.requires_hostel)
+    }
+
+    /// Yearly concession on a component amount, honouring the rule's cap.
+    pub fn concession_on(&self, amount: i32) -> i32 {
+        let concession = (i64::from(amount) * i64::from(self.percentage) / 100) as i32;
+        match self.max_amount {
+            Some(cap) => concession.min(cap),
+            None => concession,
+        }
+    }
+}
+
+/// Admin payload for creating or replacing a concession rule.
+#[derive(Deserialize)]
+pub struct ConcessionRuleInput {
+    pub name: String,
+    pub description: Option<String>,
+    pub academic_year: Option<i32>,
+    #[serde(default)]
+    pub categories: Vec<String>,
+    pub max_family_income: Option<i32>,
+    #[serde(default)]
+    pub requires_hostel: bool,
+    pub component: String,
+    pub percentage: i32,
+    pub max_amount: Option<i32>,
+    pub active: Option<bool>,
+}
+
+impl ConcessionRuleInput {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.name.trim().is_empty() {
+            return Err("name is required".to_string());
+        }
+        if !FEE_COMPONENTS.contains(&self.component.as_str()) {
+            return Err(format!(
+                "component must be one of: {}",
+                FEE_COMPONENTS.join(", ")
+            ));
+        }
+        if !(0..=100).contains(&self.percentage) {
+            return Err("percentage must be between 0 and 100".to_string());
+        }
+        if self.max_amount.is_some_and(|cap| cap < 0)
+            || self.max_family_income.is_some_and(|cap| cap < 0)
+        {
+            return Err("amounts cannot be negative".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// Annual family income bands accepted by the cost estimate.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IncomeBand {
+    #[serde(rename = "below_1l")]
+    Below1Lakh,
+    #[serde(rename = "1l_2.5l")]
+    UpTo2_5Lakh,
+    #[serde(rename = "2.5l_8l")]
+    UpTo8Lakh,
+    #[serde(rename = "above_8l")]
+    Above8Lakh,
+}
+
+impl IncomeBand {
+    /// Highest family income (in rupees) in the band, `None` when unbounded.
+    pub fn upper_limit(self) -> Option<i32> {
+        match self {
+            IncomeBand::Below1Lakh => Some(100_000),
+            IncomeBand::UpTo2_5Lakh => Some(250_000),
+            IncomeBand::UpTo8Lakh => Some(800_000),
+            IncomeBand::Above8Lakh => None,
+        }
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct CostEstimateQuery {
+    pub course: Option<String>,
+    pub category: Option<String>,
+    pub income_band: Option<IncomeBand>,
+    pub hostel: Option<bool>,
+    /// Course duration in years, defaults to 4.
+    pub duration: Option<i32>,
+}
+
+#[derive(Serialize)]
+pub struct AppliedConcession {
+    pub rule_id: i32,
+    pub name: String,
+    pub component: String,
+    pub percentage: i32,
+    pub amount_per_year: i32,
+}
+
+#[derive(Serialize)]
+pub struct YearCost {
+    pub year_of_study: i32,
+    pub tuition_fee: i32,
+    pub development_fee: i32,
+    pub exam_fee: i32,
+    pub hostel_fee: i32,
+    pub gross: i32,
+    pub concession: i32,
+    pub net: i32,
+}
+
+#[derive(Serialize)]
+pub struct CostEstimate {
+    pub college_id: i32,
+    pub course: String,
+    pub fee_year: i32,
+    pub category: String,
+    pub income_band: Option<IncomeBand>,
+    pub hostel: bool,
+    pub published_category_fee: i32,
+    pub concessions: Vec<AppliedConcession>,
+    pub years: Vec<YearCost>,
+    pub total_gross: i32,
+    pub total_concession: i32,
+    pub total_net: i32,
+}
@@ -1,3 +1,4 @@
+use crate::models::college::{deserialize_nullable, CollegePatch};
 use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
 
@@ -11,3 +12,67 @@ pub struct ContactInfo {
     pub website: Option<String>,
     pub pincode: Option<String>,
 }
+
+/// Contact details proposed by a college representative. Only the fields present
+/// are changed, and an explicit `null` clears a field.
+#[derive(Serialize, Deserialize, Default)]
+pub struct ContactChange {
+    #[serde(
+        default,
+        deserialize_with = "deserialize_nullable",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub phone: Option<Option<String>>,
+    #[serde(
+        default,
+        deserialize_with = "deserialize_nullable",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub email: Option<Option<String>>,
+    #[serde(
+        default,
+        deserialize_with = "deserialize_nullable",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub website: Option<Option<String>>,
+    #[serde(
+        default,
+        deserialize_with = "deserialize_nullable",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub address: Option<Option<String>>,
+    #[serde(
+        default,
+        deserialize_with = "deserialize_nullable",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub pincode: Option<Option<String>>,
+}
+
+impl ContactChange {
+    /// Trim and validate the provided fields with the same rules as college edits.
+    pub fn normalize(self) -> Result<Self, String> {
+        let patch = self.into_patch().normalize()?;
+        if !patch.has_contact_changes() {
+            return Err("no contact fields were provided".to_string());
+        }
+        Ok(ContactChange {
+            phone: patch.phone,
+            email: patch.email,
+            website: patch.website,
+            address: patch.address,
+            pincode: patch.pincode,
+        })
+    }
+
+    pub fn into_patch(self) -> CollegePatch {
+        CollegePatch {
+            phone: self.phone,
+            email: self.email,
+            website: self.website,
+            address: self.address,
+            pincode: self.pincode,
+            ..Default::default()
+        }
+    }
+}
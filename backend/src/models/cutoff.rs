@@ -1,4 +1,4 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Datelike, Utc};
 use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
 
@@ -13,3 +13,76 @@ pub struct Cutoff {
     pub pdf_url: Option<String>,
     pub created_at: Option<DateTime<Utc>>,
 }
+
+/// A cutoff row proposed by a college representative.
+#[derive(Serialize, Deserialize)]
+pub struct CutoffInput {
+    pub year: i32,
+    pub branch: Option<String>,
+    pub category: Option<String>,
+    pub cutoff_marks: Option<f64>,
+    pub pdf_url: Option<String>,
+}
+
+impl CutoffInput {
+    pub fn validate(&self) -> Result<(), String> {
+        let current_year = Utc::now().year();
+        if !(2000..=current_year + 1).contains(&self.year) {
+            return Err(format!(
+                "year must be between 2000 and {}",
+                current_year + 1
+            ));
+        }
+        if self
+            .branch
+            .as_ref()
+            .is_some_and(|branch| branch.len() > 100)
+        {
+            return Err("branch must be at most 100 characters".to_string());
+        }
+        if self
+            .category
+            .as_ref()
+            .is_some_and(|category| category.len() > 20)
+        {
+            return Err("category must be at most 20 characters".to_string());
+        }
+        if let Some(marks) = self.cutoff_marks {
+            if !(0.0..1000.0).contains(&marks) {
+                return Err("cutoff_marks must be between 0 and 999.99".to_string());
+            }
+        }
+        if let Some(url) = &self.pdf_url {
+            if url.len() > 500 || !(url.starts_with("http://") || url.starts_with("https://")) {
+                return Err("pdf_url must be an http:// or https:// URL".to_string());
+            }
+        }
+        Ok(())
+    }
+}
+
+/// One cutoff row from a CSV or spreadsheet upload. A row replaces the cutoff recorded
+/// for the same college, year, branch and category.
+#[derive(Deserialize)]
+pub struct CutoffImport {
+    pub college_id: i32,
+    pub year: i32,
+    pub branch: Option<String>,
+    pub category: Option<String>,
+    pub cutoff_marks: Option<f64>,
+    pub pdf_url: Option<String>,
+}
+
+impl CutoffImport {
+    /// The college and its cutoff, validated like a representative's.
+    pub fn to_input(&self) -> (i32, CutoffInput) {
+        let cutoff = CutoffInput {
+            year: self.year,
+            branch: self.branch.clone(),
+            category: self.category.clone(),
+            cutoff_marks: self.cutoff_marks,
+            pdf_url: self.pdf_url.clone(),
+        };
+        (self.college_id, cutoff)
+    }
+}
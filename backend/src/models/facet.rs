@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+#[derive(Serialize, Deserialize, FromRow)]
+pub struct FacetCount {
+    pub value: String,
+    pub count: i64,
+}
+
+/// Value counts for each filterable college attribute.
+#[derive(Serialize)]
+pub struct Facets {
+    pub districts: Vec<FacetCount>,
+    pub categories: Vec<FacetCount>,
+    pub college_types: Vec<FacetCount>,
+    pub gender_policies: Vec<FacetCount>,
+    pub instruction_languages: Vec<FacetCount>,
+}
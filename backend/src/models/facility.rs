@@ -0,0 +1,52 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+#[derive(Serialize, Deserialize, FromRow)]
+pub struct Facility {
+    pub id: i32,
+    pub slug: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub created_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Serialize, Deserialize, FromRow)]
+pub struct FacilityWithCount {
+    pub id: i32,
+    pub slug: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub college_count: i64,
+}
+
+/// Admin payload for creating or renaming a facility.
+#[derive(Deserialize)]
+pub struct FacilityInput {
+    pub slug: String,
+    pub name: String,
+    pub description: Option<String>,
+}
+
+impl FacilityInput {
+    pub fn validate(&self) -> Result<(), String> {
+        let slug_ok = !self.slug.is_empty()
+            && self
+                .slug
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_');
+        if !slug_ok {
+            return Err("slug must be lowercase letters, digits or underscores".to_string());
+        }
+        if self.name.trim().is_empty() {
+            return Err("name is required".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// Admin payload replacing the facilities linked to a college.
+#[derive(Deserialize)]
+pub struct CollegeFacilitiesInput {
+    pub facilities: Vec<String>,
+}
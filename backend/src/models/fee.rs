@@ -0,0 +1,85 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+#[derive(Serialize, Deserialize, FromRow)]
+pub struct FeeStructure {
+    pub id: i32,
+    pub college_id: i32,
+    pub course: String,
+    pub year: i32,
+    pub tuition_fee: i32,
+    pub development_fee: i32,
+    pub exam_fee: i32,
+    pub hostel_fee: Option<i32>,
+    pub total_fee: i32,
+    pub open_fee: Option<i32>,
+    pub obc_fee: Option<i32>,
+    pub sc_fee: Option<i32>,
+    pub st_fee: Option<i32>,
+    pub ews_fee: Option<i32>,
+    pub tfws_fee: Option<i32>,
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl FeeStructure {
+    /// Annual fee payable by an admission category (OPEN, OBC, SC, ST, EWS, TFWS),
+    /// falling back to the full fee when no category-specific amount is recorded.
+    pub fn fee_for_category(&self, category: &str) -> i32 {
+        let amount = match category.to_uppercase().as_str() {
+            "OPEN" => self.open_fee,
+            "OBC" => self.obc_fee,
+            "SC" => self.sc_fee,
+            "ST" => self.st_fee,
+            "EWS" => self.ews_fee,
+            "TFWS" => self.tfws_fee,
+            _ => None,
+        };
+        amount.unwrap_or(self.total_fee)
+    }
+}
+
+/// One fee row from a CSV upload, or a course proposed by a college representative.
+#[derive(Serialize, Deserialize)]
+pub struct FeeImport {
+    pub college_id: i32,
+    pub course: String,
+    pub year: i32,
+    pub tuition_fee: Option<i32>,
+    pub development_fee: Option<i32>,
+    pub exam_fee: Option<i32>,
+    pub hostel_fee: Option<i32>,
+    pub open_fee: Option<i32>,
+    pub obc_fee: Option<i32>,
+    pub sc_fee: Option<i32>,
+    pub st_fee: Option<i32>,
+    pub ews_fee: Option<i32>,
+    pub tfws_fee: Option<i32>,
+}
+
+impl FeeImport {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.course.trim().is_empty() {
+            return Err("course is required".to_string());
+        }
+
+        let amounts = [
+            self.tuition_fee,
+            self.development_fee,
+            self.exam_fee,
+            self.hostel_fee,
+            self.open_fee,
+            self.obc_fee,
+            self.sc_fee,
+            self.st_fee,
+            self.ews_fee,
+            self.tfws_fee,
+        ];
+        if amounts.iter().flatten().any(|amount| *amount < 0) {
+            return Err("fee amounts cannot be negative".to_string());
+        }
+
+        Ok(())
+    }
+}
@@ -1,28 +1,132 @@
-use serde::Deserialize;
+use crate::models::accreditation::NaacGrade;
+use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
+use serde::{Deserialize, Deserializer};
+use std::str::FromStr;
 
 #[derive(Deserialize, Debug)]
 pub struct CollegeFilters {
     pub district: Option<String>,
     pub category: Option<String>,
     pub college_type: Option<String>,
+    /// co-ed, women-only or men-only
+    pub gender_policy: Option<String>,
+    /// Language of instruction, e.g. Marathi
+    pub language: Option<String>,
     pub autonomous: Option<bool>,
     pub hostel_available: Option<bool>,
+    pub girls_hostel: Option<bool>,
+    /// Maximum annual hostel fee; combined with `girls_hostel` it applies to the girls' hostel.
+    pub max_hostel_fee: Option<i32>,
+    /// University short name (SPPU) or full name.
+    pub university: Option<String>,
+    /// Comma-separated facility slugs; a college must offer all of them.
+    pub facilities: Option<String>,
+    pub max_fee: Option<i32>,
+    pub naac_min: Option<NaacGrade>,
+    pub nirf_max: Option<i32>,
+    /// Minimum overall placement percentage in the latest placement year.
+    pub min_placement_rate: Option<f64>,
     pub search: Option<String>,
+    pub sort: Option<SortBy>,
+    /// List colleges as they were at this time. Only college fields are historical;
+    /// filters on fees, hostels, facilities, rankings and placements use current data.
+    pub as_of: Option<AsOf>,
     pub page: Option<u32>,
     pub limit: Option<u32>,
 }
 
+/// Ordering for the college listing (`sort=` query parameter).
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SortBy {
+    Name,
+    /// Lowest annual fee (latest academic year) first.
+    Fee,
+    /// Best NIRF rank (latest ranking year) first.
+    Nirf,
+    /// Highest overall placement rate (latest placement year) first.
+    Placement,
+}
+
 impl Default for CollegeFilters {
     fn default() -> Self {
         Self {
             district: None,
             category: None,
             college_type: None,
+            gender_policy: None,
+            language: None,
             autonomous: None,
             hostel_available: None,
+            girls_hostel: None,
+            max_hostel_fee: None,
+            university: None,
+            facilities: None,
+            max_fee: None,
+            naac_min: None,
+            nirf_max: None,
+            min_placement_rate: None,
             search: None,
+            sort: None,
+            as_of: None,
             page: Some(1),
             limit: Some(10),
         }
     }
 }
+
+impl CollegeFilters {
+    /// Facility slugs requested through `facilities=lab,library`, each listed once.
+    pub fn facility_slugs(&self) -> Vec<String> {
+        let mut slugs: Vec<String> = self
+            .facilities
+            .as_deref()
+            .unwrap_or_default()
+            .split(',')
+            .map(|slug| slug.trim().to_lowercase())
+            .filter(|slug| !slug.is_empty())
+            .collect();
+        slugs.sort();
+        slugs.dedup();
+        slugs
+    }
+}
+
+/// A point in time for historical queries (`as_of=`): either a date, meaning the end
+/// of that day in UTC, or an RFC 3339 timestamp.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AsOf(pub DateTime<Utc>);
+
+impl FromStr for AsOf {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let value = value.trim();
+        if let Ok(date) = NaiveDate::parse_from_str(value, "%Y-%m-%d") {
+            let end_of_day =
+                NaiveTime::from_hms_micro_opt(23, 59, 59, 999_999).expect("valid time of day");
+            return Ok(AsOf(date.and_time(end_of_day).and_utc()));
+        }
+        DateTime::parse_from_rfc3339(value)
+            .map(|at| AsOf(at.with_timezone(&Utc)))
+            .map_err(|_| {
+                format!(
+                    "as_of must be a date (YYYY-MM-DD) or an RFC 3339 timestamp, got '{}'",
+                    value
+                )
+            })
+    }
+}
+
+impl<'de> Deserialize<'de> for AsOf {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        value.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+/// Query parameters for endpoints that can show a college as it was in the past.
+#[derive(Deserialize, Debug, Default)]
+pub struct HistoryQuery {
+    pub as_of: Option<AsOf>,
+}
@@ -0,0 +1,21 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+#[derive(Serialize, Deserialize, FromRow)]
+pub struct Hostel {
+    pub id: i32,
+    pub college_id: i32,
+    /// `boys` or `girls`.
+    pub hostel_type: String,
+    pub capacity: Option<i32>,
+    /// Rupees per year.
+    pub annual_fee: Option<i32>,
+    pub mess_available: Option<bool>,
+    /// Distance from the main campus in kilometres.
+    pub distance_km: Option<f64>,
+    /// Whether first-year students are guaranteed a room.
+    pub first_year_guaranteed: Option<bool>,
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
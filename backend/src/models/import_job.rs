@@ -0,0 +1,65 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+/// Job states; queued and running jobs are resumed when the server restarts.
+pub const JOB_STATUSES: [&str; 5] = ["queued", "running", "completed", "failed", "cancelled"];
+
+/// A background college import. `processed_rows`, `inserted` and `updated` are
+/// updated after every batch; `total_rows` is counted when the file is uploaded.
+#[derive(Serialize, FromRow, Clone)]
+pub struct ImportJob {
+    pub id: i32,
+    /// colleges_csv, colleges_json, colleges_spreadsheet, cutoffs_csv or cutoffs_spreadsheet
+    pub kind: String,
+    pub status: String,
+    pub atomic: bool,
+    /// For spreadsheets, the sheet imported and the row holding its column names
+    pub sheet: Option<String>,
+    pub header_row: Option<i32>,
+    pub total_rows: Option<i32>,
+    pub processed_rows: i32,
+    pub inserted: i32,
+    pub updated: i32,
+    pub error_count: i32,
+    pub message: Option<String>,
+    pub cancel_requested: bool,
+    pub created_by: Option<i32>,
+    pub created_at: DateTime<Utc>,
+    pub started_at: Option<DateTime<Utc>>,
+    pub finished_at: Option<DateTime<Utc>>,
+}
+
+impl ImportJob {
+    pub fn is_finished(&self) -> bool {
+        matches!(self.status.as_str(), "completed" | "failed" | "cancelled")
+    }
+}
+
+/// A job with the rows it rejected, in file order, for `/api/admin/jobs/:id`.
+#[derive(Serialize)]
+pub struct ImportJobDetail {
+    #[serde(flatten)]
+    pub job: ImportJob,
+    pub errors: Vec<String>,
+}
+
+/// Query parameters for `/api/admin/jobs`.
+#[derive(Deserialize)]
+pub struct JobFilters {
+    pub status: Option<String>,
+}
+
+impl JobFilters {
+    pub fn validate(&self) -> Result<(), String> {
+        if let Some(status) = &self.status {
+            if !JOB_STATUSES.contains(&status.as_str()) {
+                return Err(format!(
+                    "status must be one of: {}",
+                    JOB_STATUSES.join(", ")
+                ));
+            }
+        }
+        Ok(())
+    }
+}
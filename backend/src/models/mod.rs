@@ -1,12 +1,39 @@
+pub mod accreditation;
+pub mod admin_user;
 pub mod admission_requirement;
+pub mod attachment;
+pub mod audit;
 pub mod college;
 pub mod college_image;
+pub mod concession;
 pub mod contact_info;
 pub mod cutoff;
+pub mod facet;
+pub mod facility;
+pub mod fee;
 pub mod filters;
+pub mod hostel;
+pub mod import_job;
+pub mod placement;
+pub mod representative;
+pub mod scholarship;
+pub mod university;
 
+pub use accreditation::{Accreditation, NaacGrade};
+pub use admin_user::AdminUser;
 pub use admission_requirement::AdmissionRequirement;
+pub use attachment::Attachment;
 pub use college::College;
+pub use college_image::{CollegeImage, CollegeImageWithRenditions, ImageRendition};
+pub use concession::{ConcessionRule, CostEstimate, CostEstimateQuery};
 // pub use college::CollegeWithContact; // Comment out or remove this line
 pub use cutoff::Cutoff;
-pub use filters::CollegeFilters;
+pub use facet::{FacetCount, Facets};
+pub use facility::{Facility, FacilityWithCount};
+pub use fee::FeeStructure;
+pub use filters::{AsOf, CollegeFilters, HistoryQuery, SortBy};
+pub use hostel::Hostel;
+pub use import_job::{ImportJob, ImportJobDetail};
+pub use placement::PlacementStat;
+pub use scholarship::{Scholarship, ScholarshipLink, StudentProfile};
+pub use university::{University, UniversityWithCount};
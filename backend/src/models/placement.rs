@@ -0,0 +1,22 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+#[derive(Serialize, Deserialize, FromRow)]
+pub struct PlacementStat {
+    pub id: i32,
+    pub college_id: i32,
+    pub year: i32,
+    pub branch: String,
+    pub students_eligible: i32,
+    pub students_placed: i32,
+    /// Percentage of eligible students placed.
+    pub placement_rate: Option<f64>,
+    /// Median package in lakhs per annum.
+    pub median_package: Option<f64>,
+    /// Highest package in lakhs per annum.
+    pub highest_package: Option<f64>,
+    pub top_recruiters: Vec<String>,
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
@@ -0,0 +1,177 @@
+use crate::models::admin_user::{validate_password, validate_username};
+use crate::models::college::{validate_email, validate_phone};
+use crate::models::contact_info::ContactChange;
+use crate::models::cutoff::CutoffInput;
+use crate::models::fee::FeeImport;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+/// Review states shared by claims and submissions.
+pub const REVIEW_STATUSES: [&str; 3] = ["pending", "approved", "rejected"];
+
+/// A request to manage a college profile; approving it creates a representative account.
+#[derive(Serialize, FromRow)]
+pub struct RepresentativeClaim {
+    pub id: i32,
+    pub college_id: i32,
+    pub college_name: String,
+    pub username: String,
+    pub full_name: String,
+    pub designation: Option<String>,
+    pub email: String,
+    pub phone: Option<String>,
+    pub message: Option<String>,
+    pub status: String,
+    pub review_note: Option<String>,
+    pub reviewed_by: Option<i32>,
+    pub reviewed_at: Option<DateTime<Utc>>,
+    pub user_id: Option<i32>,
+    pub created_at: Option<DateTime<Utc>>,
+}
+
+/// Public payload for claiming a college profile.
+#[derive(Deserialize)]
+pub struct ClaimInput {
+    pub username: String,
+    pub password: String,
+    pub full_name: String,
+    pub designation: Option<String>,
+    pub email: String,
+    pub phone: Option<String>,
+    /// How the claim can be verified, e.g. an official email address or phone number
+    pub message: Option<String>,
+}
+
+impl ClaimInput {
+    pub fn validate(&self) -> Result<(), String> {
+        validate_username(&self.username)?;
+        validate_password(&self.password)?;
+        let full_name = self.full_name.trim();
+        if full_name.is_empty() || full_name.len() > 255 {
+            return Err("full_name must be between 1 and 255 characters".to_string());
+        }
+        if self
+            .designation
+            .as_ref()
+            .is_some_and(|designation| designation.trim().len() > 255)
+        {
+            return Err("designation must be at most 255 characters".to_string());
+        }
+        validate_email(self.email.trim())?;
+        if let Some(phone) = &self.phone {
+            validate_phone(phone.trim())?;
+        }
+        if self
+            .message
+            .as_ref()
+            .is_some_and(|message| message.len() > 2000)
+        {
+            return Err("message must be at most 2000 characters".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// Reviewer payload for approving or rejecting a claim or submission.
+#[derive(Deserialize, Default)]
+pub struct ReviewDecision {
+    pub note: Option<String>,
+}
+
+/// Query parameters for the claim and submission queues.
+#[derive(Deserialize)]
+pub struct ReviewFilters {
+    pub status: Option<String>,
+    pub college_id: Option<i32>,
+}
+
+impl ReviewFilters {
+    pub fn validate(&self) -> Result<(), String> {
+        match &self.status {
+            Some(status) if !REVIEW_STATUSES.contains(&status.as_str()) => Err(format!(
+                "status must be one of: {}",
+                REVIEW_STATUSES.join(", ")
+            )),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// A change proposed by a college representative, applied once approved.
+#[derive(Serialize, FromRow)]
+pub struct CollegeSubmission {
+    pub id: i32,
+    pub college_id: i32,
+    pub college_name: String,
+    pub submitted_by: Option<i32>,
+    pub submitted_by_username: Option<String>,
+    pub kind: String,
+    pub changes: sqlx::types::Json<serde_json::Value>,
+    pub status: String,
+    pub review_note: Option<String>,
+    pub reviewed_by: Option<i32>,
+    pub reviewed_at: Option<DateTime<Utc>>,
+    pub created_at: Option<DateTime<Utc>>,
+}
+
+/// The changes carried by a submission, stored in `college_submissions.changes`.
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "kind", content = "changes", rename_all = "snake_case")]
+pub enum SubmissionChanges {
+    ContactInfo(ContactChange),
+    /// Fee structures per course and year, upserted on (course, year)
+    Courses(Vec<FeeImport>),
+    /// Cutoff rows; each replaces the cutoff recorded for its year, branch and category
+    Cutoffs(Vec<CutoffInput>),
+    /// An uploaded image that stays unpublished until approval
+    Image {
+        image_id: i32,
+    },
+}
+
+impl SubmissionChanges {
+    pub fn kind(&self) -> &'static str {
+        match self {
+            SubmissionChanges::ContactInfo(_) => "contact_info",
+            SubmissionChanges::Courses(_) => "courses",
+            SubmissionChanges::Cutoffs(_) => "cutoffs",
+            SubmissionChanges::Image { .. } => "image",
+        }
+    }
+
+    /// Validate and normalize the changes for `college_id`.
+    pub fn normalize(self, college_id: i32) -> Result<Self, String> {
+        Ok(match self {
+            SubmissionChanges::ContactInfo(change) => {
+                SubmissionChanges::ContactInfo(change.normalize()?)
+            }
+            SubmissionChanges::Courses(mut fees) => {
+                if fees.is_empty() {
+                    return Err("at least one course is required".to_string());
+                }
+                for (i, fee) in fees.iter_mut().enumerate() {
+                    fee.college_id = college_id;
+                    fee.course = fee.course.trim().to_string();
+                    fee.validate()
+                        .map_err(|e| format!("course {}: {}", i + 1, e))?;
+                }
+                SubmissionChanges::Courses(fees)
+            }
+            SubmissionChanges::Cutoffs(cutoffs) => {
+                if cutoffs.is_empty() {
+                    return Err("at least one cutoff is required".to_string());
+                }
+                for (i, cutoff) in cutoffs.iter().enumerate() {
+                    cutoff
+                        .validate()
+                        .map_err(|e| format!("cutoff {}: {}", i + 1, e))?;
+                }
+                SubmissionChanges::Cutoffs(cutoffs)
+            }
+            SubmissionChanges::Image { .. } => {
+                return Err("images are submitted by uploading them".to_string())
+            }
+        })
+    }
+}
@@ -0,0 +1,131 @@
+use crate::models::concession::IncomeBand;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+#[derive(Serialize, Deserialize, FromRow)]
+pub struct Scholarship {
+    pub id: i32,
+    pub name: String,
+    pub provider: String,
+    pub description: Option<String>,
+    pub url: Option<String>,
+    pub max_amount: Option<i32>,
+    pub categories: Vec<String>,
+    pub max_family_income: Option<i32>,
+    pub gender: Option<String>,
+    pub courses: Vec<String>,
+    pub minority_only: bool,
+    pub requires_domicile: bool,
+    pub active: bool,
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl Scholarship {
+    /// Whether a student profile satisfies every eligibility rule of the scheme.
+    /// Profile attributes that are not supplied never satisfy a restriction.
+    pub fn is_eligible(&self, profile: &StudentProfile) -> bool {
+        let category_ok = self.categories.is_empty()
+            || profile
+                .category
+                .as_deref()
+                .is_some_and(|c| contains_ignore_case(&self.categories, c));
+
+        let income_ok = match self.max_family_income {
+            None => true,
+            Some(cap) => profile
+                .income_band
+                .and_then(IncomeBand::upper_limit)
+                .is_some_and(|limit| limit <= cap),
+        };
+
+        let gender_ok = match self.gender.as_deref() {
+            None => true,
+            Some(required) => profile
+                .gender
+                .as_deref()
+                .is_some_and(|g| g.trim().eq_ignore_ascii_case(required)),
+        };
+
+        let course_ok = self.courses.is_empty()
+            || profile
+                .course
+                .as_deref()
+                .is_some_and(|c| contains_ignore_case(&self.courses, c));
+
+        let minority_ok = !self.minority_only || profile.minority == Some(true);
+        let domicile_ok = !self.requires_domicile || profile.domicile == Some(true);
+
+        category_ok && income_ok && gender_ok && course_ok && minority_ok && domicile_ok
+    }
+}
+
+fn contains_ignore_case(values: &[String], value: &str) -> bool {
+    values.iter().any(|v| v.eq_ignore_ascii_case(value.trim()))
+}
+
+/// Short reference to a scheme, embedded in college detail responses.
+#[derive(Serialize, Deserialize, FromRow)]
+pub struct ScholarshipLink {
+    pub id: i32,
+    pub name: String,
+    pub provider: String,
+    pub url: Option<String>,
+}
+
+/// Student attributes used by `/api/scholarships/match`.
+#[derive(Deserialize, Debug)]
+pub struct StudentProfile {
+    pub category: Option<String>,
+    pub income_band: Option<IncomeBand>,
+    pub gender: Option<String>,
+    /// College category the student is applying to, e.g. Engineering.
+    pub course: Option<String>,
+    pub minority: Option<bool>,
+    /// Holds a Maharashtra domicile certificate.
+    pub domicile: Option<bool>,
+}
+
+/// Admin payload for creating or replacing a scholarship scheme.
+#[derive(Deserialize)]
+pub struct ScholarshipInput {
+    pub name: String,
+    pub provider: String,
+    pub description: Option<String>,
+    pub url: Option<String>,
+    pub max_amount: Option<i32>,
+    #[serde(default)]
+    pub categories: Vec<String>,
+    pub max_family_income: Option<i32>,
+    pub gender: Option<String>,
+    #[serde(default)]
+    pub courses: Vec<String>,
+    #[serde(default)]
+    pub minority_only: bool,
+    #[serde(default)]
+    pub requires_domicile: bool,
+    pub active: Option<bool>,
+}
+
+impl ScholarshipInput {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.name.trim().is_empty() {
+            return Err("name is required".to_string());
+        }
+        if self.provider.trim().is_empty() {
+            return Err("provider is required".to_string());
+        }
+        if let Some(gender) = self.gender.as_deref() {
+            if gender != "female" && gender != "male" {
+                return Err("gender must be 'female' or 'male'".to_string());
+            }
+        }
+        if self.max_amount.is_some_and(|amount| amount < 0)
+            || self.max_family_income.is_some_and(|cap| cap < 0)
+        {
+            return Err("amounts cannot be negative".to_string());
+        }
+        Ok(())
+    }
+}
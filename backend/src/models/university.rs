@@ -0,0 +1,35 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+#[derive(Serialize, Deserialize, FromRow)]
+pub struct University {
+    pub id: i32,
+    pub name: String,
+    pub short_name: String,
+    pub city: Option<String>,
+    pub university_type: Option<String>,
+    pub website: Option<String>,
+    pub created_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Serialize, Deserialize, FromRow)]
+pub struct UniversityWithCount {
+    pub id: i32,
+    pub name: String,
+    pub short_name: String,
+    pub city: Option<String>,
+    pub university_type: Option<String>,
+    pub website: Option<String>,
+    pub college_count: i64,
+}
+
+/// Admin payload for adding a university.
+#[derive(Deserialize)]
+pub struct UniversityInput {
+    pub name: String,
+    pub short_name: String,
+    pub city: Option<String>,
+    pub university_type: Option<String>,
+    pub website: Option<String>,
+}
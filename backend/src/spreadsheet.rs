@@ -0,0 +1,133 @@
+//! Reading uploaded XLSX and ODS workbooks for the importers. Cells are read as text
+//! and deserialized against the header row the same way CSV records are, so a sheet
+//! accepts exactly the columns and values of the matching CSV upload.
+
+use calamine::{open_workbook_auto_from_rs, Data, Reader};
+use csv::StringRecord;
+use serde::de::DeserializeOwned;
+use std::io::Cursor;
+
+/// A row of a sheet: its row number as shown in the spreadsheet (counted from 1) and
+/// the deserialized row or why it was rejected.
+pub type SheetRow<T> = (usize, Result<T, String>);
+
+/// One sheet of a workbook, with its column names and the non-blank rows below them.
+pub struct Sheet {
+    pub name: String,
+    /// Row holding the column names, counted from 1
+    pub header_row: usize,
+    headers: StringRecord,
+    rows: Vec<(usize, StringRecord)>,
+}
+
+impl Sheet {
+    /// Read the sheet called `name`, or the first sheet, from an XLSX, XLS or ODS
+    /// workbook. Column names are taken from row `header_row` (counted from 1); rows
+    /// above it, such as a title, are skipped.
+    pub fn read(data: &[u8], name: Option<&str>, header_row: usize) -> Result<Sheet, String> {
+        let mut workbook = open_workbook_auto_from_rs(Cursor::new(data))
+            .map_err(|e| format!("Invalid spreadsheet: {}", e))?;
+        let sheet_names = workbook.sheet_names();
+        let name = match name {
+            Some(name) => sheet_names
+                .iter()
+                .find(|sheet| sheet.as_str() == name)
+                .ok_or_else(|| {
+                    format!(
+                        "Sheet '{}' not found; the workbook has: {}",
+                        name,
+                        sheet_names.join(", ")
+                    )
+                })?
+                .clone(),
+            None => sheet_names
+                .first()
+                .ok_or_else(|| "The workbook has no sheets".to_string())?
+                .clone(),
+        };
+        let range = workbook
+            .worksheet_range(&name)
+            .map_err(|e| format!("Failed to read sheet '{}': {}", name, e))?;
+
+        // The range covers the used cells only, so it may start below row 1 or right
+        // of column A
+        let first_row = range.start().map_or(0, |(row, _)| row as usize);
+        let mut headers = None;
+        let mut rows = Vec::new();
+        for (index, cells) in range.rows().enumerate() {
+            let row = first_row + index + 1;
+            if row < header_row {
+                continue;
+            }
+            // Numbers print as a CSV export would hold them, e.g. 1990 rather than 1990.0
+            let record: StringRecord = cells.iter().map(Data::to_string).collect();
+            if row == header_row {
+                headers = Some(header_record(&record));
+            } else if record.iter().any(|field| !field.trim().is_empty()) {
+                rows.push((row, record));
+            }
+        }
+
+        let headers = headers
+            .filter(|headers| !headers.is_empty())
+            .ok_or_else(|| format!("Sheet '{}' has no column names in row {}", name, header_row))?;
+        Ok(Sheet {
+            name,
+            header_row,
+            headers,
+            rows,
+        })
+    }
+
+    /// Number of rows below the header that hold any value.
+    pub fn len(&self) -> usize {
+        self.rows.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    /// How rows of this sheet are referred to in import errors.
+    pub fn label(&self) -> String {
+        format!("Sheet '{}' row ", self.name)
+    }
+
+    /// Deserialize every row against the header, in sheet order.
+    pub fn rows<T: DeserializeOwned>(&self) -> impl Iterator<Item = SheetRow<T>> + '_ {
+        self.rows.iter().map(|(row, record)| {
+            // Cells right of the last named column are not part of the table
+            let record: StringRecord = record.iter().take(self.headers.len()).collect();
+            let result = record
+                .deserialize(Some(&self.headers))
+                .map_err(|e| self.describe(e));
+            (*row, result)
+        })
+    }
+
+    // Name the column a value was rejected from, rather than a CSV field number
+    fn describe(&self, error: csv::Error) -> String {
+        match error.kind() {
+            csv::ErrorKind::Deserialize { err, .. } => {
+                match err
+                    .field()
+                    .and_then(|field| self.headers.get(field as usize))
+                {
+                    Some(column) => format!("column '{}': {}", column, err.kind()),
+                    None => err.kind().to_string(),
+                }
+            }
+            _ => error.to_string(),
+        }
+    }
+}
+
+// Column names, without the surrounding spaces spreadsheets often carry and the empty
+// cells after the last one
+fn header_record(record: &StringRecord) -> StringRecord {
+    let mut headers: Vec<&str> = record.iter().map(str::trim).collect();
+    while headers.last().is_some_and(|header| header.is_empty()) {
+        headers.pop();
+    }
+    headers.into_iter().collect()
+}
@@ -2,10 +2,140 @@ use axum::{
     body::Body,
     http::{Request, StatusCode},
 };
-use sqlx::postgres::PgPoolOptions;
+use maharashtra_colleges_backend::{
+    auth::hash_password,
+    create_app_with_pool,
+    csv_stream::CsvStream,
+    handlers::{
+        image::upload_dir,
+        import_job::{import_dir, resume_import_jobs},
+    },
+    models::NaacGrade,
+};
+use sqlx::{postgres::PgPoolOptions, PgPool};
+use std::collections::HashMap;
 use std::env;
 use tower::ServiceExt;
 
+const TEST_ADMIN_PASSWORD: &str = "integration-test-password";
+
+// Create (or reset) an admin account and log in as it, returning the bearer token
+async fn admin_token(app: &axum::Router, pool: &PgPool, username: &str, role: &str) -> String {
+    sqlx::query(
+        r#"
+        INSERT INTO admin_users (username, password_hash, role) VALUES ($1, $2, $3)
+        ON CONFLICT (username) DO UPDATE
+        SET password_hash = EXCLUDED.password_hash, role = EXCLUDED.role, active = TRUE
+        "#,
+    )
+    .bind(username)
+    .bind(hash_password(TEST_ADMIN_PASSWORD).unwrap())
+    .bind(role)
+    .execute(pool)
+    .await
+    .unwrap();
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/api/admin/login")
+                .header("content-type", "application/json")
+                .body(Body::from(
+                    serde_json::json!({ "username": username, "password": TEST_ADMIN_PASSWORD })
+                        .to_string(),
+                ))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let login: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    login["token"].as_str().unwrap().to_string()
+}
+
+// Follow the import job an upload queued until it ends, returning the job with the
+// rows it rejected
+async fn wait_for_job(
+    app: &axum::Router,
+    token: &str,
+    response: axum::response::Response,
+) -> serde_json::Value {
+    assert_eq!(response.status(), StatusCode::ACCEPTED);
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let job: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    let id = job["id"].as_i64().unwrap();
+
+    for _ in 0..600 {
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .uri(format!("/api/admin/jobs/{}", id))
+                    .header("authorization", format!("Bearer {}", token))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let job: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        if !matches!(job["status"].as_str(), Some("queued" | "running")) {
+            return job;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+    }
+    panic!("import job {} did not finish", id);
+}
+
+// Delete a college through the admin API and purge it straight away by moving its
+// deletion back past the retention period; returns the purge response
+async fn purge_college(
+    app: &axum::Router,
+    pool: &PgPool,
+    token: &str,
+    id: i64,
+) -> serde_json::Value {
+    let send = |method: &str, uri: String| {
+        app.clone().oneshot(
+            Request::builder()
+                .method(method)
+                .uri(uri)
+                .header("authorization", format!("Bearer {}", token))
+                .body(Body::empty())
+                .unwrap(),
+        )
+    };
+
+    let response = send("DELETE", format!("/api/admin/colleges/{}", id))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    sqlx::query("UPDATE colleges SET deleted_at = deleted_at - INTERVAL '1 year' WHERE id = $1")
+        .bind(id as i32)
+        .execute(pool)
+        .await
+        .unwrap();
+
+    let response = send("POST", format!("/api/admin/colleges/{}/purge", id))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    serde_json::from_slice(&body).unwrap()
+}
+
 #[tokio::test]
 async fn test_health_endpoint() {
     // Setup test database connection
@@ -56,3 +186,3052 @@ async fn test_colleges_endpoint() {
 
     assert_eq!(response.status(), StatusCode::OK);
 }
+
+#[tokio::test]
+async fn test_college_fees_endpoint() {
+    let database_url = env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+    let pool = PgPoolOptions::new()
+        .max_connections(1)
+        .connect(&database_url)
+        .await
+        .expect("Failed to connect to test database");
+
+    sqlx::query("DELETE FROM colleges WHERE name = 'Fee Endpoint Test College'")
+        .execute(&pool)
+        .await
+        .unwrap();
+    let college_id: i32 = sqlx::query_scalar(
+        r#"
+        INSERT INTO colleges (name, category, district, city, type)
+        VALUES ('Fee Endpoint Test College', 'Arts', 'Latur', 'Latur', 'Private')
+        RETURNING id
+        "#,
+    )
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+    sqlx::query(
+        r#"
+        INSERT INTO fee_structures (college_id, course, year, tuition_fee, development_fee, exam_fee)
+        VALUES ($1, 'BA', 2023, 10000, 2000, 500), ($1, 'BA', 2024, 12000, 2500, 500)
+        "#,
+    )
+    .bind(college_id)
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    let app = create_app_with_pool(pool.clone()).await;
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri(format!("/api/colleges/{}/fees", college_id))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let fees: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    // Most recent year first, with the total of the fee components
+    let years_and_totals: Vec<(i64, i64)> = fees
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|fee| {
+            (
+                fee["year"].as_i64().unwrap(),
+                fee["total_fee"].as_i64().unwrap(),
+            )
+        })
+        .collect();
+    assert_eq!(years_and_totals, vec![(2024, 15000), (2023, 12500)]);
+
+    sqlx::query("DELETE FROM colleges WHERE id = $1")
+        .bind(college_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_colleges_fee_filter_and_sort() {
+    let database_url = env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+    let pool = PgPoolOptions::new()
+        .max_connections(1)
+        .connect(&database_url)
+        .await
+        .expect("Failed to connect to test database");
+
+    let app = create_app_with_pool(pool).await;
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .uri("/api/colleges?max_fee=150000&sort=fee")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/api/colleges?sort=unknown")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_cost_estimate_without_fee_data() {
+    let database_url = env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+    let pool = PgPoolOptions::new()
+        .max_connections(1)
+        .connect(&database_url)
+        .await
+        .expect("Failed to connect to test database");
+
+    let app = create_app_with_pool(pool).await;
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/api/colleges/999999/cost-estimate?category=OBC&income_band=2.5l_8l")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_cost_estimate() {
+    let database_url = env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+    let pool = PgPoolOptions::new()
+        .max_connections(1)
+        .connect(&database_url)
+        .await
+        .expect("Failed to connect to test database");
+
+    sqlx::query("DELETE FROM colleges WHERE name = 'Cost Estimate Test College'")
+        .execute(&pool)
+        .await
+        .unwrap();
+    sqlx::query("DELETE FROM concession_rules WHERE name LIKE 'Cost Estimate Test%'")
+        .execute(&pool)
+        .await
+        .unwrap();
+    let college_id: i32 = sqlx::query_scalar(
+        r#"
+        INSERT INTO colleges (name, category, district, city, type)
+        VALUES ('Cost Estimate Test College', 'Engineering', 'Dhule', 'Dhule', 'Private')
+        RETURNING id
+        "#,
+    )
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+    sqlx::query(
+        r#"
+        INSERT INTO fee_structures (college_id, course, year, tuition_fee, development_fee, exam_fee, hostel_fee)
+        VALUES ($1, 'BTech', 2091, 10000, 2000, 500, 20000)
+        "#,
+    )
+    .bind(college_id)
+    .execute(&pool)
+    .await
+    .unwrap();
+    // Rules for a made-up category and year, so the seeded schemes never match. Of
+    // the two tuition rules only the more generous one counts.
+    sqlx::query(
+        r#"
+        INSERT INTO concession_rules
+            (name, academic_year, categories, max_family_income, requires_hostel, component, percentage, max_amount)
+        VALUES
+            ('Cost Estimate Test capped', 2091, '{TESTCAT}', 250000, FALSE, 'tuition', 40, 3000),
+            ('Cost Estimate Test quarter', 2091, '{TESTCAT}', 250000, FALSE, 'tuition', 25, NULL),
+            ('Cost Estimate Test hostel', 2091, '{TESTCAT}', NULL, TRUE, 'hostel', 10, NULL)
+        "#,
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    let app = create_app_with_pool(pool.clone()).await;
+    let estimate = |query: &str| {
+        let app = app.clone();
+        let uri = format!("/api/colleges/{}/cost-estimate?{}", college_id, query);
+        async move {
+            let response = app
+                .oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap())
+                .await
+                .unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+            let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+                .await
+                .unwrap();
+            serde_json::from_slice::<serde_json::Value>(&body).unwrap()
+        }
+    };
+    let applied = |estimate: &serde_json::Value| -> Vec<(String, i64)> {
+        estimate["concessions"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|c| {
+                (
+                    c["name"].as_str().unwrap().to_string(),
+                    c["amount_per_year"].as_i64().unwrap(),
+                )
+            })
+            .collect()
+    };
+
+    // 40% of the tuition is 4000, capped at 3000 a year
+    let result = estimate("category=testcat&income_band=below_1l&duration=2").await;
+    assert_eq!(result["category"], "TESTCAT");
+    assert_eq!(result["fee_year"], 2091);
+    assert_eq!(
+        applied(&result),
+        vec![("Cost Estimate Test capped".to_string(), 3000)]
+    );
+    assert_eq!(result["years"].as_array().unwrap().len(), 2);
+    assert_eq!(result["years"][0]["gross"], 12500);
+    assert_eq!(result["years"][0]["net"], 9500);
+    assert_eq!(result["total_gross"], 25000);
+    assert_eq!(result["total_concession"], 6000);
+    assert_eq!(result["total_net"], 19000);
+
+    // Staying in the hostel adds its fee and the hostel-only rule
+    let result = estimate("category=TESTCAT&income_band=below_1l&hostel=true&duration=1").await;
+    assert_eq!(
+        applied(&result),
+        vec![
+            ("Cost Estimate Test capped".to_string(), 3000),
+            ("Cost Estimate Test hostel".to_string(), 2000),
+        ]
+    );
+    assert_eq!(result["total_gross"], 32500);
+    assert_eq!(result["total_net"], 27500);
+
+    // Above the income cap no rule matches and the full fee is due for four years
+    let result = estimate("category=TESTCAT&income_band=above_8l").await;
+    assert!(applied(&result).is_empty());
+    assert_eq!(result["years"].as_array().unwrap().len(), 4);
+    assert_eq!(result["total_gross"], 50000);
+    assert_eq!(result["total_concession"], 0);
+    assert_eq!(result["total_net"], 50000);
+
+    sqlx::query("DELETE FROM concession_rules WHERE name LIKE 'Cost Estimate Test%'")
+        .execute(&pool)
+        .await
+        .unwrap();
+    sqlx::query("DELETE FROM colleges WHERE id = $1")
+        .bind(college_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_scholarship_match_endpoint() {
+    let database_url = env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+    let pool = PgPoolOptions::new()
+        .max_connections(1)
+        .connect(&database_url)
+        .await
+        .expect("Failed to connect to test database");
+
+    sqlx::query("DELETE FROM scholarships WHERE name LIKE 'Match Test%'")
+        .execute(&pool)
+        .await
+        .unwrap();
+    // Each scheme restricts on one attribute, except the last which restricts on all three
+    sqlx::query(
+        r#"
+        INSERT INTO scholarships (name, provider, categories, max_family_income, courses)
+        VALUES
+            ('Match Test category', 'Test', '{TESTCAT}', NULL, '{}'),
+            ('Match Test income', 'Test', '{}', 250000, '{}'),
+            ('Match Test course', 'Test', '{}', NULL, '{Pharmacy}'),
+            ('Match Test all', 'Test', '{TESTCAT}', 250000, '{Pharmacy}')
+        "#,
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    let app = create_app_with_pool(pool.clone()).await;
+
+    for (query, expected) in [
+        (
+            "category=testcat&income_band=below_1l&course=pharmacy",
+            vec!["all", "category", "course", "income"],
+        ),
+        (
+            "category=OBC&income_band=below_1l&course=Pharmacy",
+            vec!["course", "income"],
+        ),
+        (
+            "category=TESTCAT&income_band=above_8l&course=Pharmacy",
+            vec!["category", "course"],
+        ),
+        (
+            "category=TESTCAT&income_band=1l_2.5l&course=Arts",
+            vec!["category", "income"],
+        ),
+        // Attributes that are not given never satisfy a restriction
+        ("domicile=true", vec![]),
+    ] {
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .uri(format!("/api/scholarships/match?{}", query))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let scholarships: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        let mut matched: Vec<&str> = scholarships
+            .as_array()
+            .unwrap()
+            .iter()
+            .filter_map(|s| s["name"].as_str().unwrap().strip_prefix("Match Test "))
+            .collect();
+        matched.sort();
+        assert_eq!(matched, expected, "{}", query);
+    }
+
+    sqlx::query("DELETE FROM scholarships WHERE name LIKE 'Match Test%'")
+        .execute(&pool)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_colleges_accreditation_filters() {
+    let database_url = env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+    let pool = PgPoolOptions::new()
+        .max_connections(1)
+        .connect(&database_url)
+        .await
+        .expect("Failed to connect to test database");
+
+    let app = create_app_with_pool(pool).await;
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .uri("/api/colleges?naac_min=A%2B&nirf_max=100&sort=nirf")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/api/colleges?naac_min=Z")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+// The naac_min filter ranks grades in SQL; it must agree with the order of NaacGrade
+#[tokio::test]
+async fn test_naac_grade_order_matches_sql() {
+    let database_url = env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+    let pool = PgPoolOptions::new()
+        .max_connections(1)
+        .connect(&database_url)
+        .await
+        .expect("Failed to connect to test database");
+
+    let mut grades: Vec<NaacGrade> = ["A", "B++", "D", "A++", "C", "B+", "A+", "B"]
+        .into_iter()
+        .map(|grade| NaacGrade::try_from(grade.to_string()).unwrap())
+        .collect();
+    grades.sort();
+    let names: Vec<String> = grades
+        .iter()
+        .map(|grade| grade.as_str().to_string())
+        .collect();
+
+    let ranked: Vec<(String, i32)> = sqlx::query_as(
+        "SELECT grade, naac_grade_rank(grade) FROM unnest($1::text[]) AS grade \
+         ORDER BY naac_grade_rank(grade)",
+    )
+    .bind(&names)
+    .fetch_all(&pool)
+    .await
+    .unwrap();
+
+    assert_eq!(
+        ranked.iter().map(|(grade, _)| grade).collect::<Vec<_>>(),
+        names.iter().collect::<Vec<_>>()
+    );
+    // Every grade has its own rank above the one given to unknown grades
+    let ranks: Vec<i32> = ranked.iter().map(|(_, rank)| *rank).collect();
+    assert!(ranks[0] > 0);
+    assert!(ranks.windows(2).all(|pair| pair[0] < pair[1]));
+}
+
+#[tokio::test]
+async fn test_college_placements_endpoint() {
+    let database_url = env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+    let pool = PgPoolOptions::new()
+        .max_connections(1)
+        .connect(&database_url)
+        .await
+        .expect("Failed to connect to test database");
+
+    sqlx::query("DELETE FROM colleges WHERE name = 'Placement Endpoint Test College'")
+        .execute(&pool)
+        .await
+        .unwrap();
+    let college_id: i32 = sqlx::query_scalar(
+        r#"
+        INSERT INTO colleges (name, category, district, city, type)
+        VALUES ('Placement Endpoint Test College', 'Engineering', 'Nanded', 'Nanded', 'Private')
+        RETURNING id
+        "#,
+    )
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+    // 70% of the 2024 batch was placed; the older 2023 batch does not count
+    sqlx::query(
+        r#"
+        INSERT INTO placement_stats (college_id, year, branch, students_eligible, students_placed)
+        VALUES ($1, 2024, 'IT', 50, 30), ($1, 2024, 'CS', 50, 40), ($1, 2023, 'CS', 100, 100)
+        "#,
+    )
+    .bind(college_id)
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    let app = create_app_with_pool(pool.clone()).await;
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .uri(format!("/api/colleges/{}/placements", college_id))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let placements: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    let rows: Vec<(i64, String, f64)> = placements
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|row| {
+            (
+                row["year"].as_i64().unwrap(),
+                row["branch"].as_str().unwrap().to_string(),
+                row["placement_rate"].as_f64().unwrap(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        rows,
+        vec![
+            (2024, "CS".to_string(), 80.0),
+            (2024, "IT".to_string(), 60.0),
+            (2023, "CS".to_string(), 100.0),
+        ]
+    );
+
+    for (min_rate, listed) in [(65, true), (75, false)] {
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .uri(format!(
+                        "/api/colleges?min_placement_rate={}&sort=placement",
+                        min_rate
+                    ))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let colleges: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        let found = colleges
+            .as_array()
+            .unwrap()
+            .iter()
+            .any(|college| college["id"] == college_id);
+        assert_eq!(found, listed, "min_placement_rate={}", min_rate);
+    }
+
+    sqlx::query("DELETE FROM colleges WHERE id = $1")
+        .bind(college_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_universities() {
+    let database_url = env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+    // The import job holds its transaction while it saves progress on another connection
+    let pool = PgPoolOptions::new()
+        .max_connections(3)
+        .connect(&database_url)
+        .await
+        .expect("Failed to connect to test database");
+
+    sqlx::query("DELETE FROM colleges WHERE name LIKE 'University Test College%'")
+        .execute(&pool)
+        .await
+        .unwrap();
+    sqlx::query("DELETE FROM universities WHERE short_name = 'UTU'")
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let app = create_app_with_pool(pool.clone()).await;
+    let token = admin_token(&app, &pool, "test-university-admin", "reviewer").await;
+
+    let create = |university: serde_json::Value| {
+        app.clone().oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/api/admin/universities")
+                .header("authorization", format!("Bearer {}", token))
+                .header("content-type", "application/json")
+                .body(Body::from(university.to_string()))
+                .unwrap(),
+        )
+    };
+    let get = |uri: &str| {
+        app.clone().oneshot(
+            Request::builder()
+                .uri(uri.to_string())
+                .body(Body::empty())
+                .unwrap(),
+        )
+    };
+
+    let response = create(serde_json::json!({
+        "name": "University Test University",
+        "short_name": "UTU",
+        "city": "Jalgaon",
+        "university_type": "State"
+    }))
+    .await
+    .unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let university: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    let university_id = university["id"].as_i64().unwrap();
+
+    // Either name clashing with an existing university is a conflict
+    for duplicate in [
+        serde_json::json!({ "name": "University Test University", "short_name": "UTU2" }),
+        serde_json::json!({ "name": "University Test University 2", "short_name": "UTU" }),
+    ] {
+        let response = create(duplicate).await.unwrap();
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+    }
+    let response = create(serde_json::json!({ "name": " ", "short_name": "UTU3" }))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    // Imported colleges name their university by short name or full name
+    let college = |name: &str, university: &str| {
+        serde_json::json!({
+            "name": name,
+            "category": "Arts",
+            "district": "Jalgaon",
+            "city": "Jalgaon",
+            "type": "Private",
+            "university": university
+        })
+    };
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/api/admin/upload/json")
+                .header("authorization", format!("Bearer {}", token))
+                .header("content-type", "application/json")
+                .body(Body::from(
+                    serde_json::json!({
+                        "colleges": [
+                            college("University Test College A", "utu"),
+                            college("University Test College B", "University Test University"),
+                        ]
+                    })
+                    .to_string(),
+                ))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    let result = wait_for_job(&app, &token, response).await;
+    assert_eq!(result["inserted"], 2);
+    let affiliated: i64 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM colleges WHERE name LIKE 'University Test College%' AND university_id = $1",
+    )
+    .bind(university_id as i32)
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+    assert_eq!(affiliated, 2);
+
+    let response = get("/api/universities").await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let universities: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    let listed = universities
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|u| u["id"] == university_id)
+        .unwrap();
+    assert_eq!(listed["short_name"], "UTU");
+    assert_eq!(listed["college_count"], 2);
+
+    for filter in ["UTU", "university%20test%20university"] {
+        let response = get(&format!("/api/colleges?university={}", filter))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let colleges: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        let names: Vec<&str> = colleges
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|college| college["name"].as_str().unwrap())
+            .collect();
+        assert_eq!(
+            names,
+            vec!["University Test College A", "University Test College B"],
+            "university={}",
+            filter
+        );
+    }
+
+    sqlx::query("DELETE FROM colleges WHERE name LIKE 'University Test College%'")
+        .execute(&pool)
+        .await
+        .unwrap();
+    sqlx::query("DELETE FROM universities WHERE id = $1")
+        .bind(university_id as i32)
+        .execute(&pool)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_facilities_endpoint_and_filter() {
+    let database_url = env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+    let pool = PgPoolOptions::new()
+        .max_connections(1)
+        .connect(&database_url)
+        .await
+        .expect("Failed to connect to test database");
+
+    sqlx::query("DELETE FROM colleges WHERE name = 'Facility Filter Test College'")
+        .execute(&pool)
+        .await
+        .unwrap();
+    let college_id: i32 = sqlx::query_scalar(
+        r#"
+        INSERT INTO colleges (name, category, district, city, type)
+        VALUES ('Facility Filter Test College', 'Science', 'Beed', 'Beed', 'Private')
+        RETURNING id
+        "#,
+    )
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+    sqlx::query(
+        r#"
+        INSERT INTO college_facilities (college_id, facility_id)
+        SELECT $1, id FROM facilities WHERE slug IN ('wifi', 'library')
+        "#,
+    )
+    .bind(college_id)
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    let app = create_app_with_pool(pool.clone()).await;
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .uri("/api/facilities")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    // A slug repeated in any case counts once
+    for (facilities, listed) in [
+        ("wifi,wifi", true),
+        ("Wifi,wifi,library", true),
+        ("lab,library", false),
+    ] {
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .uri(format!("/api/colleges?facilities={}", facilities))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let colleges: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        let found = colleges
+            .as_array()
+            .unwrap()
+            .iter()
+            .any(|college| college["id"] == college_id);
+        assert_eq!(found, listed, "facilities={}", facilities);
+    }
+
+    sqlx::query("DELETE FROM colleges WHERE id = $1")
+        .bind(college_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_colleges_hostel_filters() {
+    let database_url = env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+    let pool = PgPoolOptions::new()
+        .max_connections(1)
+        .connect(&database_url)
+        .await
+        .expect("Failed to connect to test database");
+
+    let app = create_app_with_pool(pool).await;
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/api/colleges?girls_hostel=true&max_hostel_fee=60000")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_gender_policy_and_language_filters() {
+    let database_url = env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+    let pool = PgPoolOptions::new()
+        .max_connections(1)
+        .connect(&database_url)
+        .await
+        .expect("Failed to connect to test database");
+    let app = create_app_with_pool(pool).await;
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .uri("/api/colleges?gender_policy=women_only&language=marathi")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .uri("/api/colleges?gender_policy=mixed")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/api/facets")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_college_images() {
+    let database_url = env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+    let pool = PgPoolOptions::new()
+        .max_connections(1)
+        .connect(&database_url)
+        .await
+        .expect("Failed to connect to test database");
+
+    sqlx::query("DELETE FROM colleges WHERE name = 'Image Test College'")
+        .execute(&pool)
+        .await
+        .unwrap();
+    let college_id: i32 = sqlx::query_scalar(
+        r#"
+        INSERT INTO colleges (name, category, district, city, type)
+        VALUES ('Image Test College', 'Arts', 'Sangli', 'Miraj', 'Private')
+        RETURNING id
+        "#,
+    )
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+    // An image awaiting review is not part of the gallery
+    sqlx::query(
+        r#"
+        INSERT INTO college_images (college_id, image_url, is_primary, published)
+        VALUES ($1, 'https://example.edu/campus.jpg', TRUE, TRUE),
+               ($1, 'https://example.edu/pending.jpg', FALSE, FALSE)
+        "#,
+    )
+    .bind(college_id)
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    let app = create_app_with_pool(pool.clone()).await;
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .uri(format!("/api/colleges/{}/images", college_id))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let images: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    let urls: Vec<&str> = images
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|image| image["image_url"].as_str().unwrap())
+        .collect();
+    assert_eq!(urls, vec!["https://example.edu/campus.jpg"]);
+    assert_eq!(images[0]["is_primary"], true);
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/api/colleges/999999/images")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    sqlx::query("DELETE FROM colleges WHERE id = $1")
+        .bind(college_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+}
+
+#[test]
+fn test_image_processing() {
+    use image::{DynamicImage, ImageFormat};
+    use maharashtra_colleges_backend::media::{nearest_rendition, process_image};
+
+    let mut png = Vec::new();
+    DynamicImage::new_rgb8(1000, 500)
+        .write_to(&mut std::io::Cursor::new(&mut png), ImageFormat::Png)
+        .unwrap();
+
+    let processed = process_image(&png).unwrap();
+    let sizes: Vec<_> = processed
+        .renditions
+        .iter()
+        .map(|r| (r.variant, r.width, r.height))
+        .collect();
+    // Renditions are never upscaled beyond the source
+    assert_eq!(
+        sizes,
+        vec![
+            ("thumbnail", 320, 160),
+            ("card", 800, 400),
+            ("hero", 1000, 500)
+        ]
+    );
+    for rendition in &processed.renditions {
+        assert_eq!(
+            image::guess_format(&rendition.data).unwrap(),
+            ImageFormat::WebP
+        );
+    }
+
+    assert!(process_image(b"not an image").is_err());
+    assert!(process_image(b"GIF89a\x01\x00\x01\x00\x00\x00\x00").is_err());
+
+    let widths = [320, 800, 1600];
+    let pick = |w| *nearest_rendition(&widths, |&w| w, w).unwrap();
+    assert_eq!(pick(Some(100)), 320);
+    assert_eq!(pick(Some(700)), 800);
+    assert_eq!(pick(Some(1300)), 1600);
+    assert_eq!(pick(None), 1600);
+}
+
+#[tokio::test]
+async fn test_college_attachments() {
+    use maharashtra_colleges_backend::models::attachment::{
+        detect_document_type, validate_academic_year,
+    };
+
+    assert!(validate_academic_year("2024-25").is_ok());
+    assert!(validate_academic_year("1999-00").is_ok());
+    assert!(validate_academic_year("2024-26").is_err());
+    assert!(validate_academic_year("2024").is_err());
+    assert_eq!(
+        detect_document_type(b"%PDF-1.7 ...").map(|(_, ext)| ext),
+        Some("pdf")
+    );
+    assert!(detect_document_type(b"PK\x03\x04 not a docx").is_none());
+
+    let database_url = env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+    let pool = PgPoolOptions::new()
+        .max_connections(1)
+        .connect(&database_url)
+        .await
+        .expect("Failed to connect to test database");
+
+    sqlx::query("DELETE FROM colleges WHERE name = 'Attachment Test College'")
+        .execute(&pool)
+        .await
+        .unwrap();
+    let college_id: i32 = sqlx::query_scalar(
+        r#"
+        INSERT INTO colleges (name, category, district, city, type)
+        VALUES ('Attachment Test College', 'Arts', 'Sangli', 'Miraj', 'Private')
+        RETURNING id
+        "#,
+    )
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+    let file_path = format!("attachments/{}/prospectus.pdf", college_id);
+    let stored = upload_dir().join(&file_path);
+    std::fs::create_dir_all(stored.parent().unwrap()).unwrap();
+    std::fs::write(&stored, b"%PDF-1.7 prospectus").unwrap();
+    let attachment_id: i32 = sqlx::query_scalar(
+        r#"
+        INSERT INTO college_attachments
+            (college_id, document_type, academic_year, title, file_name, content_type,
+             byte_size, checksum, file_path)
+        VALUES ($1, 'prospectus', '2024-25', 'Prospectus', 'prospectus.pdf',
+                'application/pdf', 19, 'test-checksum', $2)
+        RETURNING id
+        "#,
+    )
+    .bind(college_id)
+    .bind(&file_path)
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+
+    let app = create_app_with_pool(pool.clone()).await;
+    let get = |uri: String| {
+        app.clone()
+            .oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap())
+    };
+
+    let response = get(format!("/api/colleges/{}/attachments", college_id))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let attachments: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(attachments.as_array().unwrap().len(), 1);
+    assert_eq!(attachments[0]["title"], "Prospectus");
+    assert_eq!(attachments[0]["academic_year"], "2024-25");
+    assert_eq!(
+        attachments[0]["url"],
+        format!("/attachments/{}", attachment_id)
+    );
+    assert!(attachments[0].get("file_path").is_none());
+
+    let response = get(format!("/attachments/{}", attachment_id))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response.headers()[axum::http::header::CONTENT_TYPE],
+        "application/pdf"
+    );
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    assert_eq!(&body[..], b"%PDF-1.7 prospectus");
+
+    // Stored attachment files are not reachable as static uploads, which would skip
+    // the deleted check in /attachments/:id
+    let response = get(format!("/uploads/{}", file_path)).await.unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    // A deleted college's attachments are no longer served
+    sqlx::query("UPDATE colleges SET deleted_at = NOW() WHERE id = $1")
+        .bind(college_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+    let response = get(format!("/attachments/{}", attachment_id))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    let response = get("/attachments/999999".to_string()).await.unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    std::fs::remove_dir_all(stored.parent().unwrap()).unwrap();
+    sqlx::query("DELETE FROM colleges WHERE id = $1")
+        .bind(college_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_admin_college_crud() {
+    let database_url = env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+    let pool = PgPoolOptions::new()
+        .max_connections(1)
+        .connect(&database_url)
+        .await
+        .expect("Failed to connect to test database");
+
+    sqlx::query("DELETE FROM colleges WHERE name = 'CRUD Test College'")
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let app = create_app_with_pool(pool.clone()).await;
+    let token = admin_token(&app, &pool, "test-crud-admin", "superadmin").await;
+    let authorization = format!("Bearer {}", token);
+
+    let json_request = |method: &str, uri: String, body: serde_json::Value| {
+        Request::builder()
+            .method(method)
+            .uri(uri)
+            .header("authorization", &authorization)
+            .header("content-type", "application/json")
+            .body(Body::from(body.to_string()))
+            .unwrap()
+    };
+
+    let response = app
+        .clone()
+        .oneshot(json_request(
+            "POST",
+            "/api/admin/colleges".to_string(),
+            serde_json::json!({
+                "name": "CRUD Test College",
+                "category": "Arts",
+                "district": "Nashik",
+                "city": "Nashik",
+                "type": "Private",
+                "pincode": "422001"
+            }),
+        ))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let created: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    let id = created["id"].as_i64().unwrap();
+
+    let response = app
+        .clone()
+        .oneshot(json_request(
+            "PATCH",
+            format!("/api/admin/colleges/{}", id),
+            serde_json::json!({ "pincode": "12" }),
+        ))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let response = app
+        .clone()
+        .oneshot(json_request(
+            "PATCH",
+            format!("/api/admin/colleges/{}", id),
+            serde_json::json!({ "pincode": null, "autonomous": true }),
+        ))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let patched: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(patched["autonomous"], true);
+    assert!(patched["pincode"].is_null());
+    assert_eq!(patched["city"], "Nashik");
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("DELETE")
+                .uri(format!("/api/admin/colleges/{}", id))
+                .header("authorization", &authorization)
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let deleted: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(deleted["name"], "CRUD Test College");
+    assert!(deleted["purge_after"].as_str().unwrap() > deleted["deleted_at"].as_str().unwrap());
+
+    let get_public = |uri: String| {
+        app.clone()
+            .oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap())
+    };
+    let admin_request = |method: &str, uri: String| {
+        Request::builder()
+            .method(method)
+            .uri(uri)
+            .header("authorization", &authorization)
+            .body(Body::empty())
+            .unwrap()
+    };
+
+    // Deleted colleges are hidden from public queries but kept for restoring
+    let response = get_public(format!("/api/colleges/{}", id)).await.unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    let response = get_public("/api/colleges?search=CRUD%20Test".to_string())
+        .await
+        .unwrap();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let listed: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert!(listed.as_array().unwrap().is_empty());
+
+    let response = app
+        .clone()
+        .oneshot(admin_request(
+            "GET",
+            "/api/admin/colleges/deleted".to_string(),
+        ))
+        .await
+        .unwrap();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let deleted_colleges: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    let entry = deleted_colleges
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|college| college["id"] == id)
+        .expect("deleted college is listed");
+    assert_eq!(entry["deleted_by_username"], "test-crud-admin");
+
+    // Purging is refused until the retention period has passed
+    let response = app
+        .clone()
+        .oneshot(admin_request(
+            "POST",
+            format!("/api/admin/colleges/{}/purge", id),
+        ))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+
+    let response = app
+        .clone()
+        .oneshot(admin_request(
+            "POST",
+            format!("/api/admin/colleges/{}/restore", id),
+        ))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let response = get_public(format!("/api/colleges/{}", id)).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = app
+        .clone()
+        .oneshot(admin_request(
+            "POST",
+            format!("/api/admin/colleges/{}/restore", id),
+        ))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    let purged = purge_college(&app, &pool, &token, id).await;
+    assert_eq!(purged["deleted"]["contact_info"], 1);
+
+    let response = app
+        .clone()
+        .oneshot(admin_request(
+            "POST",
+            format!("/api/admin/colleges/{}/restore", id),
+        ))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_admin_authentication() {
+    let database_url = env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+    let pool = PgPoolOptions::new()
+        .max_connections(1)
+        .connect(&database_url)
+        .await
+        .expect("Failed to connect to test database");
+
+    let app = create_app_with_pool(pool.clone()).await;
+
+    let get_me = |authorization: Option<String>| {
+        let mut request = Request::builder().uri("/api/admin/me");
+        if let Some(authorization) = authorization {
+            request = request.header("authorization", authorization);
+        }
+        request.body(Body::empty()).unwrap()
+    };
+
+    let response = app.clone().oneshot(get_me(None)).await.unwrap();
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    let response = app
+        .clone()
+        .oneshot(get_me(Some("Bearer not-a-token".to_string())))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    let token = admin_token(&app, &pool, "test-auth-admin", "superadmin").await;
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/api/admin/login")
+                .header("content-type", "application/json")
+                .body(Body::from(
+                    serde_json::json!({ "username": "test-auth-admin", "password": "wrong" })
+                        .to_string(),
+                ))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    let response = app
+        .clone()
+        .oneshot(get_me(Some(format!("Bearer {}", token))))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/api/admin/logout")
+                .header("authorization", format!("Bearer {}", token))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+    // The token is revoked after logout
+    let response = app
+        .oneshot(get_me(Some(format!("Bearer {}", token))))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn test_admin_role_permissions() {
+    let database_url = env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+    let pool = PgPoolOptions::new()
+        .max_connections(1)
+        .connect(&database_url)
+        .await
+        .expect("Failed to connect to test database");
+    let app = create_app_with_pool(pool.clone()).await;
+
+    let volunteer = admin_token(&app, &pool, "test-rbac-volunteer", "volunteer").await;
+    let reviewer = admin_token(&app, &pool, "test-rbac-reviewer", "reviewer").await;
+
+    let send = |method: &str, uri: &str, token: &str, body: Option<serde_json::Value>| {
+        let mut builder = Request::builder()
+            .method(method)
+            .uri(uri)
+            .header("authorization", format!("Bearer {}", token));
+        let body = match body {
+            Some(body) => {
+                builder = builder.header("content-type", "application/json");
+                Body::from(body.to_string())
+            }
+            None => Body::empty(),
+        };
+        app.clone().oneshot(builder.body(body).unwrap())
+    };
+
+    // Volunteers can read stats but not delete or manage accounts
+    let response = send("GET", "/api/admin/stats", &volunteer, None)
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = send("DELETE", "/api/admin/colleges/999999999", &volunteer, None)
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    let response = send("GET", "/api/admin/users", &volunteer, None)
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    // Reviewers can edit but not delete
+    let response = send(
+        "PATCH",
+        "/api/admin/colleges/999999999",
+        &reviewer,
+        Some(serde_json::json!({ "website": "https://example.org" })),
+    )
+    .await
+    .unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    let response = send("DELETE", "/api/admin/colleges/999999999", &reviewer, None)
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    let response = send(
+        "POST",
+        "/api/admin/colleges/999999999/purge",
+        &reviewer,
+        None,
+    )
+    .await
+    .unwrap();
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    // Invalid roles are rejected when creating accounts
+    let superadmin = admin_token(&app, &pool, "test-rbac-superadmin", "superadmin").await;
+    let response = send(
+        "POST",
+        "/api/admin/users",
+        &superadmin,
+        Some(serde_json::json!({
+            "username": "test-rbac-invalid",
+            "password": TEST_ADMIN_PASSWORD,
+            "role": "owner"
+        })),
+    )
+    .await
+    .unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let response = send("GET", "/api/admin/roles", &volunteer, None)
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_college_representatives() {
+    let database_url = env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+    let pool = PgPoolOptions::new()
+        .max_connections(1)
+        .connect(&database_url)
+        .await
+        .expect("Failed to connect to test database");
+
+    sqlx::query("DELETE FROM admin_users WHERE username = 'test-representative'")
+        .execute(&pool)
+        .await
+        .unwrap();
+    sqlx::query("DELETE FROM colleges WHERE name = 'Representative Test College'")
+        .execute(&pool)
+        .await
+        .unwrap();
+    sqlx::query("DELETE FROM representative_claims WHERE username = 'test-representative'")
+        .execute(&pool)
+        .await
+        .unwrap();
+    let college_id: i32 = sqlx::query_scalar(
+        r#"
+        INSERT INTO colleges (name, category, district, city, type)
+        VALUES ('Representative Test College', 'Arts', 'Satara', 'Karad', 'Private')
+        RETURNING id
+        "#,
+    )
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+
+    let app = create_app_with_pool(pool.clone()).await;
+    let superadmin = admin_token(&app, &pool, "test-claims-superadmin", "superadmin").await;
+    let reviewer = admin_token(&app, &pool, "test-claims-reviewer", "reviewer").await;
+
+    let send = |method: &str, uri: String, token: Option<&str>, body: serde_json::Value| {
+        let mut builder = Request::builder()
+            .method(method)
+            .uri(uri)
+            .header("content-type", "application/json");
+        if let Some(token) = token {
+            builder = builder.header("authorization", format!("Bearer {}", token));
+        }
+        app.clone()
+            .oneshot(builder.body(Body::from(body.to_string())).unwrap())
+    };
+    let json = |response: axum::response::Response| async move {
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        serde_json::from_slice::<serde_json::Value>(&body).unwrap()
+    };
+
+    // Anyone can claim a college; an admin approves the claim
+    let response = send(
+        "POST",
+        format!("/api/colleges/{}/claims", college_id),
+        None,
+        serde_json::json!({
+            "username": "test-representative",
+            "password": TEST_ADMIN_PASSWORD,
+            "full_name": "Registrar",
+            "email": "registrar@example.edu"
+        }),
+    )
+    .await
+    .unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let claim = json(response).await;
+    assert_eq!(claim["status"], "pending");
+
+    let response = send(
+        "POST",
+        format!("/api/admin/claims/{}/approve", claim["id"]),
+        Some(&reviewer),
+        serde_json::json!({}),
+    )
+    .await
+    .unwrap();
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    let response = send(
+        "POST",
+        format!("/api/admin/claims/{}/approve", claim["id"]),
+        Some(&superadmin),
+        serde_json::json!({ "note": "Verified by phone" }),
+    )
+    .await
+    .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(json(response).await["status"], "approved");
+
+    let response = send(
+        "POST",
+        "/api/admin/login".to_string(),
+        None,
+        serde_json::json!({ "username": "test-representative", "password": TEST_ADMIN_PASSWORD }),
+    )
+    .await
+    .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let login = json(response).await;
+    assert_eq!(login["user"]["role"], "representative");
+    let representative = login["token"].as_str().unwrap().to_string();
+
+    // Representatives cannot edit directly or touch other colleges
+    let response = send(
+        "PATCH",
+        format!("/api/admin/colleges/{}", college_id),
+        Some(&representative),
+        serde_json::json!({ "phone": "020-2550000" }),
+    )
+    .await
+    .unwrap();
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    let contact_change = serde_json::json!({
+        "kind": "contact_info",
+        "changes": { "phone": "020-2550000" }
+    });
+    let response = send(
+        "POST",
+        format!("/api/admin/colleges/{}/submissions", college_id + 1),
+        Some(&representative),
+        contact_change.clone(),
+    )
+    .await
+    .unwrap();
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    let response = send(
+        "POST",
+        format!("/api/admin/colleges/{}/submissions", college_id),
+        Some(&representative),
+        contact_change,
+    )
+    .await
+    .unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let submission = json(response).await;
+
+    // Nothing is published until a reviewer approves
+    let response = send(
+        "GET",
+        format!("/api/colleges/{}", college_id),
+        None,
+        serde_json::json!({}),
+    )
+    .await
+    .unwrap();
+    assert!(json(response).await["phone"].is_null());
+
+    let response = send(
+        "POST",
+        format!("/api/admin/submissions/{}/approve", submission["id"]),
+        Some(&reviewer),
+        serde_json::json!({}),
+    )
+    .await
+    .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = send(
+        "GET",
+        format!("/api/colleges/{}", college_id),
+        None,
+        serde_json::json!({}),
+    )
+    .await
+    .unwrap();
+    assert_eq!(json(response).await["phone"], "020-2550000");
+
+    let response = send(
+        "POST",
+        format!("/api/admin/submissions/{}/approve", submission["id"]),
+        Some(&reviewer),
+        serde_json::json!({}),
+    )
+    .await
+    .unwrap();
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+
+    // Approved cutoffs replace the rows for their branch and category only
+    sqlx::query(
+        r#"
+        INSERT INTO cutoffs (college_id, year, branch, category, cutoff_marks)
+        VALUES ($1, 2024, 'BA', 'OPEN', 70), ($1, 2024, 'BA', 'SC', 60)
+        "#,
+    )
+    .bind(college_id)
+    .execute(&pool)
+    .await
+    .unwrap();
+    let response = send(
+        "POST",
+        format!("/api/admin/colleges/{}/submissions", college_id),
+        Some(&representative),
+        serde_json::json!({
+            "kind": "cutoffs",
+            "changes": [
+                { "year": 2024, "branch": "BA", "category": "OPEN", "cutoff_marks": 78.5 },
+                { "year": 2024, "branch": "BA", "category": "OBC", "cutoff_marks": 72.25 }
+            ]
+        }),
+    )
+    .await
+    .unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let submission = json(response).await;
+
+    let response = send(
+        "GET",
+        "/api/admin/submissions?status=pending".to_string(),
+        Some(&representative),
+        serde_json::json!({}),
+    )
+    .await
+    .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(json(response).await.as_array().unwrap().len(), 1);
+
+    let response = send(
+        "POST",
+        format!("/api/admin/submissions/{}/approve", submission["id"]),
+        Some(&reviewer),
+        serde_json::json!({}),
+    )
+    .await
+    .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let cutoffs: Vec<(String, f64)> = sqlx::query_as(
+        r#"
+        SELECT category, cutoff_marks::FLOAT8 FROM cutoffs
+        WHERE college_id = $1 AND year = 2024
+        ORDER BY category
+        "#,
+    )
+    .bind(college_id)
+    .fetch_all(&pool)
+    .await
+    .unwrap();
+    assert_eq!(
+        cutoffs,
+        vec![
+            ("OBC".to_string(), 72.25),
+            ("OPEN".to_string(), 78.5),
+            ("SC".to_string(), 60.0)
+        ]
+    );
+    let duplicate = sqlx::query(
+        "INSERT INTO cutoffs (college_id, year, branch, category) VALUES ($1, 2024, 'BA', 'SC')",
+    )
+    .bind(college_id)
+    .execute(&pool)
+    .await;
+    assert!(duplicate.is_err());
+
+    // Submissions for a college deleted while they were pending are not applied, and
+    // the college cannot be purged while it has a representative
+    let response = send(
+        "POST",
+        format!("/api/admin/colleges/{}/submissions", college_id),
+        Some(&representative),
+        serde_json::json!({
+            "kind": "cutoffs",
+            "changes": [{ "year": 2024, "branch": "BA", "category": "ST", "cutoff_marks": 55 }]
+        }),
+    )
+    .await
+    .unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let submission = json(response).await;
+    sqlx::query("UPDATE colleges SET deleted_at = NOW() - INTERVAL '1 year' WHERE id = $1")
+        .bind(college_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let response = send(
+        "POST",
+        format!("/api/admin/submissions/{}/approve", submission["id"]),
+        Some(&reviewer),
+        serde_json::json!({}),
+    )
+    .await
+    .unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    let response = send(
+        "POST",
+        format!("/api/admin/colleges/{}/purge", college_id),
+        Some(&superadmin),
+        serde_json::json!({}),
+    )
+    .await
+    .unwrap();
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+
+    sqlx::query("DELETE FROM admin_users WHERE username = 'test-representative'")
+        .execute(&pool)
+        .await
+        .unwrap();
+    sqlx::query("DELETE FROM colleges WHERE id = $1")
+        .bind(college_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_audit_log() {
+    let database_url = env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+    let pool = PgPoolOptions::new()
+        .max_connections(1)
+        .connect(&database_url)
+        .await
+        .expect("Failed to connect to test database");
+
+    let app = create_app_with_pool(pool.clone()).await;
+    let token = admin_token(&app, &pool, "test-audit-admin", "superadmin").await;
+
+    let send = |method: &str, uri: String, body: Option<serde_json::Value>| {
+        let builder = Request::builder()
+            .method(method)
+            .uri(uri)
+            .header("authorization", format!("Bearer {}", token))
+            .header("content-type", "application/json");
+        let body = body.map_or_else(Body::empty, |body| Body::from(body.to_string()));
+        app.clone().oneshot(builder.body(body).unwrap())
+    };
+    let json = |response: axum::response::Response| async move {
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        serde_json::from_slice::<serde_json::Value>(&body).unwrap()
+    };
+
+    let response = send(
+        "POST",
+        "/api/admin/colleges".to_string(),
+        Some(serde_json::json!({
+            "name": "Audit Test College",
+            "category": "Science",
+            "district": "Kolhapur",
+            "city": "Kolhapur",
+            "type": "Government",
+            "phone": "0231-2600000"
+        })),
+    )
+    .await
+    .unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let id = json(response).await["id"].as_i64().unwrap();
+
+    let response = send(
+        "PATCH",
+        format!("/api/admin/colleges/{}", id),
+        Some(serde_json::json!({ "autonomous": true })),
+    )
+    .await
+    .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = send(
+        "GET",
+        format!("/api/admin/audit?college_id={}&action=update", id),
+        None,
+    )
+    .await
+    .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let entries = json(response).await;
+    let update = entries
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|entry| entry["table_name"] == "colleges")
+        .expect("college update is audited");
+    assert_eq!(update["actor"], "test-audit-admin");
+    assert_eq!(update["source"], "manual_edit");
+    assert_eq!(update["changes"]["autonomous"]["before"], false);
+    assert_eq!(update["changes"]["autonomous"]["after"], true);
+    assert!(update["changes"].get("name").is_none());
+
+    purge_college(&app, &pool, &token, id).await;
+
+    // The history outlives the college
+    let response = send(
+        "GET",
+        format!(
+            "/api/admin/audit?college_id={}&actor=test-audit-admin&action=delete",
+            id
+        ),
+        None,
+    )
+    .await
+    .unwrap();
+    let tables: Vec<String> = json(response)
+        .await
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|entry| entry["table_name"].as_str().unwrap().to_string())
+        .collect();
+    assert!(tables.contains(&"colleges".to_string()));
+    assert!(tables.contains(&"contact_info".to_string()));
+
+    let tomorrow = (chrono::Utc::now() + chrono::Duration::days(1)).date_naive();
+    let response = send(
+        "GET",
+        format!("/api/admin/audit?college_id={}&from={}", id, tomorrow),
+        None,
+    )
+    .await
+    .unwrap();
+    assert!(json(response).await.as_array().unwrap().is_empty());
+
+    let response = send("GET", "/api/admin/audit?table=hostels".to_string(), None)
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_college_history() {
+    let database_url = env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+    let pool = PgPoolOptions::new()
+        .max_connections(1)
+        .connect(&database_url)
+        .await
+        .expect("Failed to connect to test database");
+
+    sqlx::query("DELETE FROM colleges WHERE name LIKE 'History Test College%'")
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let app = create_app_with_pool(pool.clone()).await;
+    let token = admin_token(&app, &pool, "test-history-admin", "superadmin").await;
+
+    let send = |method: &str, uri: String, body: Option<serde_json::Value>| {
+        let builder = Request::builder()
+            .method(method)
+            .uri(uri)
+            .header("authorization", format!("Bearer {}", token))
+            .header("content-type", "application/json");
+        let body = body.map_or_else(Body::empty, |body| Body::from(body.to_string()));
+        app.clone().oneshot(builder.body(body).unwrap())
+    };
+    let json = |response: axum::response::Response| async move {
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        serde_json::from_slice::<serde_json::Value>(&body).unwrap()
+    };
+
+    let response = send(
+        "POST",
+        "/api/admin/colleges".to_string(),
+        Some(serde_json::json!({
+            "name": "History Test College",
+            "category": "Commerce",
+            "district": "Satara",
+            "city": "Karad",
+            "type": "Private",
+            "phone": "02164-200000"
+        })),
+    )
+    .await
+    .unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let id = json(response).await["id"].as_i64().unwrap();
+
+    let before_patch = chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Micros, true);
+    let response = send(
+        "PATCH",
+        format!("/api/admin/colleges/{}", id),
+        Some(
+            serde_json::json!({ "name": "History Test College Renamed", "phone": "02164-211111" }),
+        ),
+    )
+    .await
+    .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = send("GET", format!("/api/colleges/{}/history", id), None)
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let versions = json(response).await;
+    let versions = versions.as_array().unwrap();
+    assert_eq!(versions.len(), 2);
+    assert_eq!(versions[0]["name"], "History Test College");
+    assert_eq!(versions[0]["phone"], "02164-200000");
+    assert_eq!(versions[0]["valid_to"], versions[1]["valid_from"]);
+    assert_eq!(versions[1]["name"], "History Test College Renamed");
+    assert!(versions[1]["valid_to"].is_null());
+
+    let response = send(
+        "GET",
+        format!("/api/colleges/{}?as_of={}", id, before_patch),
+        None,
+    )
+    .await
+    .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let college = json(response).await;
+    assert_eq!(college["name"], "History Test College");
+    assert_eq!(college["phone"], "02164-200000");
+
+    let response = send(
+        "GET",
+        format!("/api/colleges?search=History%20Test&as_of={}", before_patch),
+        None,
+    )
+    .await
+    .unwrap();
+    let names: Vec<String> = json(response)
+        .await
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|college| college["name"].as_str().unwrap().to_string())
+        .collect();
+    assert_eq!(names, vec!["History Test College".to_string()]);
+
+    // The college did not exist yet
+    let yesterday = (chrono::Utc::now() - chrono::Duration::days(1)).date_naive();
+    let response = send(
+        "GET",
+        format!("/api/colleges/{}?as_of={}", id, yesterday),
+        None,
+    )
+    .await
+    .unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    let response = send("GET", format!("/api/colleges/{}?as_of=June", id), None)
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let response = send("DELETE", format!("/api/admin/colleges/{}", id), None)
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = send("GET", format!("/api/colleges/{}/history", id), None)
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    // The time spent deleted is left out of the restored college's history
+    let response = send("POST", format!("/api/admin/colleges/{}/restore", id), None)
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let response = send("GET", format!("/api/colleges/{}/history", id), None)
+        .await
+        .unwrap();
+    let versions = json(response).await;
+    let versions = versions.as_array().unwrap();
+    assert_eq!(versions.len(), 3);
+    assert_eq!(versions[2]["name"], "History Test College Renamed");
+    assert!(versions[2]["valid_to"].is_null());
+
+    // Purging keeps the versions, but the history is no longer served
+    purge_college(&app, &pool, &token, id).await;
+    let versions: i64 =
+        sqlx::query_scalar("SELECT COUNT(*) FROM record_versions WHERE college_id = $1")
+            .bind(id as i32)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+    assert!(versions > 0);
+    let response = send("GET", format!("/api/colleges/{}/history", id), None)
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    let response = send("GET", "/api/colleges/0/history".to_string(), None)
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_college_import_dry_run() {
+    let database_url = env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+    let pool = PgPoolOptions::new()
+        .max_connections(1)
+        .connect(&database_url)
+        .await
+        .expect("Failed to connect to test database");
+
+    sqlx::query("DELETE FROM colleges WHERE name LIKE 'Dry Run Test College%'")
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let app = create_app_with_pool(pool.clone()).await;
+    let token = admin_token(&app, &pool, "test-dry-run-admin", "volunteer").await;
+
+    let upload_json = |uri: &str, colleges: serde_json::Value| {
+        app.clone().oneshot(
+            Request::builder()
+                .method("POST")
+                .uri(uri)
+                .header("authorization", format!("Bearer {}", token))
+                .header("content-type", "application/json")
+                .body(Body::from(
+                    serde_json::json!({ "colleges": colleges }).to_string(),
+                ))
+                .unwrap(),
+        )
+    };
+    let json = |response: axum::response::Response| async move {
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        serde_json::from_slice::<serde_json::Value>(&body).unwrap()
+    };
+    let college = |name: &str, autonomous: bool, phone: Option<&str>| {
+        serde_json::json!({
+            "name": name,
+            "category": "Science",
+            "district": "Sangli",
+            "city": "Miraj",
+            "type": "Private",
+            "autonomous": autonomous,
+            "phone": phone,
+            "girls_hostel_capacity": 120
+        })
+    };
+
+    let response = upload_json(
+        "/api/admin/upload/json",
+        serde_json::json!([
+            college("Dry Run Test College A", false, Some("0233-2200000")),
+            college("Dry Run Test College B", false, None),
+        ]),
+    )
+    .await
+    .unwrap();
+    let result = wait_for_job(&app, &token, response).await;
+    assert_eq!(result["status"], "completed");
+    assert_eq!(result["inserted"], 2);
+
+    let mut invalid = college("Dry Run Test College D", false, None);
+    invalid["university"] = serde_json::json!("No Such University");
+    let response = upload_json(
+        "/api/admin/upload/json?dry_run=true",
+        serde_json::json!([
+            college("Dry Run Test College A", true, None),
+            college("Dry Run Test College B", false, None),
+            college("Dry Run Test College C", false, Some("0233-2300000")),
+            invalid,
+            // Listed twice: diffed against the row above rather than the database
+            college("Dry Run Test College C", true, None),
+        ]),
+    )
+    .await
+    .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let preview = json(response).await;
+    assert_eq!(preview["dry_run"], true);
+    assert_eq!(preview["inserted"], 1);
+    assert_eq!(preview["updated"], 2);
+    assert_eq!(preview["unchanged"], 1);
+    let errors = preview["errors"].as_array().unwrap();
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].as_str().unwrap().starts_with("College #4:"));
+
+    let rows = preview["rows"].as_array().unwrap();
+    assert_eq!(rows[0]["action"], "update");
+    assert_eq!(
+        rows[0]["changes"],
+        serde_json::json!({ "autonomous": { "before": false, "after": true } })
+    );
+    assert_eq!(rows[1]["action"], "unchanged");
+    assert_eq!(rows[2]["action"], "insert");
+    assert_eq!(rows[2]["changes"]["phone"]["after"], "0233-2300000");
+    assert_eq!(rows[2]["changes"]["girls_hostel_capacity"]["after"], 120);
+    assert_eq!(rows[3]["row"], 5);
+    assert_eq!(
+        rows[3]["changes"],
+        serde_json::json!({ "autonomous": { "before": false, "after": true } })
+    );
+
+    // Nothing was written
+    let (inserted, autonomous): (i64, bool) = sqlx::query_as(
+        r#"
+        SELECT COUNT(*) FILTER (WHERE name = 'Dry Run Test College C'),
+               bool_or(autonomous)
+        FROM colleges WHERE name LIKE 'Dry Run Test College%'
+        "#,
+    )
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+    assert_eq!(inserted, 0);
+    assert!(!autonomous);
+
+    let csv = "name,category,district,city,type,established_year\n\
+               Dry Run Test College B,Science,Sangli,Miraj,Private,1999\n\
+               Dry Run Test College E,Science,Sangli,Miraj,Private,abc\n";
+    let body = format!(
+        "--boundary\r\nContent-Disposition: form-data; name=\"file\"; filename=\"colleges.csv\"\r\n\
+         Content-Type: text/csv\r\n\r\n{}\r\n--boundary--\r\n",
+        csv
+    );
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/api/admin/upload/csv?dry_run=true")
+                .header("authorization", format!("Bearer {}", token))
+                .header("content-type", "multipart/form-data; boundary=boundary")
+                .body(Body::from(body))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let preview = json(response).await;
+    assert_eq!(preview["updated"], 1);
+    assert_eq!(
+        preview["rows"][0]["changes"]["established_year"],
+        serde_json::json!({ "before": null, "after": 1999 })
+    );
+    // Hostel details the row leaves out are kept
+    assert!(preview["rows"][0]["changes"]
+        .get("girls_hostel_capacity")
+        .is_none());
+    assert!(preview["errors"][0]
+        .as_str()
+        .unwrap()
+        .starts_with("Line 3: CSV parsing error"));
+
+    sqlx::query("DELETE FROM colleges WHERE name LIKE 'Dry Run Test College%'")
+        .execute(&pool)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_atomic_college_import() {
+    let database_url = env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+    // The job holds its transaction while it saves progress on another connection
+    let pool = PgPoolOptions::new()
+        .max_connections(3)
+        .connect(&database_url)
+        .await
+        .expect("Failed to connect to test database");
+
+    sqlx::query("DELETE FROM colleges WHERE name LIKE 'Atomic Test College%'")
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let app = create_app_with_pool(pool.clone()).await;
+    let token = admin_token(&app, &pool, "test-atomic-admin", "volunteer").await;
+
+    let upload_json = |colleges: serde_json::Value| {
+        app.clone().oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/api/admin/upload/json?atomic=true")
+                .header("authorization", format!("Bearer {}", token))
+                .header("content-type", "application/json")
+                .body(Body::from(
+                    serde_json::json!({ "colleges": colleges }).to_string(),
+                ))
+                .unwrap(),
+        )
+    };
+    let college = |name: &str| {
+        serde_json::json!({
+            "name": name,
+            "category": "Arts",
+            "district": "Latur",
+            "city": "Latur",
+            "type": "Private",
+            "phone": "02382-250000"
+        })
+    };
+    let imported = || async {
+        sqlx::query_scalar::<_, i64>(
+            "SELECT COUNT(*) FROM colleges WHERE name LIKE 'Atomic Test College%'",
+        )
+        .fetch_one(&pool)
+        .await
+        .unwrap()
+    };
+
+    let mut unknown_university = college("Atomic Test College B");
+    unknown_university["university"] = serde_json::json!("No Such University");
+    let mut bad_policy = college("Atomic Test College D");
+    bad_policy["gender_policy"] = serde_json::json!("nobody");
+    let response = upload_json(serde_json::json!([
+        college("Atomic Test College A"),
+        unknown_university,
+        college("Atomic Test College C"),
+        bad_policy,
+    ]))
+    .await
+    .unwrap();
+    let result = wait_for_job(&app, &token, response).await;
+    assert_eq!(result["status"], "completed");
+    assert_eq!(result["inserted"], 0);
+    let errors = result["errors"].as_array().unwrap();
+    assert_eq!(errors.len(), 2);
+    assert!(errors[0].as_str().unwrap().starts_with("College #2:"));
+    assert!(errors[1].as_str().unwrap().starts_with("College #4:"));
+    assert!(result["message"].as_str().unwrap().contains("rolled back"));
+    assert_eq!(imported().await, 0);
+
+    let response = upload_json(serde_json::json!([
+        college("Atomic Test College A"),
+        college("Atomic Test College C"),
+    ]))
+    .await
+    .unwrap();
+    let result = wait_for_job(&app, &token, response).await;
+    assert_eq!(result["inserted"], 2);
+    assert!(result["errors"].as_array().unwrap().is_empty());
+    assert_eq!(imported().await, 2);
+
+    sqlx::query("DELETE FROM colleges WHERE name LIKE 'Atomic Test College%'")
+        .execute(&pool)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_batched_college_import() {
+    let database_url = env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+    let pool = PgPoolOptions::new()
+        .max_connections(1)
+        .connect(&database_url)
+        .await
+        .expect("Failed to connect to test database");
+
+    sqlx::query("DELETE FROM colleges WHERE name LIKE 'Batch Test College%'")
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let app = create_app_with_pool(pool.clone()).await;
+    let token = admin_token(&app, &pool, "test-batch-admin", "volunteer").await;
+
+    let upload_json = |colleges: serde_json::Value| {
+        app.clone().oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/api/admin/upload/json")
+                .header("authorization", format!("Bearer {}", token))
+                .header("content-type", "application/json")
+                .body(Body::from(
+                    serde_json::json!({ "colleges": colleges }).to_string(),
+                ))
+                .unwrap(),
+        )
+    };
+    let college = |name: &str, phone: &str| {
+        serde_json::json!({
+            "name": name,
+            "category": "Science",
+            "district": "Satara",
+            "city": "Karad",
+            "type": "Private",
+            "phone": phone
+        })
+    };
+
+    // The database refuses the NUL byte, failing the batch; its rows are then
+    // retried one by one so only that row is reported. A college listed twice
+    // ends up with the values of its last row.
+    let mut refused = college("Batch Test College B", "02164-200002");
+    refused["address"] = serde_json::json!("Bad\u{0}address");
+    let mut hostel = college("Batch Test College A", "02164-200009");
+    hostel["boys_hostel_capacity"] = serde_json::json!(120);
+    let response = upload_json(serde_json::json!([
+        college("Batch Test College A", "02164-200001"),
+        refused,
+        hostel,
+        college("Batch Test College C", "02164-200003"),
+    ]))
+    .await
+    .unwrap();
+    let result = wait_for_job(&app, &token, response).await;
+    assert_eq!(result["inserted"], 2);
+    assert_eq!(result["updated"], 1);
+    let errors = result["errors"].as_array().unwrap();
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].as_str().unwrap().starts_with("College #2:"));
+
+    let (phone, capacity): (Option<String>, Option<i32>) = sqlx::query_as(
+        "SELECT ci.phone, h.capacity FROM colleges c \
+         JOIN contact_info ci ON ci.college_id = c.id \
+         JOIN hostels h ON h.college_id = c.id AND h.hostel_type = 'boys' \
+         WHERE c.name = 'Batch Test College A'",
+    )
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+    assert_eq!(phone.as_deref(), Some("02164-200009"));
+    assert_eq!(capacity, Some(120));
+
+    // Enough rows to span several batches
+    let colleges: Vec<_> = (0..1201)
+        .map(|i| college(&format!("Batch Test College {:04}", i), "02164-210000"))
+        .collect();
+    let response = upload_json(serde_json::Value::Array(colleges))
+        .await
+        .unwrap();
+    let result = wait_for_job(&app, &token, response).await;
+    assert_eq!(result["total_rows"], 1201);
+    assert_eq!(result["processed_rows"], 1201);
+    assert_eq!(result["inserted"], 1201);
+    assert!(result["errors"].as_array().unwrap().is_empty());
+    let contacts: i64 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM contact_info ci JOIN colleges c ON c.id = ci.college_id \
+         WHERE c.name LIKE 'Batch Test College 0%' OR c.name LIKE 'Batch Test College 1%'",
+    )
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+    assert_eq!(contacts, 1201);
+
+    sqlx::query("DELETE FROM colleges WHERE name LIKE 'Batch Test College%'")
+        .execute(&pool)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_streaming_csv_import() {
+    // Records split across chunks parse the same as when read in one piece,
+    // including quoted line breaks, CRLF endings and a missing final newline
+    let csv = "\u{feff}name,city\r\n\
+               Plain College,Pune\r\n\
+               \"Quoted, \"\"Old\"\"\nCampus\",Nashik\r\n\
+               \r\n\
+               Short Row\r\n\
+               Last College,Nagpur";
+    type Row = (usize, Result<HashMap<String, String>, String>);
+    let read = |chunk_size: usize| {
+        let mut stream = CsvStream::new();
+        let mut rows: Vec<Row> = Vec::new();
+        for chunk in csv.as_bytes().chunks(chunk_size) {
+            rows.extend(stream.feed(chunk));
+        }
+        rows.extend(stream.finish());
+        rows
+    };
+
+    let whole = read(csv.len());
+    assert_eq!(whole, read(1));
+    assert_eq!(whole, read(7));
+    assert_eq!(whole.len(), 4);
+    let row = |i: usize| whole[i].1.as_ref().unwrap();
+    assert_eq!(whole[0].0, 2);
+    assert_eq!(row(0)["name"], "Plain College");
+    assert_eq!(row(1)["name"], "Quoted, \"Old\"\nCampus");
+    assert_eq!(row(1)["city"], "Nashik");
+    assert_eq!(whole[2].0, 4);
+    assert!(whole[2]
+        .1
+        .as_ref()
+        .unwrap_err()
+        .contains("found record with 1 fields"));
+    assert_eq!(row(3)["city"], "Nagpur");
+
+    // An upload declared larger than the limit is refused before it is read
+    let database_url = env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+    let pool = PgPoolOptions::new()
+        .max_connections(1)
+        .connect(&database_url)
+        .await
+        .expect("Failed to connect to test database");
+    let app = create_app_with_pool(pool.clone()).await;
+    let token = admin_token(&app, &pool, "test-streaming-admin", "volunteer").await;
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/api/admin/upload/csv")
+                .header("authorization", format!("Bearer {}", token))
+                .header("content-type", "multipart/form-data; boundary=boundary")
+                .header("content-length", (1u64 << 40).to_string())
+                .body(Body::from("--boundary--\r\n"))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::PAYLOAD_TOO_LARGE);
+}
+
+#[tokio::test]
+async fn test_import_jobs() {
+    let database_url = env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+    let pool = PgPoolOptions::new()
+        .max_connections(1)
+        .connect(&database_url)
+        .await
+        .expect("Failed to connect to test database");
+
+    sqlx::query("DELETE FROM colleges WHERE name LIKE 'Job Test College%'")
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let app = create_app_with_pool(pool.clone()).await;
+    let token = admin_token(&app, &pool, "test-jobs-admin", "volunteer").await;
+
+    let send = |method: &str, uri: String| {
+        app.clone().oneshot(
+            Request::builder()
+                .method(method)
+                .uri(uri)
+                .header("authorization", format!("Bearer {}", token))
+                .body(Body::empty())
+                .unwrap(),
+        )
+    };
+    let text = |response: axum::response::Response| async move {
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        String::from_utf8(body.to_vec()).unwrap()
+    };
+
+    // The upload returns the queued job straight away; its rejected rows are kept
+    let csv = "name,category,district,city,type,established_year\n\
+               Job Test College A,Commerce,Jalgaon,Bhusawal,Private,1985\n\
+               Job Test College B,Commerce,Jalgaon,Bhusawal,Private,abc\n\
+               Job Test College C,Commerce,Jalgaon,Bhusawal,Private,1990\n";
+    let body = format!(
+        "--boundary\r\nContent-Disposition: form-data; name=\"file\"; filename=\"colleges.csv\"\r\n\
+         Content-Type: text/csv\r\n\r\n{}\r\n--boundary--\r\n",
+        csv
+    );
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/api/admin/upload/csv")
+                .header("authorization", format!("Bearer {}", token))
+                .header("content-type", "multipart/form-data; boundary=boundary")
+                .body(Body::from(body))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    let job = wait_for_job(&app, &token, response).await;
+    let id = job["id"].as_i64().unwrap();
+    assert_eq!(job["kind"], "colleges_csv");
+    assert_eq!(job["status"], "completed");
+    assert_eq!(job["message"], "CSV upload completed");
+    assert_eq!(job["total_rows"], 3);
+    assert_eq!(job["processed_rows"], 3);
+    assert_eq!(job["inserted"], 2);
+    assert_eq!(job["error_count"], 1);
+    assert!(job["errors"][0]
+        .as_str()
+        .unwrap()
+        .starts_with("Line 3: CSV parsing error"));
+
+    // The event stream of a finished job sends its outcome and ends
+    let response = send("GET", format!("/api/admin/jobs/{}/events", id))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let events = text(response).await;
+    assert!(events.starts_with("event: finished\n"));
+    assert!(events.contains("\"inserted\":2"));
+
+    let response = send("GET", "/api/admin/jobs?status=completed".to_string())
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert!(text(response).await.contains(&format!("\"id\":{},", id)));
+    let response = send("GET", "/api/admin/jobs?status=paused".to_string())
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let response = send("POST", format!("/api/admin/jobs/{}/cancel", id))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+    let response = send("GET", "/api/admin/jobs/0".to_string()).await.unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    // A job interrupted by a restart resumes after the rows it had already handled
+    let admin_id: i32 =
+        sqlx::query_scalar("SELECT id FROM admin_users WHERE username = 'test-jobs-admin'")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+    let resumed: i32 = sqlx::query_scalar(
+        "INSERT INTO import_jobs (kind, status, total_rows, processed_rows, inserted, created_by) \
+         VALUES ('colleges_json', 'running', 2, 1, 1, $1) RETURNING id",
+    )
+    .bind(admin_id)
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+    let college = |name: &str| {
+        serde_json::json!({
+            "name": name,
+            "category": "Commerce",
+            "district": "Jalgaon",
+            "city": "Bhusawal",
+            "type": "Private"
+        })
+    };
+    let imports = import_dir();
+    std::fs::create_dir_all(&imports).unwrap();
+    std::fs::write(
+        imports.join(format!("{}.json", resumed)),
+        serde_json::json!({
+            "colleges": [college("Job Test College D"), college("Job Test College E")]
+        })
+        .to_string(),
+    )
+    .unwrap();
+    resume_import_jobs(&pool).await.unwrap();
+
+    let mut job = serde_json::Value::Null;
+    for _ in 0..600 {
+        let response = send("GET", format!("/api/admin/jobs/{}", resumed))
+            .await
+            .unwrap();
+        job = serde_json::from_str(&text(response).await).unwrap();
+        if job["status"] != "running" {
+            break;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+    }
+    assert_eq!(job["status"], "completed");
+    assert_eq!(job["processed_rows"], 2);
+    assert_eq!(job["inserted"], 2);
+    let names: Vec<String> = sqlx::query_scalar(
+        "SELECT name FROM colleges WHERE name IN ('Job Test College D', 'Job Test College E')",
+    )
+    .fetch_all(&pool)
+    .await
+    .unwrap();
+    assert_eq!(names, vec!["Job Test College E".to_string()]);
+    assert!(!imports.join(format!("{}.json", resumed)).exists());
+
+    // A queued job is cancelled before it starts
+    let queued: i32 = sqlx::query_scalar(
+        "INSERT INTO import_jobs (kind, created_by) VALUES ('colleges_csv', $1) RETURNING id",
+    )
+    .bind(admin_id)
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+    let response = send("POST", format!("/api/admin/jobs/{}/cancel", queued))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let job: serde_json::Value = serde_json::from_str(&text(response).await).unwrap();
+    assert_eq!(job["status"], "cancelled");
+    assert_eq!(job["cancel_requested"], true);
+
+    sqlx::query("DELETE FROM import_jobs WHERE id IN ($1, $2, $3)")
+        .bind(id as i32)
+        .bind(resumed)
+        .bind(queued)
+        .execute(&pool)
+        .await
+        .unwrap();
+    sqlx::query("DELETE FROM colleges WHERE name LIKE 'Job Test College%'")
+        .execute(&pool)
+        .await
+        .unwrap();
+}
+
+// A spreadsheet row; cells that parse as numbers are stored as numbers
+type SheetRows<'a> = &'a [&'a [&'a str]];
+
+fn xml_escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+// Build an XLSX workbook with the given sheets, in order
+fn xlsx_workbook(sheets: &[(&str, SheetRows)]) -> Vec<u8> {
+    use std::io::Write;
+
+    let mut zip = zip::ZipWriter::new(std::io::Cursor::new(Vec::new()));
+    let options = zip::write::SimpleFileOptions::default();
+    let mut file = |name: &str, contents: String| {
+        zip.start_file(name, options).unwrap();
+        zip.write_all(contents.as_bytes()).unwrap();
+    };
+
+    let overrides: String = (1..=sheets.len())
+        .map(|i| {
+            format!(
+                "<Override PartName=\"/xl/worksheets/sheet{}.xml\" ContentType=\"application/vnd.openxmlformats-officedocument.spreadsheetml.worksheet+xml\"/>",
+                i
+            )
+        })
+        .collect();
+    file(
+        "[Content_Types].xml",
+        format!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\
+             <Types xmlns=\"http://schemas.openxmlformats.org/package/2006/content-types\">\
+             <Default Extension=\"rels\" ContentType=\"application/vnd.openxmlformats-package.relationships+xml\"/>\
+             <Default Extension=\"xml\" ContentType=\"application/xml\"/>\
+             <Override PartName=\"/xl/workbook.xml\" ContentType=\"application/vnd.openxmlformats-officedocument.spreadsheetml.sheet.main+xml\"/>\
+             {}</Types>",
+            overrides
+        ),
+    );
+    file(
+        "_rels/.rels",
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\
+         <Relationships xmlns=\"http://schemas.openxmlformats.org/package/2006/relationships\">\
+         <Relationship Id=\"rId1\" Type=\"http://schemas.openxmlformats.org/officeDocument/2006/relationships/officeDocument\" Target=\"xl/workbook.xml\"/>\
+         </Relationships>"
+            .to_string(),
+    );
+
+    let mut entries = String::new();
+    let mut relationships = String::new();
+    for (i, (name, rows)) in sheets.iter().enumerate() {
+        let n = i + 1;
+        entries.push_str(&format!(
+            "<sheet name=\"{}\" sheetId=\"{}\" r:id=\"rId{}\"/>",
+            xml_escape(name),
+            n,
+            n
+        ));
+        relationships.push_str(&format!(
+            "<Relationship Id=\"rId{}\" Type=\"http://schemas.openxmlformats.org/officeDocument/2006/relationships/worksheet\" Target=\"worksheets/sheet{}.xml\"/>",
+            n, n
+        ));
+
+        let mut data = String::new();
+        for (r, cells) in rows.iter().enumerate() {
+            data.push_str(&format!("<row r=\"{}\">", r + 1));
+            for (c, value) in cells.iter().enumerate() {
+                let reference = format!("{}{}", (b'A' + c as u8) as char, r + 1);
+                if value.is_empty() {
+                    continue;
+                } else if value.parse::<f64>().is_ok() {
+                    data.push_str(&format!("<c r=\"{}\"><v>{}</v></c>", reference, value));
+                } else {
+                    data.push_str(&format!(
+                        "<c r=\"{}\" t=\"inlineStr\"><is><t>{}</t></is></c>",
+                        reference,
+                        xml_escape(value)
+                    ));
+                }
+            }
+            data.push_str("</row>");
+        }
+        file(
+            &format!("xl/worksheets/sheet{}.xml", n),
+            format!(
+                "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\
+                 <worksheet xmlns=\"http://schemas.openxmlformats.org/spreadsheetml/2006/main\">\
+                 <sheetData>{}</sheetData></worksheet>",
+                data
+            ),
+        );
+    }
+    file(
+        "xl/workbook.xml",
+        format!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\
+             <workbook xmlns=\"http://schemas.openxmlformats.org/spreadsheetml/2006/main\" \
+             xmlns:r=\"http://schemas.openxmlformats.org/officeDocument/2006/relationships\">\
+             <sheets>{}</sheets></workbook>",
+            entries
+        ),
+    );
+    file(
+        "xl/_rels/workbook.xml.rels",
+        format!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\
+             <Relationships xmlns=\"http://schemas.openxmlformats.org/package/2006/relationships\">\
+             {}</Relationships>",
+            relationships
+        ),
+    );
+
+    zip.finish().unwrap().into_inner()
+}
+
+// Build an ODS workbook with one sheet
+fn ods_workbook(name: &str, rows: SheetRows) -> Vec<u8> {
+    use std::io::Write;
+
+    let mut zip = zip::ZipWriter::new(std::io::Cursor::new(Vec::new()));
+    let options = zip::write::SimpleFileOptions::default();
+    let mut file = |name: &str, contents: String| {
+        zip.start_file(name, options).unwrap();
+        zip.write_all(contents.as_bytes()).unwrap();
+    };
+
+    file(
+        "mimetype",
+        "application/vnd.oasis.opendocument.spreadsheet".to_string(),
+    );
+    file(
+        "META-INF/manifest.xml",
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\
+         <manifest:manifest xmlns:manifest=\"urn:oasis:names:tc:opendocument:xmlns:manifest:1.0\">\
+         <manifest:file-entry manifest:full-path=\"/\" manifest:media-type=\"application/vnd.oasis.opendocument.spreadsheet\"/>\
+         <manifest:file-entry manifest:full-path=\"content.xml\" manifest:media-type=\"text/xml\"/>\
+         </manifest:manifest>"
+            .to_string(),
+    );
+
+    let mut data = String::new();
+    for cells in rows {
+        data.push_str("<table:table-row>");
+        for value in cells.iter() {
+            if value.is_empty() {
+                data.push_str("<table:table-cell/>");
+            } else if value.parse::<f64>().is_ok() {
+                data.push_str(&format!(
+                    "<table:table-cell office:value-type=\"float\" office:value=\"{0}\"><text:p>{0}</text:p></table:table-cell>",
+                    value
+                ));
+            } else {
+                data.push_str(&format!(
+                    "<table:table-cell office:value-type=\"string\"><text:p>{}</text:p></table:table-cell>",
+                    xml_escape(value)
+                ));
+            }
+        }
+        data.push_str("</table:table-row>");
+    }
+    file(
+        "content.xml",
+        format!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\
+             <office:document-content xmlns:office=\"urn:oasis:names:tc:opendocument:xmlns:office:1.0\" \
+             xmlns:table=\"urn:oasis:names:tc:opendocument:xmlns:table:1.0\" \
+             xmlns:text=\"urn:oasis:names:tc:opendocument:xmlns:text:1.0\" office:version=\"1.2\">\
+             <office:body><office:spreadsheet><table:table table:name=\"{}\">{}</table:table>\
+             </office:spreadsheet></office:body></office:document-content>",
+            xml_escape(name),
+            data
+        ),
+    );
+
+    zip.finish().unwrap().into_inner()
+}
+
+#[tokio::test]
+async fn test_spreadsheet_import() {
+    let database_url = env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+    let pool = PgPoolOptions::new()
+        .max_connections(1)
+        .connect(&database_url)
+        .await
+        .expect("Failed to connect to test database");
+
+    sqlx::query("DELETE FROM colleges WHERE name LIKE 'Spreadsheet Test College%'")
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let app = create_app_with_pool(pool.clone()).await;
+    let token = admin_token(&app, &pool, "test-spreadsheet-admin", "volunteer").await;
+
+    let upload = |uri: &str, file_name: &str, data: Vec<u8>| {
+        let mut body = format!(
+            "--boundary\r\nContent-Disposition: form-data; name=\"file\"; filename=\"{}\"\r\n\
+             Content-Type: application/octet-stream\r\n\r\n",
+            file_name
+        )
+        .into_bytes();
+        body.extend(data);
+        body.extend(b"\r\n--boundary--\r\n");
+        app.clone().oneshot(
+            Request::builder()
+                .method("POST")
+                .uri(uri)
+                .header("authorization", format!("Bearer {}", token))
+                .header("content-type", "multipart/form-data; boundary=boundary")
+                .body(Body::from(body))
+                .unwrap(),
+        )
+    };
+    let json = |response: axum::response::Response| async move {
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        serde_json::from_slice::<serde_json::Value>(&body).unwrap()
+    };
+
+    // The table sits on the second sheet below a title, with a blank row inside it
+    let notes: SheetRows = &[&["Prepared by the admissions cell"]];
+    let colleges: SheetRows = &[
+        &["Colleges 2025"],
+        &[],
+        &[
+            " name ",
+            "category",
+            "district",
+            "city",
+            "type",
+            "established_year",
+            "autonomous",
+        ],
+        &[
+            "Spreadsheet Test College A",
+            "Science",
+            "Nanded",
+            "Nanded",
+            "Private",
+            "1985",
+            "true",
+        ],
+        &[
+            "Spreadsheet Test College B",
+            "Science",
+            "Nanded",
+            "Nanded",
+            "Private",
+            "abc",
+            "false",
+        ],
+        &[],
+        &[
+            "Spreadsheet Test College C",
+            "Science",
+            "Nanded",
+            "Nanded",
+            "Private",
+            "1990",
+            "false",
+        ],
+    ];
+    let workbook = xlsx_workbook(&[("Notes", notes), ("Colleges", colleges)]);
+
+    let response = upload(
+        "/api/admin/upload/spreadsheet?dry_run=true&sheet=Colleges&header_row=3",
+        "colleges.xlsx",
+        workbook.clone(),
+    )
+    .await
+    .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let preview = json(response).await;
+    assert_eq!(preview["inserted"], 2);
+    assert_eq!(preview["rows"][1]["row"], 7);
+    assert!(preview["errors"][0]
+        .as_str()
+        .unwrap()
+        .starts_with("Sheet 'Colleges' row 5: column 'established_year':"));
+
+    let response = upload(
+        "/api/admin/upload/spreadsheet?sheet=Colleges&header_row=3",
+        "colleges.xlsx",
+        workbook.clone(),
+    )
+    .await
+    .unwrap();
+    let job = wait_for_job(&app, &token, response).await;
+    assert_eq!(job["kind"], "colleges_spreadsheet");
+    assert_eq!(job["status"], "completed");
+    assert_eq!(job["sheet"], "Colleges");
+    assert_eq!(job["header_row"], 3);
+    assert_eq!(job["total_rows"], 3);
+    assert_eq!(job["inserted"], 2);
+    assert_eq!(job["errors"].as_array().unwrap().len(), 1);
+    assert!(job["errors"][0]
+        .as_str()
+        .unwrap()
+        .starts_with("Sheet 'Colleges' row 5:"));
+
+    let (college_id, established_year, autonomous): (i32, Option<i32>, Option<bool>) =
+        sqlx::query_as(
+            "SELECT id, established_year, autonomous FROM colleges \
+             WHERE name = 'Spreadsheet Test College A' AND deleted_at IS NULL",
+        )
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!(established_year, Some(1985));
+    assert_eq!(autonomous, Some(true));
+
+    let response = upload(
+        "/api/admin/upload/spreadsheet?sheet=Fees",
+        "colleges.xlsx",
+        workbook.clone(),
+    )
+    .await
+    .unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    assert!(json(response).await["error"]
+        .as_str()
+        .unwrap()
+        .contains("Notes, Colleges"));
+    let response = upload(
+        "/api/admin/upload/spreadsheet?header_row=0",
+        "colleges.xlsx",
+        workbook,
+    )
+    .await
+    .unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    let response = upload(
+        "/api/admin/upload/spreadsheet",
+        "colleges.xlsx",
+        b"name,city\n".to_vec(),
+    )
+    .await
+    .unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    // Cutoffs from an ODS workbook, imported by a job; a second upload replaces them
+    let mut jobs = vec![job["id"].as_i64().unwrap() as i32];
+    let id = college_id.to_string();
+    let cutoffs: &[&[&str]] = &[
+        &["college_id", "year", "branch", "category", "cutoff_marks"],
+        &[&id, "2024", "Computer Engineering", "OPEN", "92.5"],
+        &[&id, "2024", "Computer Engineering", "OBC", "88"],
+        &["999999999", "2024", "Computer Engineering", "OPEN", "90"],
+    ];
+    for expected_updates in [0, 2] {
+        let response = upload(
+            "/api/admin/upload/cutoffs/spreadsheet",
+            "cutoffs.ods",
+            ods_workbook("Cutoffs", cutoffs),
+        )
+        .await
+        .unwrap();
+        let job = wait_for_job(&app, &token, response).await;
+        jobs.push(job["id"].as_i64().unwrap() as i32);
+        assert_eq!(job["kind"], "cutoffs_spreadsheet");
+        assert_eq!(job["status"], "completed");
+        assert_eq!(job["inserted"], 2 - expected_updates);
+        assert_eq!(job["updated"], expected_updates);
+        assert_eq!(
+            job["errors"],
+            serde_json::json!(["Sheet 'Cutoffs' row 4: College 999999999 not found"])
+        );
+    }
+
+    // A cutoff listed twice in one upload ends with its last row's values
+    let csv = format!(
+        "college_id,year,branch,category,cutoff_marks\n\
+         {id},2024,Computer Engineering,OPEN,93\n\
+         {id},2024,Computer Engineering,OPEN,93.25\n",
+        id = college_id
+    );
+    let response = upload(
+        "/api/admin/upload/cutoffs/csv",
+        "cutoffs.csv",
+        csv.into_bytes(),
+    )
+    .await
+    .unwrap();
+    let job = wait_for_job(&app, &token, response).await;
+    jobs.push(job["id"].as_i64().unwrap() as i32);
+    assert_eq!(job["kind"], "cutoffs_csv");
+    assert_eq!(job["status"], "completed");
+    assert_eq!(job["total_rows"], 2);
+    assert_eq!(job["updated"], 2);
+
+    let marks: Vec<f64> = sqlx::query_scalar(
+        "SELECT cutoff_marks::FLOAT8 FROM cutoffs WHERE college_id = $1 ORDER BY category",
+    )
+    .bind(college_id)
+    .fetch_all(&pool)
+    .await
+    .unwrap();
+    assert_eq!(marks, vec![88.0, 93.25]);
+    let audited: i64 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM audit_log \
+         WHERE college_id = $1 AND table_name = 'cutoffs' AND source = 'spreadsheet_upload'",
+    )
+    .bind(college_id)
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+    // The second upload rewrote identical values, which are not logged
+    assert_eq!(audited, 2);
+
+    sqlx::query("DELETE FROM import_jobs WHERE id = ANY($1)")
+        .bind(&jobs)
+        .execute(&pool)
+        .await
+        .unwrap();
+    sqlx::query("DELETE FROM colleges WHERE name LIKE 'Spreadsheet Test College%'")
+        .execute(&pool)
+        .await
+        .unwrap();
+}
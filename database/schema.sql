@@ -61,3 +61,727 @@ CREATE INDEX idx_colleges_district ON colleges(district);
 CREATE INDEX idx_colleges_category ON colleges(category);
 CREATE INDEX idx_colleges_type ON colleges(type);
 CREATE INDEX idx_cutoffs_college_year ON cutoffs(college_id, year);
+
+-- Unique keys used by the bulk importer's ON CONFLICT upserts
+ALTER TABLE colleges
+    ADD CONSTRAINT colleges_name_district_city_key UNIQUE (name, district, city);
+
+ALTER TABLE contact_info
+    ADD CONSTRAINT contact_info_college_id_key UNIQUE (college_id);
+
+-- Fee structure per college, course and academic year (amounts in rupees)
+CREATE TABLE fee_structures (
+    id SERIAL PRIMARY KEY,
+    college_id INTEGER NOT NULL REFERENCES colleges(id) ON DELETE CASCADE,
+    course VARCHAR(100) NOT NULL,
+    year INTEGER NOT NULL,
+    tuition_fee INTEGER NOT NULL DEFAULT 0,
+    development_fee INTEGER NOT NULL DEFAULT 0,
+    exam_fee INTEGER NOT NULL DEFAULT 0,
+    hostel_fee INTEGER,
+    total_fee INTEGER GENERATED ALWAYS AS (tuition_fee + development_fee + exam_fee) STORED,
+    -- Annual fee payable by each admission category, when it differs from total_fee
+    open_fee INTEGER,
+    obc_fee INTEGER,
+    sc_fee INTEGER,
+    st_fee INTEGER,
+    ews_fee INTEGER,
+    tfws_fee INTEGER,
+    created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE (college_id, course, year)
+);
+
+CREATE INDEX idx_fee_structures_college_year ON fee_structures(college_id, year);
+
+-- Data-driven fee concession rules used by the cost-of-attendance estimate.
+-- A rule reduces one fee component by a percentage (optionally capped) for students
+-- matching its categories, income cap and hostel requirement.
+CREATE TABLE concession_rules (
+    id SERIAL PRIMARY KEY,
+    name VARCHAR(100) NOT NULL,
+    description TEXT,
+    academic_year INTEGER, -- NULL applies to every year
+    categories TEXT[] NOT NULL DEFAULT '{}', -- empty applies to every category
+    max_family_income INTEGER, -- NULL means no income cap
+    requires_hostel BOOLEAN NOT NULL DEFAULT FALSE,
+    component VARCHAR(20) NOT NULL CHECK (component IN ('tuition', 'development', 'exam', 'hostel')),
+    percentage INTEGER NOT NULL CHECK (percentage BETWEEN 0 AND 100),
+    max_amount INTEGER, -- yearly cap on the concession, NULL means uncapped
+    active BOOLEAN NOT NULL DEFAULT TRUE,
+    created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP
+);
+
+-- Indicative Maharashtra schemes; admins should review these every admission year
+INSERT INTO concession_rules (name, description, categories, max_family_income, component, percentage) VALUES
+    ('EBC (Rajarshri Chhatrapati Shahu Maharaj Shikshan Shulkh Shishyavrutti)',
+     '50% tuition fee reimbursement for economically backward OPEN/EWS students',
+     '{OPEN,EWS}', 800000, 'tuition', 50),
+    ('TFWS seat', 'Tuition fee waiver for students admitted on TFWS seats',
+     '{TFWS}', 800000, 'tuition', 100),
+    ('OBC tuition fee scholarship', '50% tuition fee reimbursement for OBC/SEBC/VJNT/SBC students',
+     '{OBC,SEBC,VJNT,SBC}', 800000, 'tuition', 50),
+    ('Post-matric scholarship (tuition)', 'Full tuition fee reimbursement for SC/ST students',
+     '{SC,ST}', 250000, 'tuition', 100),
+    ('Post-matric scholarship (development)', 'Full development fee reimbursement for SC/ST students',
+     '{SC,ST}', 250000, 'development', 100),
+    ('Post-matric scholarship (exam)', 'Full exam fee reimbursement for SC/ST students',
+     '{SC,ST}', 250000, 'exam', 100);
+
+-- Scholarship schemes with structured eligibility rules.
+-- Empty arrays and NULL caps mean the scheme does not restrict on that attribute.
+CREATE TABLE scholarships (
+    id SERIAL PRIMARY KEY,
+    name VARCHAR(255) NOT NULL,
+    provider VARCHAR(100) NOT NULL, -- MahaDBT, Central (NSP), AICTE, etc.
+    description TEXT,
+    url VARCHAR(500),
+    max_amount INTEGER, -- maximum yearly benefit in rupees
+    categories TEXT[] NOT NULL DEFAULT '{}',
+    max_family_income INTEGER,
+    gender VARCHAR(10) CHECK (gender IN ('female', 'male')),
+    courses TEXT[] NOT NULL DEFAULT '{}', -- college categories: Engineering, Medical, etc.
+    minority_only BOOLEAN NOT NULL DEFAULT FALSE,
+    requires_domicile BOOLEAN NOT NULL DEFAULT FALSE, -- Maharashtra domicile
+    active BOOLEAN NOT NULL DEFAULT TRUE,
+    created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP
+);
+
+INSERT INTO scholarships (name, provider, description, url, categories, max_family_income, gender, courses, minority_only, requires_domicile) VALUES
+    ('Government of India Post-Matric Scholarship (SC)', 'MahaDBT',
+     'Tuition, exam fee and maintenance allowance for SC students', 'https://mahadbt.maharashtra.gov.in',
+     '{SC}', 250000, NULL, '{}', FALSE, TRUE),
+    ('Post-Matric Scholarship for ST Students', 'MahaDBT',
+     'Tuition, exam fee and maintenance allowance for ST students', 'https://mahadbt.maharashtra.gov.in',
+     '{ST}', 250000, NULL, '{}', FALSE, TRUE),
+    ('Post-Matric Scholarship for OBC/VJNT/SBC Students', 'MahaDBT',
+     'Fee reimbursement and maintenance allowance', 'https://mahadbt.maharashtra.gov.in',
+     '{OBC,VJNT,SBC}', 150000, NULL, '{}', FALSE, TRUE),
+    ('Rajarshi Chhatrapati Shahu Maharaj Shikshan Shulkh Shishyavrutti Yojana', 'MahaDBT',
+     'Tuition fee reimbursement for economically backward students', 'https://mahadbt.maharashtra.gov.in',
+     '{OPEN,EWS}', 800000, NULL, '{}', FALSE, TRUE),
+    ('Dr. Panjabrao Deshmukh Vastigruh Nirvah Bhatta Yojana', 'MahaDBT',
+     'Hostel maintenance allowance for students staying away from home', 'https://mahadbt.maharashtra.gov.in',
+     '{OPEN,EWS,OBC}', 800000, NULL, '{}', FALSE, TRUE),
+    ('Central Sector Scheme of Scholarships for College and University Students', 'Central (NSP)',
+     'Merit-based scholarship for students above the 80th percentile in Class XII', 'https://scholarships.gov.in',
+     '{}', 450000, NULL, '{}', FALSE, FALSE),
+    ('AICTE Pragati Scholarship for Girls', 'AICTE',
+     'Scholarship for girl students in AICTE-approved technical institutions', 'https://scholarships.gov.in',
+     '{}', 800000, 'female', '{Engineering,Pharmacy}', FALSE, FALSE),
+    ('Post-Matric Scholarship for Minorities', 'Central (NSP)',
+     'Scholarship for students from notified minority communities', 'https://scholarships.gov.in',
+     '{}', 200000, NULL, '{}', TRUE, FALSE);
+
+-- NAAC institutional accreditation cycles
+CREATE TABLE naac_accreditations (
+    id SERIAL PRIMARY KEY,
+    college_id INTEGER NOT NULL REFERENCES colleges(id) ON DELETE CASCADE,
+    grade VARCHAR(5) NOT NULL CHECK (grade IN ('A++', 'A+', 'A', 'B++', 'B+', 'B', 'C', 'D')),
+    cgpa DOUBLE PRECISION,
+    cycle INTEGER,
+    valid_from DATE NOT NULL,
+    valid_until DATE NOT NULL,
+    created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE (college_id, valid_from)
+);
+
+-- NBA accreditation of individual programmes
+CREATE TABLE nba_accreditations (
+    id SERIAL PRIMARY KEY,
+    college_id INTEGER NOT NULL REFERENCES colleges(id) ON DELETE CASCADE,
+    programme VARCHAR(255) NOT NULL,
+    valid_from DATE NOT NULL,
+    valid_until DATE NOT NULL,
+    created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE (college_id, programme, valid_from)
+);
+
+-- NIRF rankings per year and ranking category (Overall, Engineering, College, ...)
+CREATE TABLE nirf_rankings (
+    id SERIAL PRIMARY KEY,
+    college_id INTEGER NOT NULL REFERENCES colleges(id) ON DELETE CASCADE,
+    year INTEGER NOT NULL,
+    category VARCHAR(50) NOT NULL,
+    rank INTEGER, -- exact rank, published for the top 100/200
+    rank_band VARCHAR(20), -- e.g. 101-150 when only a band is published
+    score DOUBLE PRECISION,
+    created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE (college_id, year, category),
+    CHECK (rank IS NOT NULL OR rank_band IS NOT NULL)
+);
+
+CREATE INDEX idx_naac_college ON naac_accreditations(college_id);
+CREATE INDEX idx_nba_college ON nba_accreditations(college_id);
+CREATE INDEX idx_nirf_college_year ON nirf_rankings(college_id, year);
+
+-- Orders NAAC grades so filters can compare them (higher is better)
+CREATE FUNCTION naac_grade_rank(grade TEXT) RETURNS INTEGER AS $$
+    SELECT CASE grade
+        WHEN 'A++' THEN 8
+        WHEN 'A+' THEN 7
+        WHEN 'A' THEN 6
+        WHEN 'B++' THEN 5
+        WHEN 'B+' THEN 4
+        WHEN 'B' THEN 3
+        WHEN 'C' THEN 2
+        WHEN 'D' THEN 1
+        ELSE 0
+    END
+$$ LANGUAGE SQL IMMUTABLE;
+
+-- Universities that colleges are affiliated to
+CREATE TABLE universities (
+    id SERIAL PRIMARY KEY,
+    name VARCHAR(255) NOT NULL UNIQUE,
+    short_name VARCHAR(50) NOT NULL UNIQUE, -- SPPU, MU, RTMNU, etc.
+    city VARCHAR(100),
+    university_type VARCHAR(50), -- State, Deemed, Private, Central
+    website VARCHAR(255),
+    created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP
+);
+
+ALTER TABLE colleges
+    ADD COLUMN university_id INTEGER REFERENCES universities(id) ON DELETE SET NULL;
+
+CREATE INDEX idx_colleges_university ON colleges(university_id);
+
+INSERT INTO universities (name, short_name, city, university_type, website) VALUES
+    ('Savitribai Phule Pune University', 'SPPU', 'Pune', 'State', 'https://www.unipune.ac.in'),
+    ('University of Mumbai', 'MU', 'Mumbai', 'State', 'https://mu.ac.in'),
+    ('Rashtrasant Tukadoji Maharaj Nagpur University', 'RTMNU', 'Nagpur', 'State', 'https://www.nagpuruniversity.ac.in'),
+    ('Dr. Babasaheb Ambedkar Technological University', 'DBATU', 'Lonere', 'State', 'https://dbatu.ac.in'),
+    ('Dr. Babasaheb Ambedkar Marathwada University', 'BAMU', 'Chhatrapati Sambhajinagar', 'State', 'https://www.bamu.ac.in'),
+    ('Shivaji University', 'SUK', 'Kolhapur', 'State', 'https://www.unishivaji.ac.in'),
+    ('Sant Gadge Baba Amravati University', 'SGBAU', 'Amravati', 'State', 'https://www.sgbau.ac.in'),
+    ('Swami Ramanand Teerth Marathwada University', 'SRTMU', 'Nanded', 'State', 'https://srtmun.ac.in'),
+    ('Kavayitri Bahinabai Chaudhari North Maharashtra University', 'KBCNMU', 'Jalgaon', 'State', 'https://nmu.ac.in'),
+    ('Punyashlok Ahilyadevi Holkar Solapur University', 'PAHSU', 'Solapur', 'State', 'https://su.digitaluniversity.ac'),
+    ('Gondwana University', 'GUG', 'Gadchiroli', 'State', 'https://unigug.ac.in'),
+    ('SNDT Women''s University', 'SNDT', 'Mumbai', 'State', 'https://sndt.ac.in'),
+    ('Maharashtra University of Health Sciences', 'MUHS', 'Nashik', 'State', 'https://www.muhs.ac.in');
+
+-- Placement outcomes per college, branch and year (packages in lakhs per annum)
+CREATE TABLE placement_stats (
+    id SERIAL PRIMARY KEY,
+    college_id INTEGER NOT NULL REFERENCES colleges(id) ON DELETE CASCADE,
+    year INTEGER NOT NULL,
+    branch VARCHAR(100) NOT NULL,
+    students_eligible INTEGER NOT NULL CHECK (students_eligible >= 0),
+    students_placed INTEGER NOT NULL CHECK (students_placed >= 0),
+    placement_rate DOUBLE PRECISION GENERATED ALWAYS AS (
+        CASE WHEN students_eligible > 0
+            THEN students_placed * 100.0 / students_eligible
+        END
+    ) STORED,
+    median_package DOUBLE PRECISION,
+    highest_package DOUBLE PRECISION,
+    top_recruiters TEXT[] NOT NULL DEFAULT '{}',
+    created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE (college_id, branch, year),
+    CHECK (students_placed <= students_eligible)
+);
+
+CREATE INDEX idx_placement_stats_college_year ON placement_stats(college_id, year);
+
+-- Managed facility/amenity taxonomy
+CREATE TABLE facilities (
+    id SERIAL PRIMARY KEY,
+    slug VARCHAR(50) NOT NULL UNIQUE, -- used by the facilities= filter
+    name VARCHAR(100) NOT NULL,
+    description TEXT,
+    created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP
+);
+
+-- Facilities offered by each college
+CREATE TABLE college_facilities (
+    college_id INTEGER NOT NULL REFERENCES colleges(id) ON DELETE CASCADE,
+    facility_id INTEGER NOT NULL REFERENCES facilities(id) ON DELETE CASCADE,
+    PRIMARY KEY (college_id, facility_id)
+);
+
+CREATE INDEX idx_college_facilities_facility ON college_facilities(facility_id);
+
+INSERT INTO facilities (slug, name) VALUES
+    ('library', 'Library'),
+    ('lab', 'Laboratories'),
+    ('sports_complex', 'Sports complex'),
+    ('wheelchair_access', 'Wheelchair access'),
+    ('wifi', 'Wi-Fi campus'),
+    ('canteen', 'Canteen'),
+    ('transport', 'College transport'),
+    ('girls_hostel', 'Girls'' hostel');
+
+-- Hostel details per college, one row per hostel type
+CREATE TABLE hostels (
+    id SERIAL PRIMARY KEY,
+    college_id INTEGER NOT NULL REFERENCES colleges(id) ON DELETE CASCADE,
+    hostel_type VARCHAR(10) NOT NULL CHECK (hostel_type IN ('boys', 'girls')),
+    capacity INTEGER CHECK (capacity >= 0),
+    annual_fee INTEGER CHECK (annual_fee >= 0), -- rupees per year
+    mess_available BOOLEAN,
+    distance_km DOUBLE PRECISION CHECK (distance_km >= 0), -- from the main campus
+    first_year_guaranteed BOOLEAN,
+    created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE (college_id, hostel_type)
+);
+
+-- Gender admission policy and languages of instruction
+ALTER TABLE colleges
+    ADD COLUMN gender_policy VARCHAR(20) NOT NULL DEFAULT 'co-ed'
+        CHECK (gender_policy IN ('co-ed', 'women-only', 'men-only')),
+    ADD COLUMN instruction_languages TEXT[] NOT NULL DEFAULT '{}'; -- Marathi, English, Hindi, ...
+
+CREATE INDEX idx_colleges_gender_policy ON colleges(gender_policy);
+CREATE INDEX idx_colleges_instruction_languages ON colleges USING GIN (instruction_languages);
+
+-- Images belong to exactly one college, with at most one primary image each
+DELETE FROM college_images WHERE college_id IS NULL;
+
+UPDATE college_images SET is_primary = FALSE
+WHERE is_primary IS NULL
+   OR (is_primary AND id <> (
+        SELECT MIN(i.id) FROM college_images i
+        WHERE i.college_id = college_images.college_id AND i.is_primary
+   ));
+
+ALTER TABLE college_images
+    ALTER COLUMN college_id SET NOT NULL,
+    ALTER COLUMN is_primary SET NOT NULL,
+    ALTER COLUMN uploaded_at TYPE TIMESTAMPTZ;
+
+CREATE INDEX idx_college_images_college ON college_images(college_id);
+CREATE UNIQUE INDEX idx_college_images_one_primary ON college_images(college_id) WHERE is_primary;
+
+-- Uploaded images are identified by the SHA-256 of their bytes so identical
+-- uploads for a college are stored once
+ALTER TABLE college_images
+    ADD COLUMN content_hash VARCHAR(64),
+    ADD COLUMN width INTEGER CHECK (width > 0),
+    ADD COLUMN height INTEGER CHECK (height > 0);
+
+CREATE UNIQUE INDEX idx_college_images_content_hash ON college_images(college_id, content_hash);
+
+-- Metadata-free WebP renditions generated from each uploaded image
+CREATE TABLE image_renditions (
+    id SERIAL PRIMARY KEY,
+    image_id INTEGER NOT NULL REFERENCES college_images(id) ON DELETE CASCADE,
+    variant VARCHAR(20) NOT NULL CHECK (variant IN ('thumbnail', 'card', 'hero')),
+    width INTEGER NOT NULL CHECK (width > 0),
+    height INTEGER NOT NULL CHECK (height > 0),
+    byte_size INTEGER NOT NULL CHECK (byte_size >= 0),
+    file_path VARCHAR(500) NOT NULL, -- relative to UPLOAD_DIR
+    created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE (image_id, variant)
+);
+
+-- Documents published by colleges (prospectuses, fee notices, cutoff lists)
+CREATE TABLE college_attachments (
+    id SERIAL PRIMARY KEY,
+    college_id INTEGER NOT NULL REFERENCES colleges(id) ON DELETE CASCADE,
+    document_type VARCHAR(30) NOT NULL
+        CHECK (document_type IN ('prospectus', 'fee_notice', 'cutoff', 'brochure', 'other')),
+    academic_year VARCHAR(7) CHECK (academic_year ~ '^[0-9]{4}-[0-9]{2}$'), -- e.g. 2024-25
+    title VARCHAR(255) NOT NULL,
+    file_name VARCHAR(255) NOT NULL, -- original upload name
+    content_type VARCHAR(100) NOT NULL,
+    byte_size BIGINT NOT NULL CHECK (byte_size > 0),
+    checksum VARCHAR(64) NOT NULL, -- SHA-256 of the file
+    file_path VARCHAR(500) NOT NULL, -- relative to UPLOAD_DIR
+    uploaded_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE (college_id, checksum)
+);
+
+CREATE INDEX idx_college_attachments_college ON college_attachments(college_id);
+
+-- Accounts allowed to use the /api/admin endpoints
+CREATE TABLE admin_users (
+    id SERIAL PRIMARY KEY,
+    username VARCHAR(100) NOT NULL UNIQUE, -- stored lowercase
+    password_hash TEXT NOT NULL, -- Argon2id PHC string
+    active BOOLEAN NOT NULL DEFAULT TRUE,
+    created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP,
+    last_login_at TIMESTAMPTZ
+);
+
+-- One row per issued login token; revoking the row invalidates the token
+CREATE TABLE admin_sessions (
+    id BIGSERIAL PRIMARY KEY,
+    user_id INTEGER NOT NULL REFERENCES admin_users(id) ON DELETE CASCADE,
+    issued_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    expires_at TIMESTAMPTZ NOT NULL,
+    revoked_at TIMESTAMPTZ
+);
+
+CREATE INDEX idx_admin_sessions_user ON admin_sessions(user_id);
+
+-- Privilege level of each admin account
+ALTER TABLE admin_users
+    ADD COLUMN role VARCHAR(20) NOT NULL DEFAULT 'volunteer'
+        CHECK (role IN ('volunteer', 'reviewer', 'superadmin'));
+
+-- Accounts created before roles existed had full access
+UPDATE admin_users SET role = 'superadmin';
+
+-- College representatives: admin accounts limited to proposing changes for one college
+ALTER TABLE admin_users DROP CONSTRAINT admin_users_role_check;
+ALTER TABLE admin_users
+    ADD CONSTRAINT admin_users_role_check
+        CHECK (role IN ('representative', 'volunteer', 'reviewer', 'superadmin')),
+    ADD COLUMN college_id INTEGER REFERENCES colleges(id) ON DELETE CASCADE,
+    ADD CONSTRAINT admin_users_college_scope_check
+        CHECK ((role = 'representative') = (college_id IS NOT NULL));
+
+-- Requests from institutions to manage a college profile; approving one creates
+-- the representative account
+CREATE TABLE representative_claims (
+    id SERIAL PRIMARY KEY,
+    college_id INTEGER NOT NULL REFERENCES colleges(id) ON DELETE CASCADE,
+    username VARCHAR(100) NOT NULL, -- stored lowercase
+    password_hash TEXT NOT NULL,
+    full_name VARCHAR(255) NOT NULL,
+    designation VARCHAR(255),
+    email VARCHAR(255) NOT NULL,
+    phone VARCHAR(20),
+    message TEXT, -- how the claimant can be verified
+    status VARCHAR(20) NOT NULL DEFAULT 'pending'
+        CHECK (status IN ('pending', 'approved', 'rejected')),
+    review_note TEXT,
+    reviewed_by INTEGER REFERENCES admin_users(id) ON DELETE SET NULL,
+    reviewed_at TIMESTAMPTZ,
+    user_id INTEGER REFERENCES admin_users(id) ON DELETE SET NULL, -- account created on approval
+    created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE UNIQUE INDEX idx_representative_claims_pending_username
+    ON representative_claims(username) WHERE status = 'pending';
+CREATE INDEX idx_representative_claims_status ON representative_claims(status, created_at);
+
+-- Changes proposed by representatives, applied only once a reviewer approves them
+CREATE TABLE college_submissions (
+    id SERIAL PRIMARY KEY,
+    college_id INTEGER NOT NULL REFERENCES colleges(id) ON DELETE CASCADE,
+    submitted_by INTEGER REFERENCES admin_users(id) ON DELETE SET NULL,
+    kind VARCHAR(20) NOT NULL CHECK (kind IN ('contact_info', 'courses', 'cutoffs', 'image')),
+    changes JSONB NOT NULL,
+    status VARCHAR(20) NOT NULL DEFAULT 'pending'
+        CHECK (status IN ('pending', 'approved', 'rejected')),
+    review_note TEXT,
+    reviewed_by INTEGER REFERENCES admin_users(id) ON DELETE SET NULL,
+    reviewed_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX idx_college_submissions_status ON college_submissions(status, created_at);
+CREATE INDEX idx_college_submissions_college ON college_submissions(college_id);
+
+-- Images uploaded by representatives stay hidden until their submission is approved
+ALTER TABLE college_images ADD COLUMN published BOOLEAN NOT NULL DEFAULT TRUE;
+
+-- One row per created, updated or deleted record in the audited tables, written by
+-- triggers. The application identifies the actor and source of a change with the
+-- transaction-local settings audit.actor_id, audit.actor and audit.source; changes
+-- made without them (e.g. from psql) are recorded with source 'database'.
+CREATE TABLE audit_log (
+    id BIGSERIAL PRIMARY KEY,
+    occurred_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    actor_id INTEGER REFERENCES admin_users(id) ON DELETE SET NULL,
+    actor VARCHAR(100), -- username at the time of the change
+    source VARCHAR(30) NOT NULL, -- csv_upload, json_upload, manual_edit, image_upload, submission, database
+    table_name VARCHAR(50) NOT NULL,
+    record_id INTEGER NOT NULL,
+    college_id INTEGER, -- not a foreign key, so the history outlives the college
+    action VARCHAR(10) NOT NULL CHECK (action IN ('create', 'update', 'delete')),
+    changes JSONB NOT NULL -- {"column": {"before": ..., "after": ...}} for changed columns only
+);
+
+CREATE INDEX idx_audit_log_college ON audit_log(college_id, occurred_at);
+CREATE INDEX idx_audit_log_actor ON audit_log(actor, occurred_at);
+CREATE INDEX idx_audit_log_occurred_at ON audit_log(occurred_at);
+
+CREATE FUNCTION record_audit() RETURNS trigger AS $$
+DECLARE
+    old_row JSONB := CASE WHEN TG_OP <> 'INSERT' THEN to_jsonb(OLD) END;
+    new_row JSONB := CASE WHEN TG_OP <> 'DELETE' THEN to_jsonb(NEW) END;
+    current_row JSONB := COALESCE(new_row, old_row);
+    diff JSONB;
+BEGIN
+    SELECT jsonb_object_agg(
+               key, jsonb_build_object('before', old_row -> key, 'after', new_row -> key))
+    INTO diff
+    FROM jsonb_object_keys(current_row) AS key
+    WHERE key <> 'updated_at'
+      AND (old_row -> key) IS DISTINCT FROM (new_row -> key);
+
+    -- Upserts that rewrite identical values are not changes
+    IF diff IS NULL THEN
+        RETURN NULL;
+    END IF;
+
+    INSERT INTO audit_log (actor_id, actor, source, table_name, record_id, college_id, action, changes)
+    VALUES (
+        NULLIF(current_setting('audit.actor_id', TRUE), '')::INTEGER,
+        NULLIF(current_setting('audit.actor', TRUE), ''),
+        COALESCE(NULLIF(current_setting('audit.source', TRUE), ''), 'database'),
+        TG_TABLE_NAME,
+        (current_row ->> 'id')::INTEGER,
+        CASE WHEN TG_TABLE_NAME = 'colleges'
+            THEN (current_row ->> 'id')::INTEGER
+            ELSE (current_row ->> 'college_id')::INTEGER
+        END,
+        CASE TG_OP WHEN 'INSERT' THEN 'create' WHEN 'UPDATE' THEN 'update' ELSE 'delete' END,
+        diff
+    );
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER colleges_audit AFTER INSERT OR UPDATE OR DELETE ON colleges
+    FOR EACH ROW EXECUTE FUNCTION record_audit();
+CREATE TRIGGER contact_info_audit AFTER INSERT OR UPDATE OR DELETE ON contact_info
+    FOR EACH ROW EXECUTE FUNCTION record_audit();
+CREATE TRIGGER cutoffs_audit AFTER INSERT OR UPDATE OR DELETE ON cutoffs
+    FOR EACH ROW EXECUTE FUNCTION record_audit();
+CREATE TRIGGER college_images_audit AFTER INSERT OR UPDATE OR DELETE ON college_images
+    FOR EACH ROW EXECUTE FUNCTION record_audit();
+
+-- Row versions of colleges and contact_info, maintained by triggers. Each version
+-- holds the full row as JSONB and is valid from valid_from until valid_to (NULL
+-- for the current version; closed without a successor when the row is deleted).
+CREATE TABLE record_versions (
+    id BIGSERIAL PRIMARY KEY,
+    table_name VARCHAR(50) NOT NULL,
+    record_id INTEGER NOT NULL,
+    college_id INTEGER NOT NULL, -- not a foreign key, so versions outlive the college
+    valid_from TIMESTAMPTZ NOT NULL,
+    valid_to TIMESTAMPTZ,
+    data JSONB NOT NULL,
+    CHECK (valid_to IS NULL OR valid_to > valid_from)
+);
+
+CREATE UNIQUE INDEX idx_record_versions_current
+    ON record_versions(table_name, record_id) WHERE valid_to IS NULL;
+CREATE INDEX idx_record_versions_college ON record_versions(college_id, valid_from);
+CREATE INDEX idx_record_versions_period ON record_versions(table_name, valid_from, valid_to);
+
+CREATE FUNCTION record_version() RETURNS trigger AS $$
+DECLARE
+    changed_at TIMESTAMPTZ := NOW();
+BEGIN
+    -- Rewriting identical values (e.g. re-importing the same CSV) is not a new version
+    IF TG_OP = 'UPDATE' AND to_jsonb(OLD) - 'updated_at' = to_jsonb(NEW) - 'updated_at' THEN
+        RETURN NULL;
+    END IF;
+
+    IF TG_OP <> 'INSERT' THEN
+        -- A version opened earlier in this transaction was never visible; replace it
+        DELETE FROM record_versions
+        WHERE table_name = TG_TABLE_NAME AND record_id = OLD.id
+          AND valid_to IS NULL AND xmin = pg_current_xact_id()::xid;
+        -- A transaction that started later may have committed a newer version first
+        changed_at := GREATEST(changed_at, (
+            SELECT MAX(valid_from) + INTERVAL '1 microsecond' FROM record_versions
+            WHERE table_name = TG_TABLE_NAME AND record_id = OLD.id
+        ));
+        UPDATE record_versions SET valid_to = changed_at
+        WHERE table_name = TG_TABLE_NAME AND record_id = OLD.id AND valid_to IS NULL;
+    END IF;
+
+    IF TG_OP <> 'DELETE' THEN
+        INSERT INTO record_versions (table_name, record_id, college_id, valid_from, data)
+        VALUES (
+            TG_TABLE_NAME,
+            NEW.id,
+            CASE WHEN TG_TABLE_NAME = 'colleges'
+                THEN NEW.id
+                ELSE (to_jsonb(NEW) ->> 'college_id')::INTEGER
+            END,
+            changed_at,
+            to_jsonb(NEW)
+        );
+    END IF;
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER colleges_version AFTER INSERT OR UPDATE OR DELETE ON colleges
+    FOR EACH ROW EXECUTE FUNCTION record_version();
+CREATE TRIGGER contact_info_version AFTER INSERT OR UPDATE OR DELETE ON contact_info
+    FOR EACH ROW EXECUTE FUNCTION record_version();
+
+-- Earlier values were not kept, so existing rows get a single version starting
+-- when the college was created
+INSERT INTO record_versions (table_name, record_id, college_id, valid_from, data)
+SELECT 'colleges', c.id, c.id, COALESCE(c.created_at AT TIME ZONE 'UTC', NOW()), to_jsonb(c)
+FROM colleges c;
+
+INSERT INTO record_versions (table_name, record_id, college_id, valid_from, data)
+SELECT 'contact_info', ci.id, ci.college_id,
+       COALESCE(c.created_at AT TIME ZONE 'UTC', NOW()), to_jsonb(ci)
+FROM contact_info ci
+JOIN colleges c ON c.id = ci.college_id;
+
+-- The colleges and contact_info rows as they were at a point in time
+CREATE FUNCTION colleges_as_of(at TIMESTAMPTZ) RETURNS SETOF colleges AS $$
+    SELECT r.* FROM record_versions v,
+        jsonb_populate_record(NULL::colleges, v.data) AS r
+    WHERE v.table_name = 'colleges'
+      AND v.valid_from <= at AND (v.valid_to IS NULL OR v.valid_to > at)
+$$ LANGUAGE sql STABLE;
+
+CREATE FUNCTION contact_info_as_of(at TIMESTAMPTZ) RETURNS SETOF contact_info AS $$
+    SELECT r.* FROM record_versions v,
+        jsonb_populate_record(NULL::contact_info, v.data) AS r
+    WHERE v.table_name = 'contact_info'
+      AND v.valid_from <= at AND (v.valid_to IS NULL OR v.valid_to > at)
+$$ LANGUAGE sql STABLE;
+
+-- TG_TABLE_NAME is of type name, with the "C" collation, so comparing table_name
+-- with it could not use an index and the version trigger scanned all of
+-- record_versions for every change. Compare against a text copy instead, and index
+-- each record's versions by date for the lookup of its latest one.
+CREATE INDEX idx_record_versions_record ON record_versions(table_name, record_id, valid_from);
+
+CREATE OR REPLACE FUNCTION record_version() RETURNS trigger AS $$
+DECLARE
+    changed_at TIMESTAMPTZ := NOW();
+    -- In the column's collation, so the indexes on table_name apply
+    versioned_table TEXT := TG_TABLE_NAME;
+BEGIN
+    -- Rewriting identical values (e.g. re-importing the same CSV) is not a new version
+    IF TG_OP = 'UPDATE' AND to_jsonb(OLD) - 'updated_at' = to_jsonb(NEW) - 'updated_at' THEN
+        RETURN NULL;
+    END IF;
+
+    IF TG_OP <> 'INSERT' THEN
+        -- A version opened earlier in this transaction was never visible; replace it
+        DELETE FROM record_versions
+        WHERE table_name = versioned_table AND record_id = OLD.id
+          AND valid_to IS NULL AND xmin = pg_current_xact_id()::xid;
+        -- A transaction that started later may have committed a newer version first
+        changed_at := GREATEST(changed_at, (
+            SELECT MAX(valid_from) + INTERVAL '1 microsecond' FROM record_versions
+            WHERE table_name = versioned_table AND record_id = OLD.id
+        ));
+        UPDATE record_versions SET valid_to = changed_at
+        WHERE table_name = versioned_table AND record_id = OLD.id AND valid_to IS NULL;
+    END IF;
+
+    IF TG_OP <> 'DELETE' THEN
+        INSERT INTO record_versions (table_name, record_id, college_id, valid_from, data)
+        VALUES (
+            TG_TABLE_NAME,
+            NEW.id,
+            CASE WHEN TG_TABLE_NAME = 'colleges'
+                THEN NEW.id
+                ELSE (to_jsonb(NEW) ->> 'college_id')::INTEGER
+            END,
+            changed_at,
+            to_jsonb(NEW)
+        );
+    END IF;
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
+
+-- Deleting a college only hides it; the row and everything that references it are
+-- kept until a superadmin purges it after the retention period
+ALTER TABLE colleges
+    ADD COLUMN deleted_at TIMESTAMPTZ,
+    ADD COLUMN deleted_by INTEGER REFERENCES admin_users(id) ON DELETE SET NULL;
+
+CREATE INDEX idx_colleges_deleted_at ON colleges(deleted_at) WHERE deleted_at IS NOT NULL;
+
+-- College imports run in the background. The uploaded file is kept under IMPORT_DIR
+-- until the job ends, so a job interrupted by a restart is resumed from its last
+-- checkpoint (or, for atomic imports, from the start).
+CREATE TABLE import_jobs (
+    id SERIAL PRIMARY KEY,
+    kind VARCHAR(20) NOT NULL CHECK (kind IN ('colleges_csv', 'colleges_json')),
+    status VARCHAR(20) NOT NULL DEFAULT 'queued'
+        CHECK (status IN ('queued', 'running', 'completed', 'failed', 'cancelled')),
+    atomic BOOLEAN NOT NULL DEFAULT FALSE,
+    total_rows INTEGER, -- known once the file has been counted
+    processed_rows INTEGER NOT NULL DEFAULT 0, -- rows handled as of the last checkpoint
+    inserted INTEGER NOT NULL DEFAULT 0,
+    updated INTEGER NOT NULL DEFAULT 0,
+    error_count INTEGER NOT NULL DEFAULT 0,
+    message TEXT,
+    cancel_requested BOOLEAN NOT NULL DEFAULT FALSE,
+    created_by INTEGER REFERENCES admin_users(id) ON DELETE SET NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    started_at TIMESTAMPTZ,
+    finished_at TIMESTAMPTZ
+);
+
+CREATE INDEX idx_import_jobs_created_at ON import_jobs(created_at);
+CREATE INDEX idx_import_jobs_unfinished ON import_jobs(id) WHERE status IN ('queued', 'running');
+
+-- Rows an import job rejected, as reported in upload responses ("Line 5: ...")
+CREATE TABLE import_job_errors (
+    job_id INTEGER NOT NULL REFERENCES import_jobs(id) ON DELETE CASCADE,
+    row_number INTEGER NOT NULL,
+    message TEXT NOT NULL
+);
+
+CREATE INDEX idx_import_job_errors_job ON import_job_errors(job_id, row_number);
+
+-- XLSX/ODS college imports run as jobs too, reading the sheet chosen at upload with
+-- its column names taken from row header_row
+ALTER TABLE import_jobs DROP CONSTRAINT import_jobs_kind_check;
+ALTER TABLE import_jobs ADD CONSTRAINT import_jobs_kind_check
+    CHECK (kind IN ('colleges_csv', 'colleges_json', 'colleges_spreadsheet'));
+
+ALTER TABLE import_jobs
+    ADD COLUMN sheet TEXT,
+    ADD COLUMN header_row INTEGER;
+
+-- Purging a college no longer deletes its representatives' accounts along with it;
+-- the purge is refused while any account is still scoped to the college
+ALTER TABLE admin_users
+    DROP CONSTRAINT admin_users_college_id_fkey,
+    ADD CONSTRAINT admin_users_college_id_fkey
+        FOREIGN KEY (college_id) REFERENCES colleges(id) ON DELETE RESTRICT;
+
+-- One cutoff per college, year, branch and category, so concurrent uploads cannot add
+-- duplicates. A missing branch or category is the same key as an empty one. Existing
+-- duplicates are not resolved here: the migration stops and lists them so an admin
+-- can decide which rows to keep.
+DO $$
+DECLARE
+    duplicates TEXT;
+BEGIN
+    SELECT string_agg(duplicate, '; ') INTO duplicates
+    FROM (
+        SELECT format('college %s, year %s, branch %L, category %L: ids %s',
+                      college_id, year, COALESCE(branch, ''), COALESCE(category, ''),
+                      string_agg(id::TEXT, ', ' ORDER BY id)) AS duplicate
+        FROM cutoffs
+        GROUP BY college_id, year, COALESCE(branch, ''), COALESCE(category, '')
+        HAVING COUNT(*) > 1
+    ) groups;
+
+    IF duplicates IS NOT NULL THEN
+        RAISE EXCEPTION 'cutoffs has duplicate rows; remove all but one of each before migrating: %',
+            duplicates;
+    END IF;
+END
+$$;
+
+CREATE UNIQUE INDEX cutoffs_college_year_branch_category_key
+    ON cutoffs (college_id, year, COALESCE(branch, ''), COALESCE(category, ''));
+
+-- Covered by the leading columns of the unique index
+DROP INDEX idx_cutoffs_college_year;
+
+-- Cutoff CSV and spreadsheet uploads run as import jobs like the college imports
+ALTER TABLE import_jobs DROP CONSTRAINT import_jobs_kind_check;
+ALTER TABLE import_jobs ADD CONSTRAINT import_jobs_kind_check
+    CHECK (kind IN (
+        'colleges_csv', 'colleges_json', 'colleges_spreadsheet',
+        'cutoffs_csv', 'cutoffs_spreadsheet'
+    ));
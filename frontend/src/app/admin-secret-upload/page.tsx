@@ -8,27 +8,111 @@ import {
   Download,
   AlertCircle,
   CheckCircle,
+  LogOut,
 } from "lucide-react";
 
-interface UploadResponse {
-  message: string;
+const API_BASE = "http://localhost:3001";
+const TOKEN_KEY = "adminToken";
+
+// An upload is imported by a background job, followed until it finishes
+interface ImportJob {
+  id: number;
+  status: "queued" | "running" | "completed" | "failed" | "cancelled";
+  total_rows: number | null;
+  processed_rows: number;
+  message: string | null;
   inserted: number;
   updated: number;
   errors: string[];
 }
 
+const JOB_POLL_INTERVAL_MS = 1000;
+
 interface Stats {
   [key: string]: number;
 }
 
+interface LoginResponse {
+  token: string;
+  expires_at: string;
+}
+
 export default function AdminUploadPage() {
   const [uploadMethod, setUploadMethod] = useState<"csv" | "json">("csv");
   const [file, setFile] = useState<File | null>(null);
   const [jsonData, setJsonData] = useState("");
   const [uploading, setUploading] = useState(false);
-  const [result, setResult] = useState<UploadResponse | null>(null);
+  const [result, setResult] = useState<ImportJob | null>(null);
+  const [progress, setProgress] = useState<ImportJob | null>(null);
   const [stats, setStats] = useState<Stats | null>(null);
   const [error, setError] = useState<string | null>(null);
+  const [token, setToken] = useState<string | null>(null);
+  const [username, setUsername] = useState("");
+  const [password, setPassword] = useState("");
+
+  const authHeaders = (): Record<string, string> =>
+    token ? { Authorization: `Bearer ${token}` } : {};
+
+  // Forget an expired or revoked token so the login form is shown again
+  const handleUnauthorized = (response: Response) => {
+    if (response.status === 401) {
+      sessionStorage.removeItem(TOKEN_KEY);
+      setToken(null);
+      setStats(null);
+      throw new Error("Session expired, please log in again");
+    }
+  };
+
+  const handleLogin = async () => {
+    setError(null);
+    try {
+      const response = await fetch(`${API_BASE}/api/admin/login`, {
+        method: "POST",
+        headers: { "Content-Type": "application/json" },
+        body: JSON.stringify({ username, password }),
+      });
+      if (!response.ok) {
+        throw new Error("Invalid username or password");
+      }
+      const login: LoginResponse = await response.json();
+      sessionStorage.setItem(TOKEN_KEY, login.token);
+      setToken(login.token);
+      setPassword("");
+    } catch (err) {
+      setError(err instanceof Error ? err.message : "Login failed");
+    }
+  };
+
+  const handleLogout = async () => {
+    await fetch(`${API_BASE}/api/admin/logout`, {
+      method: "POST",
+      headers: authHeaders(),
+    }).catch(() => undefined);
+    sessionStorage.removeItem(TOKEN_KEY);
+    setToken(null);
+    setStats(null);
+  };
+
+  // Poll the job an upload queued until it is over, showing its progress meanwhile
+  const waitForJob = async (queued: ImportJob): Promise<ImportJob> => {
+    let job = queued;
+    while (job.status === "queued" || job.status === "running") {
+      setProgress(job);
+      await new Promise((resolve) => setTimeout(resolve, JOB_POLL_INTERVAL_MS));
+      const response = await fetch(`${API_BASE}/api/admin/jobs/${job.id}`, {
+        headers: authHeaders(),
+      });
+      handleUnauthorized(response);
+      if (!response.ok) {
+        throw new Error(`Failed to follow import: ${response.statusText}`);
+      }
+      job = await response.json();
+    }
+    if (job.status === "failed") {
+      throw new Error(job.message || "Import failed");
+    }
+    return job;
+  };
 
   const handleFileUpload = async () => {
     if (!file) {
@@ -44,24 +128,28 @@ export default function AdminUploadPage() {
       const formData = new FormData();
       formData.append("file", file);
 
-      const response = await fetch(
-        "http://localhost:3001/api/admin/upload/csv",
-        {
-          method: "POST",
-          body: formData,
-        },
-      );
-
+      // Workbooks go to the spreadsheet import, which reads their first sheet
+      const endpoint = /\.(xlsx|xls|ods)$/i.test(file.name)
+        ? "spreadsheet"
+        : "csv";
+      const response = await fetch(`${API_BASE}/api/admin/upload/${endpoint}`, {
+        method: "POST",
+        headers: authHeaders(),
+        body: formData,
+      });
+
+      handleUnauthorized(response);
       if (!response.ok) {
         throw new Error(`Upload failed: ${response.statusText}`);
       }
 
-      const result: UploadResponse = await response.json();
+      const result = await waitForJob(await response.json());
       setResult(result);
       await loadStats();
     } catch (err) {
       setError(err instanceof Error ? err.message : "Upload failed");
     } finally {
+      setProgress(null);
       setUploading(false);
     }
   };
@@ -79,36 +167,39 @@ export default function AdminUploadPage() {
     try {
       const parsedData = JSON.parse(jsonData);
 
-      const response = await fetch(
-        "http://localhost:3001/api/admin/upload/json",
-        {
-          method: "POST",
-          headers: {
-            "Content-Type": "application/json",
-          },
-          body: JSON.stringify(parsedData),
+      const response = await fetch(`${API_BASE}/api/admin/upload/json`, {
+        method: "POST",
+        headers: {
+          ...authHeaders(),
+          "Content-Type": "application/json",
         },
-      );
+        body: JSON.stringify(parsedData),
+      });
 
+      handleUnauthorized(response);
       if (!response.ok) {
         const errorText = await response.text();
         throw new Error(`Upload failed: ${errorText || response.statusText}`);
       }
 
-      const result: UploadResponse = await response.json();
+      const result = await waitForJob(await response.json());
       setResult(result);
       setJsonData(""); // Clear on success
       await loadStats();
     } catch (err) {
       setError(err instanceof Error ? err.message : "Upload failed");
     } finally {
+      setProgress(null);
       setUploading(false);
     }
   };
 
   const loadStats = async () => {
     try {
-      const response = await fetch("http://localhost:3001/api/admin/stats");
+      const response = await fetch(`${API_BASE}/api/admin/stats`, {
+        headers: authHeaders(),
+      });
+      handleUnauthorized(response);
       if (response.ok) {
         const statsData = await response.json();
         setStats(statsData);
@@ -132,9 +223,59 @@ export default function AdminUploadPage() {
   };
 
   useEffect(() => {
-    loadStats();
+    setToken(sessionStorage.getItem(TOKEN_KEY));
   }, []);
 
+  useEffect(() => {
+    if (token) {
+      loadStats();
+    }
+    // eslint-disable-next-line react-hooks/exhaustive-deps
+  }, [token]);
+
+  if (!token) {
+    return (
+      <div className="min-h-screen bg-gray-50 py-8 flex items-center justify-center">
+        <div className="bg-white rounded-lg shadow-md p-6 w-full max-w-sm">
+          <h1 className="text-xl font-semibold text-gray-900 mb-4">
+            Admin Login
+          </h1>
+          <form
+            onSubmit={(e) => {
+              e.preventDefault();
+              handleLogin();
+            }}
+          >
+            <input
+              type="text"
+              value={username}
+              onChange={(e) => setUsername(e.target.value)}
+              placeholder="Username"
+              autoComplete="username"
+              className="w-full mb-3 p-2 border border-gray-300 rounded-md"
+            />
+            <input
+              type="password"
+              value={password}
+              onChange={(e) => setPassword(e.target.value)}
+              placeholder="Password"
+              autoComplete="current-password"
+              className="w-full mb-4 p-2 border border-gray-300 rounded-md"
+            />
+            <button
+              type="submit"
+              disabled={!username || !password}
+              className="w-full px-4 py-2 bg-blue-600 text-white rounded-md hover:bg-blue-700 disabled:bg-gray-400 disabled:cursor-not-allowed"
+            >
+              Log in
+            </button>
+          </form>
+          {error && <p className="text-sm text-red-600 mt-3">{error}</p>}
+        </div>
+      </div>
+    );
+  }
+
   return (
     <div className="min-h-screen bg-gray-50 py-8">
       <div className="max-w-4xl mx-auto px-4 sm:px-6 lg:px-8">
@@ -146,6 +287,13 @@ export default function AdminUploadPage() {
           <p className="text-gray-600">
             Upload college data via CSV or JSON format
           </p>
+          <button
+            onClick={handleLogout}
+            className="mt-3 inline-flex items-center gap-2 text-sm text-gray-600 hover:text-gray-900"
+          >
+            <LogOut className="h-4 w-4" />
+            Log out
+          </button>
         </div>
 
         {/* Stats Dashboard */}
@@ -225,11 +373,11 @@ export default function AdminUploadPage() {
 
               <div className="mb-4">
                 <label className="block text-sm font-medium text-gray-700 mb-2">
-                  Select CSV File
+                  Select CSV or Excel File
                 </label>
                 <input
                   type="file"
-                  accept=".csv"
+                  accept=".csv,.xlsx,.xls,.ods"
                   onChange={(e) => setFile(e.target.files?.[0] || null)}
                   className="block w-full text-sm text-gray-500 file:mr-4 file:py-2 file:px-4 file:rounded-md file:border-0 file:text-sm file:font-semibold file:bg-blue-50 file:text-blue-700 hover:file:bg-blue-100"
                 />
@@ -241,7 +389,7 @@ export default function AdminUploadPage() {
                 className="flex items-center gap-2 px-6 py-2 bg-blue-600 text-white rounded-md hover:bg-blue-700 disabled:bg-gray-400 disabled:cursor-not-allowed"
               >
                 <Upload className="h-4 w-4" />
-                {uploading ? "Uploading..." : "Upload CSV"}
+                {uploading ? "Uploading..." : "Upload File"}
               </button>
             </div>
           )}
@@ -291,6 +439,17 @@ export default function AdminUploadPage() {
           )}
         </div>
 
+        {/* Import progress */}
+        {progress && (
+          <div className="bg-blue-50 border border-blue-200 rounded-lg p-4 mb-6 text-blue-800">
+            {progress.status === "queued"
+              ? "Import queued..."
+              : `Importing: ${progress.processed_rows} of ${
+                  progress.total_rows ?? "?"
+                } rows`}
+          </div>
+        )}
+
         {/* Results */}
         {error && (
           <div className="bg-red-50 border border-red-200 rounded-lg p-4 mb-6">
@@ -306,7 +465,9 @@ export default function AdminUploadPage() {
           <div className="bg-green-50 border border-green-200 rounded-lg p-6">
             <div className="flex items-center gap-2 text-green-800 mb-4">
               <CheckCircle className="h-5 w-5" />
-              <span className="font-medium">Upload Complete</span>
+              <span className="font-medium">
+                {result.message || "Upload Complete"}
+              </span>
             </div>
 
             <div className="grid grid-cols-1 md:grid-cols-3 gap-4 mb-4">